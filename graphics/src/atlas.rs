@@ -1,11 +1,30 @@
 mod allocation;
 mod allocator;
 mod atlas_set;
+mod compaction;
+mod entry;
+mod event;
+mod format_info;
+mod handle;
+mod packed_frame;
+mod staging;
+mod trim;
 
 use crate::AIndexSet;
 pub use allocation::Allocation;
 pub use allocator::Allocator;
 pub use atlas_set::AtlasSet;
+pub use compaction::CompactProgress;
+pub(crate) use compaction::{CompactCopy, Compaction};
+pub use entry::AtlasEntry;
+pub use event::AtlasEvent;
+pub use format_info::FormatInfo;
+pub use handle::TextureHandle;
+pub use packed_frame::PackedFrame;
+use slab::Slab;
+pub use staging::TextureStagingBelt;
+pub(crate) use trim::trim_transparent;
+pub use trim::TrimOffset;
 
 /// Atlas Layer within an [`AtlasSet`].
 ///
@@ -17,16 +36,30 @@ pub struct Atlas {
     ///  use to avoid placing newly loaded images into
     /// if we are migrating images out of it.
     pub migrating: bool,
+    /// Holds a single large Texture on its own so nothing else gets packed
+    /// into it. See [`AtlasSet::set_large_texture_threshold`].
+    pub dedicated: bool,
 }
 
 impl Atlas {
     /// Creates a new Atlas with Allocator texture size.
     ///
     pub fn new(size: u32) -> Self {
+        Self::with_options(size, &guillotiere::DEFAULT_OPTIONS)
+    }
+
+    /// Creates a new Atlas with Allocator texture size packing with the
+    /// given [`guillotiere::AllocatorOptions`].
+    ///
+    pub fn with_options(
+        size: u32,
+        options: &guillotiere::AllocatorOptions,
+    ) -> Self {
         Self {
-            allocator: Allocator::new(size),
+            allocator: Allocator::with_options(size, options),
             allocated: AIndexSet::default(),
             migrating: false,
+            dedicated: false,
         }
     }
 
@@ -40,18 +73,51 @@ impl Atlas {
         self.allocator.allocate(width, height)
     }
 
+    /// Returns the Width and Height the layer can allocate within.
+    ///
+    pub fn size(&self) -> u32 {
+        self.allocator.size()
+    }
+
     /// Inserts [Allocation] Aquired Index for Back Mapping.
     ///
     pub fn insert_index(&mut self, index: usize) {
         self.allocated.insert(index);
     }
 
+    /// Returns the layer's Allocations and their index sorted by their rect
+    /// origin from top to bottom then left to right.
+    /// Gives a stable order regardless of the order of allocated.
+    ///
+    /// # Arguments
+    /// - store: The [`AtlasSet`] store the indexs belong to.
+    ///
+    pub fn iter_allocations_sorted<'a, U, Data: Copy + Default>(
+        &'a self,
+        store: &'a Slab<(Allocation<Data>, U)>,
+    ) -> impl Iterator<Item = (usize, &'a Allocation<Data>)> + 'a {
+        let mut allocations: Vec<(usize, &Allocation<Data>)> = self
+            .allocated
+            .iter()
+            .filter_map(|&index| {
+                store.get(index).map(|(allocation, _)| (index, allocation))
+            })
+            .collect();
+
+        allocations.sort_by_key(|(index, allocation)| {
+            let (x, y) = allocation.position();
+            (y, x, *index)
+        });
+        allocations.into_iter()
+    }
+
     /// Clears the internal Allocator and Allocated stores.
     ///
     pub fn clear(&mut self) {
         self.allocator.clear();
         self.allocated.clear();
         self.migrating = false;
+        self.dedicated = false;
     }
 
     /// Deallocates a [`Allocation`] returning it to the Allocator for reuse.
@@ -63,6 +129,10 @@ impl Atlas {
     ) {
         self.allocated.swap_remove(&index);
         self.allocator.deallocate(allocation);
+
+        if self.allocated.is_empty() {
+            self.dedicated = false;
+        }
     }
 
     /// Returns how many alloctions have been removed since the
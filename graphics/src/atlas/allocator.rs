@@ -26,6 +26,24 @@ impl Allocator {
         Some(allocation)
     }
 
+    /// Returns a new Allocation with padding pixels left empty on every side.
+    /// The returned rectangle only covers the inner Width and Height.
+    ///
+    pub fn allocate_padded(
+        &mut self,
+        width: u32,
+        height: u32,
+        padding: u32,
+    ) -> Option<guillotiere::Allocation> {
+        let mut allocation =
+            self.allocate(width + padding * 2, height + padding * 2)?;
+        allocation.rectangle = allocation
+            .rectangle
+            .inflate(-(padding as i32), -(padding as i32));
+
+        Some(allocation)
+    }
+
     /// Clears the Allocator and its counters.
     ///
     pub fn clear(&mut self) {
@@ -49,6 +67,12 @@ impl Allocator {
         self.allocations == 0
     }
 
+    /// Returns the Width and Height the [`Allocator`] can allocate within.
+    ///
+    pub fn size(&self) -> u32 {
+        self.allocator.size().width as u32
+    }
+
     /// How many deallocations have been made. Used for defragmentation.
     ///
     pub fn deallocations(&self) -> usize {
@@ -58,8 +82,21 @@ impl Allocator {
     /// Creates a new [`Allocator`] layer.
     ///
     pub fn new(size: u32) -> Self {
-        let allocator = guillotiere::AtlasAllocator::new(
+        Self::with_options(size, &guillotiere::DEFAULT_OPTIONS)
+    }
+
+    /// Creates a new [`Allocator`] layer packing with the given
+    /// [`guillotiere::AllocatorOptions`]. Rounding sizes up with the alignment
+    /// and tuning the small and large size thresholds can reduce
+    /// fragmentation for a given spread of Texture sizes.
+    ///
+    pub fn with_options(
+        size: u32,
+        options: &guillotiere::AllocatorOptions,
+    ) -> Self {
+        let allocator = guillotiere::AtlasAllocator::with_options(
             guillotiere::Size::new(size as i32, size as i32),
+            options,
         );
 
         Self {
@@ -1,10 +1,15 @@
 use crate::{
-    AHashMap, AHashSet, Allocation, Atlas, GpuRenderer, TextureGroup,
-    TextureLayout, UVec3,
+    trim_transparent, AHashMap, AHashSet, Allocation, Allocator, Atlas,
+    AtlasEntry, AtlasEvent, CompactCopy, CompactProgress, Compaction,
+    FormatInfo, GpuRenderer, GraphicsError, OtherError, PackedFrame, Texture,
+    TextureGroup, TextureHandle, TextureLayout, TextureStagingBelt, TrimOffset,
+    UVec2, UVec3, Vec2,
 };
 use lru::LruCache;
 use slab::Slab;
-use std::hash::Hash;
+use std::{
+    any::Any, borrow::Cow, collections::VecDeque, hash::Hash, path::Path,
+};
 use wgpu::BindGroup;
 
 /**
@@ -43,6 +48,8 @@ pub struct AtlasSet<U: Hash + Eq + Clone = String, Data: Copy + Default = i32> {
     pub texture: wgpu::Texture,
     /// Texture View for WGPU
     pub texture_view: wgpu::TextureView,
+    /// Single Layer Texture Views for sampling each layer outside of the Atlas.
+    pub layer_views: Vec<wgpu::TextureView>,
     /// Layers of texture.
     pub layers: Vec<Atlas>,
     /// Holds the Original Texture Size and layer information.
@@ -53,6 +60,18 @@ pub struct AtlasSet<U: Hash + Eq + Clone = String, Data: Copy + Default = i32> {
     pub store: Slab<(Allocation<Data>, U)>,
     /// for key to index lookups.
     pub lookup: AHashMap<U, usize>,
+    /// Generation per store index. Incremented each time the index is removed
+    /// so older [`TextureHandle`]s become invalid.
+    pub(crate) generations: Vec<u32>,
+    /// Revision per store index. Incremented each time the [`Allocation`] is moved
+    /// within the Texture so users like [`crate::Rect`] know to refresh their UV's.
+    pub revisions: Vec<u32>,
+    /// Frames of a packed Sheet mapped to the Sheets index.
+    /// Frames share the Sheets [`Allocation`] so they never deallocate space.
+    pub frames: AHashMap<usize, usize>,
+    /// Application Data attached to a [`Allocation`] by index.
+    /// Removed along with the [`Allocation`].
+    pub user_data: AHashMap<usize, Box<dyn Any>>,
     /// keeps a list of least used allocations so we can unload them when need be.
     /// Also include the RefCount per ID lookup.
     /// we use this to keep track of when Fonts need to be unloaded.
@@ -63,6 +82,55 @@ pub struct AtlasSet<U: Hash + Eq + Clone = String, Data: Copy + Default = i32> {
     pub last_used: AHashSet<usize>,
     /// Format the Texture uses.
     pub format: wgpu::TextureFormat,
+    /// If uploaded Textures have their colors premultiplied by alpha.
+    pub premultiplied: bool,
+    /// Size buckets new layers are created with. A texture goes into the smallest
+    /// bucket it fits so small textures do not fill up layers meant for large ones.
+    /// Each layer still uses the full extent of GPU memory as they share one Texture.
+    /// When empty every layer uses the full extent.
+    pub layer_sizes: Vec<u32>,
+    /// Trims fully transparent rows and columns from the edges of uploaded
+    /// textures before allocating. Only applies to Rgba8UnormSrgb textures.
+    pub trim_transparent: bool,
+    /// Optional RGB color made fully transparent when [`Texture::upload_from`]
+    /// decodes a file. Used for legacy art keyed with a color like magenta.
+    pub color_key: Option<[u8; 3]>,
+    /// [`TrimOffset`] of each trimmed [`Allocation`] by index.
+    pub trims: AHashMap<usize, TrimOffset>,
+    /// Pixels left empty around each new [`Allocation`] so filtering does not
+    /// bleed neighbouring Textures in. Only applies to later uploads.
+    pub padding: u32,
+    /// Padding each [`Allocation`] was packed with by index when not 0.
+    pub paddings: AHashMap<usize, u32>,
+    /// Indexs whose Texture got downscaled to fit the memory_budget.
+    pub downscaled: AHashSet<usize>,
+    /// Optional budget in bytes for the uploaded textures.
+    /// Uploads that would go over it are downscaled by half until they fit.
+    /// Do not set this on Atlases whose Data depends on the texture size like Glyphs.
+    pub memory_budget: Option<u64>,
+    /// Optional budget in bytes of texture data written to the GPU per frame.
+    /// Uploads past it are queued until [`AtlasSet::flush_pending`].
+    pub upload_budget: Option<u64>,
+    /// Bytes written to the GPU since the last [`AtlasSet::flush_pending`].
+    pub uploaded_bytes: u64,
+    /// Queued uploads of index, generation and texture bytes waiting on the budget.
+    pub pending_uploads: VecDeque<(usize, u32, Vec<u8>)>,
+    /// Optional ring of reusable staging Buffers uploads are copied through.
+    /// Staged uploads reach the Texture once [`AtlasSet::submit_uploads`] is called.
+    pub staging: Option<TextureStagingBelt>,
+    /// If the Texture is created with RENDER_ATTACHMENT usage so Allocations
+    /// can be rendered into with [`AtlasSet::begin_render_pass`].
+    pub render_target: bool,
+    /// Fraction of the layer dimension above which a Texture gets a layer of
+    /// its own instead of being packed with others. None packs everything.
+    pub large_texture_threshold: Option<f32>,
+    /// [`guillotiere::AllocatorOptions`] each layer's Allocator packs with.
+    pub allocator_options: guillotiere::AllocatorOptions,
+    /// In progress incremental compaction of [`AtlasSet::compact_step`].
+    pub(crate) compaction: Option<Compaction>,
+    /// Incremented by every upload, write, removal, clear and growth so an
+    /// in progress compaction knows it is outdated.
+    pub(crate) mutations: u64,
     /// When the System will Error if reached. This is the max allowed Layers
     /// Default is 256 as Most GPU allow a max of 256.
     pub max_layers: usize,
@@ -78,25 +146,64 @@ pub struct AtlasSet<U: Hash + Eq + Clone = String, Data: Copy + Default = i32> {
     /// uses the refcount to unload rather than the unused.
     /// must exist for fonts to unload correctly and must be set to false for them.
     pub use_ref_count: bool,
+    /// Max anisotropy the Atlas sampler uses. 1 disables anisotropic filtering.
+    pub anisotropy: u16,
     /// Texture Bind group for Atlas
     pub texture_group: TextureGroup,
+    /// Optional handler [`AtlasEvent`]s are reported to.
+    /// See [`AtlasSet::on_event`].
+    pub(crate) event_handler: Option<Box<dyn FnMut(AtlasEvent)>>,
 }
 
 impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
+    //Allocates width and height surrounded by the AtlasSet's padding.
     fn allocate(
         &mut self,
         width: u32,
         height: u32,
         data: Data,
+    ) -> Option<Allocation<Data>> {
+        self.mark_mutated();
+        let padding = self.padding as i32;
+        let mut allocation = self.allocate_region(
+            width + self.padding * 2,
+            height + self.padding * 2,
+            data,
+        )?;
+        allocation.allocation.rectangle =
+            allocation.allocation.rectangle.inflate(-padding, -padding);
+
+        Some(allocation)
+    }
+
+    fn allocate_region(
+        &mut self,
+        width: u32,
+        height: u32,
+        data: Data,
     ) -> Option<Allocation<Data>> {
         /* Check if the allocation would fit. */
         if width > self.extent.width || height > self.extent.height {
             return None;
         }
 
-        /* Try allocating from an existing layer. */
-        for (i, layer) in self.layers.iter_mut().enumerate() {
-            if let Some(allocation) = layer.allocator.allocate(width, height) {
+        if self.is_large_texture(width, height) {
+            return self.allocate_dedicated(width, height, data);
+        }
+
+        /* Try allocating from the smallest existing layer it fits. */
+        let mut layers: Vec<usize> = (0..self.layers.len())
+            .filter(|&i| {
+                !self.layers[i].dedicated
+                    && self.layers[i].size() >= width.max(height)
+            })
+            .collect();
+        layers.sort_by_key(|&i| self.layers[i].size());
+
+        for i in layers {
+            if let Some(allocation) =
+                self.layers[i].allocator.allocate(width, height)
+            {
                 return Some(Allocation {
                     allocation,
                     layer: i,
@@ -116,7 +223,7 @@ impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
                     break;
                 }
 
-                if let Some(layer_id) = self.remove(id) {
+                if let Some(layer_id) = self.evict(id) {
                     let layer = self.layers.get_mut(layer_id)?;
 
                     if let Some(allocation) =
@@ -139,7 +246,10 @@ impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
             return None;
         }
 
-        let mut layer = Atlas::new(self.extent.width);
+        let mut layer = Atlas::with_options(
+            self.layer_size(width, height),
+            &self.allocator_options,
+        );
 
         if let Some(allocation) = layer.allocator.allocate(width, height) {
             self.layers.push(layer);
@@ -155,6 +265,73 @@ impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
         None
     }
 
+    //Returns true if the size is above the large texture threshold.
+    fn is_large_texture(&self, width: u32, height: u32) -> bool {
+        self.large_texture_threshold.is_some_and(|threshold| {
+            width.max(height) as f32 > threshold * self.extent.width as f32
+        })
+    }
+
+    //Allocates a large texture within an empty layer, or a new one, and
+    //marks the layer as dedicated so nothing else gets packed into it.
+    fn allocate_dedicated(
+        &mut self,
+        width: u32,
+        height: u32,
+        data: Data,
+    ) -> Option<Allocation<Data>> {
+        let empty = (0..self.layers.len())
+            .filter(|&i| {
+                let layer = &self.layers[i];
+
+                layer.allocated.is_empty()
+                    && !layer.migrating
+                    && layer.size() >= width.max(height)
+            })
+            .min_by_key(|&i| self.layers[i].size());
+
+        if let Some(i) = empty {
+            let layer = &mut self.layers[i];
+            let allocation = layer.allocator.allocate(width, height)?;
+            layer.dedicated = true;
+
+            return Some(Allocation {
+                allocation,
+                layer: i,
+                data,
+            });
+        }
+
+        if self.layers.len() + 1 == self.max_layers {
+            return None;
+        }
+
+        let mut layer = Atlas::with_options(
+            self.layer_size(width, height),
+            &self.allocator_options,
+        );
+        let allocation = layer.allocator.allocate(width, height)?;
+        layer.dedicated = true;
+        self.layers.push(layer);
+
+        Some(Allocation {
+            allocation,
+            layer: self.layers.len() - 1,
+            data,
+        })
+    }
+
+    //Returns the smallest layer size bucket that fits width and height.
+    fn layer_size(&self, width: u32, height: u32) -> u32 {
+        self.layer_sizes
+            .iter()
+            .copied()
+            .filter(|&size| size >= width.max(height))
+            .min()
+            .unwrap_or(self.extent.width)
+            .min(self.extent.width)
+    }
+
     //TODO Add shrink that takes layers using a unload boolean and also promote each layers
     //TODO allocation layers to the new layer location. while removing the old empty layer.
     fn grow(&mut self, amount: usize, renderer: &GpuRenderer) {
@@ -162,6 +339,21 @@ impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
             return;
         }
 
+        self.mark_mutated();
+        self.rebuild_texture(self.layers.len() - amount, renderer);
+        self.emit(AtlasEvent::LayerAdded(self.layers.len()));
+    }
+
+    //Recreates the Texture with every Layer copying over the first
+    //amount_to_copy Layers from the old Texture.
+    fn rebuild_texture(
+        &mut self,
+        amount_to_copy: usize,
+        renderer: &GpuRenderer,
+    ) {
+        // Staged uploads target the old Texture so they must land before copying it.
+        self.submit_uploads(renderer);
+
         let extent = wgpu::Extent3d {
             width: self.extent.width,
             height: self.extent.height,
@@ -172,18 +364,14 @@ impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
             renderer.device().create_texture(&wgpu::TextureDescriptor {
                 label: Some("Texture"),
                 size: extent,
-                mip_level_count: 0,
+                mip_level_count: 1,
                 sample_count: 1,
                 dimension: wgpu::TextureDimension::D2,
                 format: self.format,
-                usage: wgpu::TextureUsages::TEXTURE_BINDING
-                    | wgpu::TextureUsages::COPY_DST
-                    | wgpu::TextureUsages::COPY_SRC,
+                usage: self.texture_usage(),
                 view_formats: &[wgpu::TextureFormat::Bgra8Unorm],
             });
 
-        let amount_to_copy = self.layers.len() - amount;
-
         let mut encoder = renderer.device().create_command_encoder(
             &wgpu::CommandEncoderDescriptor {
                 label: Some("Texture command encoder"),
@@ -232,6 +420,11 @@ impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
                 base_array_layer: 0,
                 array_layer_count: Some(self.layers.len() as u32),
             });
+        self.layer_views = Self::create_layer_views(
+            &self.texture,
+            self.format,
+            self.layers.len() as u32,
+        );
         renderer.queue().submit(std::iter::once(encoder.finish()));
     }
 
@@ -246,11 +439,33 @@ impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
         format: wgpu::TextureFormat,
         use_ref_count: bool,
     ) -> Self {
-        let limits = renderer.device().limits();
+        Self::with_layers(renderer, format, use_ref_count, 2)
+    }
+
+    /// Creates a new [`AtlasSet`] with a set amount of Layers allocated up front.
+    /// Use this to avoid growing the layers one at a time when many textures load at once.
+    ///
+    /// # Arguments
+    /// - format: [`wgpu::TextureFormat`] the texture layers will need to be.
+    /// - use_ref_count: Mostly used for Glyph Storage and Auto Removal.
+    /// - initial_layers: Amount of Layers to Start with. Clamped between 1 and the devices max layers.
+    ///
+    pub fn with_layers(
+        renderer: &mut GpuRenderer,
+        format: wgpu::TextureFormat,
+        use_ref_count: bool,
+        initial_layers: u32,
+    ) -> Self {
+        let limits = renderer.device_limits();
+        let initial_layers =
+            initial_layers.clamp(1, limits.max_texture_array_layers);
+        let size = limits
+            .max_texture_dimension_3d
+            .min(renderer.max_texture_dimension());
         let extent = wgpu::Extent3d {
-            width: limits.max_texture_dimension_3d,
-            height: limits.max_texture_dimension_3d,
-            depth_or_array_layers: 2,
+            width: size,
+            height: size,
+            depth_or_array_layers: initial_layers,
         };
 
         let texture =
@@ -275,268 +490,1551 @@ impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
             base_mip_level: 0,
             mip_level_count: Some(1),
             base_array_layer: 0,
-            array_layer_count: Some(1),
+            array_layer_count: Some(initial_layers),
         });
 
+        let layer_views = Self::create_layer_views(
+            &texture,
+            format,
+            extent.depth_or_array_layers,
+        );
         let texture_group =
             TextureGroup::from_view(renderer, &texture_view, TextureLayout);
 
         Self {
             texture,
             texture_view,
-            layers: vec![
-                Atlas::new(limits.max_texture_dimension_3d),
-                Atlas::new(limits.max_texture_dimension_3d),
-            ],
+            layer_views,
+            layers: (0..initial_layers).map(|_| Atlas::new(size)).collect(),
             store: Slab::with_capacity(512),
             lookup: AHashMap::new(),
+            generations: Vec::with_capacity(512),
+            revisions: Vec::with_capacity(512),
+            frames: AHashMap::new(),
+            user_data: AHashMap::new(),
             extent,
             cache: LruCache::unbounded(),
             last_used: AHashSet::default(),
             format,
+            premultiplied: false,
+            layer_sizes: Vec::new(),
+            trim_transparent: false,
+            color_key: None,
+            trims: AHashMap::new(),
+            padding: 0,
+            paddings: AHashMap::new(),
+            downscaled: AHashSet::new(),
+            memory_budget: None,
+            upload_budget: None,
+            uploaded_bytes: 0,
+            pending_uploads: VecDeque::new(),
+            staging: None,
+            render_target: false,
+            large_texture_threshold: None,
+            allocator_options: guillotiere::DEFAULT_OPTIONS,
+            compaction: None,
+            mutations: 0,
             max_layers: limits.max_texture_array_layers as usize,
             deallocations_limit: 32,
             layer_check_limit: (limits.max_texture_array_layers as f64 * 0.8)
                 as usize,
             layer_free_limit: 3,
             use_ref_count,
+            anisotropy: 1,
             texture_group,
+            event_handler: None,
         }
     }
 
-    /// Uploads a new Texture Byte Array into the GPU AtlasSets Layer.
+    /// Sets the max anisotropy of the Atlas sampler so textures viewed at
+    /// grazing angles stay sharp. Anisotropy above 1 switches the sampler to
+    /// Linear filtering. The level is clamped to 1 through 16 and to 1 if the
+    /// Device does not support anisotropic filtering.
     ///
-    pub fn upload_allocation(
+    /// Returns the level that got applied.
+    ///
+    pub fn set_anisotropy(
         &mut self,
-        buffer: &[u8],
-        allocation: &Allocation<Data>,
-        renderer: &GpuRenderer,
-    ) {
-        let (x, y) = allocation.position();
-        let (width, height) = allocation.size();
-        let layer = allocation.layer;
+        renderer: &mut GpuRenderer,
+        level: u16,
+    ) -> u16 {
+        let level = if renderer.supports_anisotropy() {
+            level.clamp(1, 16)
+        } else {
+            1
+        };
 
-        renderer.queue().write_texture(
-            wgpu::ImageCopyTexture {
-                texture: &self.texture,
-                mip_level: 0,
-                origin: wgpu::Origin3d {
-                    x,
-                    y,
-                    z: layer as u32,
-                },
-                aspect: wgpu::TextureAspect::All,
-            },
-            buffer,
-            wgpu::ImageDataLayout {
-                offset: 0,
-                bytes_per_row: Some(
-                    if self.format == wgpu::TextureFormat::Rgba8UnormSrgb {
-                        4 * width
-                    } else {
-                        width
-                    },
-                ),
-                rows_per_image: Some(height),
-            },
-            wgpu::Extent3d {
-                width,
-                height,
-                depth_or_array_layers: 1,
-            },
-        );
+        if level != self.anisotropy {
+            self.anisotropy = level;
+            self.texture_group = TextureGroup::from_view_with_anisotropy(
+                renderer,
+                &self.texture_view,
+                TextureLayout,
+                wgpu::FilterMode::Nearest,
+                level,
+            );
+        }
+
+        level
     }
 
-    /// Clears all information of stored Textures and Allocations.
+    /// Sets if the Texture can be rendered into. Enabling it recreates the
+    /// Texture with RENDER_ATTACHMENT usage keeping every uploaded texture.
+    /// The Atlas format must be renderable.
     ///
-    /// This Does not Empty the [`AtlasSet`]s GPU Texture Buffer.
-    /// As we normally just overwrite the buffer when we add new Allocations.
+    pub fn set_render_target(
+        &mut self,
+        renderer: &mut GpuRenderer,
+        render_target: bool,
+    ) -> &mut Self {
+        if render_target != self.render_target {
+            self.render_target = render_target;
+            self.rebuild_texture(self.layers.len(), renderer);
+            self.texture_group = TextureGroup::from_view_with_anisotropy(
+                renderer,
+                &self.texture_view,
+                TextureLayout,
+                wgpu::FilterMode::Nearest,
+                self.anisotropy,
+            );
+        }
+
+        self
+    }
+
+    //Returns the usages the Texture is created with.
+    fn texture_usage(&self) -> wgpu::TextureUsages {
+        let usage = wgpu::TextureUsages::TEXTURE_BINDING
+            | wgpu::TextureUsages::COPY_DST
+            | wgpu::TextureUsages::COPY_SRC;
+
+        if self.render_target {
+            usage | wgpu::TextureUsages::RENDER_ATTACHMENT
+        } else {
+            usage
+        }
+    }
+
+    /// Sets the [`guillotiere::AllocatorOptions`] the layers pack with so
+    /// packing can be tuned to the spread of Texture sizes loaded.
+    /// Empty layers and layers added afterwards use them right away while
+    /// layers holding Textures pick them up when they are next defragmented.
     ///
-    pub fn clear(&mut self) {
-        for layer in self.layers.iter_mut() {
-            layer.allocator.clear();
+    pub fn set_allocator_options(
+        &mut self,
+        options: guillotiere::AllocatorOptions,
+    ) -> &mut Self {
+        self.allocator_options = options;
+
+        for layer in &mut self.layers {
+            if layer.allocator.is_empty() {
+                layer.allocator =
+                    Allocator::with_options(layer.allocator.size(), &options);
+            }
         }
 
-        self.store.clear();
-        self.lookup.clear();
-        self.cache.clear();
-        self.last_used.clear();
+        self
     }
 
-    //TODO Make function that checks for unloading and migrating.
-    /// Clears the last_used cache's.
+    /// Sets the fraction of the layer dimension above which a Texture is
+    /// given a layer of its own instead of being packed with smaller ones,
+    /// so large backgrounds do not fragment the layers icons are packed in.
+    /// The fraction is clamped between 0 and 1. None packs every Texture.
+    /// Only affects Textures allocated afterwards.
     ///
-    pub fn trim(&mut self) {
-        self.last_used.clear();
+    pub fn set_large_texture_threshold(
+        &mut self,
+        fraction: Option<f32>,
+    ) -> &mut Self {
+        self.large_texture_threshold =
+            fraction.map(|fraction| fraction.clamp(0.0, 1.0));
+        self
     }
 
-    /// Promotes the cache's Allocation by key making it recently used..
+    /// Sets the size buckets new layers are created with.
+    /// Sizes larger than the [`AtlasSet`]'s extent are clamped to it.
+    /// Existing layers keep their size.
     ///
-    pub fn promote_by_key(&mut self, key: U) {
-        if let Some(id) = self.lookup.get(&key) {
-            self.cache.promote(id);
-            self.last_used.insert(*id);
-        }
+    pub fn set_layer_sizes(&mut self, sizes: &[u32]) -> &mut Self {
+        self.layer_sizes = sizes
+            .iter()
+            .map(|&size| size.clamp(1, self.extent.width))
+            .collect();
+        self.layer_sizes.sort_unstable();
+        self.layer_sizes.dedup();
+        self
     }
 
-    /// Promotes the cache's Allocation by index making it recently used..
+    /// Enables trimming fully transparent rows and columns from the edges of
+    /// uploaded textures to save space. The trim is kept as a [`TrimOffset`]
+    /// so [`crate::Rect`] still places the texture where it originally was.
+    /// Only applies to Rgba8UnormSrgb textures. Packed Sheets are never trimmed.
     ///
-    pub fn promote(&mut self, id: usize) {
-        self.cache.promote(&id);
-        self.last_used.insert(id);
+    pub fn set_trim_transparent(&mut self, trim: bool) -> &mut Self {
+        self.trim_transparent = trim;
+        self
     }
 
-    /// Gets the [`Allocation`]'s index if it exists.
+    /// Sets the RGB color made fully transparent when files are decoded by
+    /// [`Texture::upload_from`]. None disables color keying.
     ///
-    pub fn lookup(&self, key: &U) -> Option<usize> {
-        self.lookup.get(key).copied()
+    pub fn set_color_key(&mut self, color_key: Option<[u8; 3]>) -> &mut Self {
+        self.color_key = color_key;
+        self
     }
 
-    /// Gets using key the reference of [`Allocation`] with key if it exists.
+    /// Sets the handler each [`AtlasEvent`] is reported to as it happens
+    /// such as to log layer growth or lower streaming quality when textures
+    /// get evicted. Replaces any previous handler.
     ///
-    pub fn peek_by_key(&mut self, key: &U) -> Option<&(Allocation<Data>, U)> {
-        if let Some(id) = self.lookup.get(key) {
-            self.store.get(*id)
-        } else {
-            None
+    pub fn on_event(
+        &mut self,
+        handler: impl FnMut(AtlasEvent) + 'static,
+    ) -> &mut Self {
+        self.event_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Removes the handler set by [`AtlasSet::on_event`].
+    ///
+    pub fn clear_event_handler(&mut self) -> &mut Self {
+        self.event_handler = None;
+        self
+    }
+
+    //Reports the event to the handler if one is set.
+    fn emit(&mut self, event: AtlasEvent) {
+        if let Some(handler) = &mut self.event_handler {
+            handler(event);
         }
     }
 
-    /// Gets using index the reference of [`Allocation`] with key if it exists.
+    /// Returns the [`TrimOffset`] of the [`Allocation`] at index if it was trimmed.
     ///
-    pub fn peek(&mut self, id: usize) -> Option<&(Allocation<Data>, U)> {
-        self.store.get(id)
+    pub fn trim_offset(&self, id: usize) -> Option<TrimOffset> {
+        self.trims.get(&id).copied()
     }
 
-    /// If [`Allocation`] using key exists.
+    /// Sets if the Textures uploaded to the [`AtlasSet`] have their colors
+    /// premultiplied by alpha. Only describes the data for
+    /// [`AtlasSet::format_info`] as uploads are stored as given.
     ///
-    pub fn contains_key(&mut self, key: &U) -> bool {
-        self.lookup.contains_key(key)
+    pub fn set_premultiplied(&mut self, premultiplied: bool) -> &mut Self {
+        self.premultiplied = premultiplied;
+        self
     }
 
-    /// If [`Allocation`] at id exists.
+    /// Returns the [`FormatInfo`] of the [`Allocation`] at index so read
+    /// back bytes can be interpreted. Returns None if the index does not exist.
     ///
-    pub fn contains(&mut self, id: usize) -> bool {
-        self.store.contains(id)
+    pub fn format_info(&self, id: usize) -> Option<FormatInfo> {
+        self.store.get(id).map(|_| FormatInfo {
+            format: self.format,
+            bytes_per_pixel: self.bytes_per_row(1),
+            premultiplied: self.premultiplied,
+        })
     }
 
-    /// Gets using key the [`Allocation`] if it exists.
-    /// Also Increments the Cache and adds to last_used list.
+    /// Sets the padding in pixels left empty around each new [`Allocation`].
+    /// Already loaded Allocations keep the padding they were packed with.
     ///
-    pub fn get_by_key(&mut self, key: &U) -> Option<Allocation<Data>> {
-        let id = *self.lookup.get(key)?;
-        if let Some((allocation, _)) = self.store.get(id) {
-            self.cache.promote(&id);
-            self.last_used.insert(id);
-            return Some(*allocation);
+    pub fn set_padding(&mut self, padding: u32) -> &mut Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Returns the padding in pixels surrounding an [`Allocation`] within
+    /// its layer as it was packed. Frames of a packed Sheet have none.
+    /// Returns None if the index does not exist.
+    ///
+    pub fn allocation_padding(&self, id: usize) -> Option<u32> {
+        self.store
+            .get(id)
+            .map(|_| self.paddings.get(&id).copied().unwrap_or(0))
+    }
+
+    //Trims the texture if trimming is enabled and the format is Rgba8UnormSrgb.
+    fn trim_upload<'a>(
+        &self,
+        bytes: &'a [u8],
+        width: u32,
+        height: u32,
+    ) -> (Cow<'a, [u8]>, u32, u32, Option<TrimOffset>) {
+        if self.trim_transparent
+            && self.format == wgpu::TextureFormat::Rgba8UnormSrgb
+        {
+            if let Some((trimmed, trim)) =
+                trim_transparent(bytes, width, height)
+            {
+                return (
+                    Cow::Owned(trimmed),
+                    trim.width,
+                    trim.height,
+                    Some(trim),
+                );
+            }
         }
 
-        None
+        (Cow::Borrowed(bytes), width, height, None)
     }
 
-    /// Gets using index the [`Allocation`] if it exists.
-    /// Also Increments the Cache and adds to last_used list.
+    /// Sets the budget in bytes for uploaded textures.
+    /// Uploads that would go over it are downscaled by half until they fit.
+    /// None disables the budget.
     ///
-    pub fn get(&mut self, id: usize) -> Option<Allocation<Data>> {
-        if let Some((allocation, _)) = self.store.get(id) {
-            self.cache.promote(&id);
-            self.last_used.insert(id);
-            return Some(*allocation);
-        }
+    pub fn set_memory_budget(&mut self, bytes: Option<u64>) -> &mut Self {
+        self.memory_budget = bytes;
+        self
+    }
 
-        None
+    /// Sets the budget in bytes of texture data written to the GPU per frame.
+    /// Uploads past it still get their [`Allocation`] right away but their
+    /// texture data is queued and written by [`AtlasSet::flush_pending`].
+    /// None writes every upload immediately.
+    ///
+    pub fn set_upload_budget_per_frame(
+        &mut self,
+        bytes: Option<u64>,
+    ) -> &mut Self {
+        self.upload_budget = bytes;
+        self
     }
 
-    /// Removed Texture by key.
-    /// Removing will leave anything using the texture inable to load the correct texture if
-    /// a new texture is loaded in the olds place.
-    /// TODO Redo texture system so texture allocations are not held by the images but instead
-    /// TODO are held by the system so we can reload images later on if they got unloaded.
+    /// Writes queued uploads until the per frame budget is reached and resets
+    /// the budget for the new frame. Call once per frame.
+    /// At least one queued upload is written each call so large textures still load.
     ///
-    /// returns the layer id if removed otherwise None for everything else.
+    /// Returns how many uploads are still queued.
     ///
-    pub fn remove_by_key(&mut self, key: &U) -> Option<usize> {
-        let id = *self.lookup.get(key)?;
-        let refcount = self.cache.pop(&id)?.saturating_sub(1);
+    pub fn flush_pending(&mut self, renderer: &GpuRenderer) -> usize {
+        self.uploaded_bytes = 0;
 
-        if self.use_ref_count && refcount > 0 {
-            self.cache.push(id, refcount);
-            return None;
+        while let Some((id, generation, bytes)) =
+            self.pending_uploads.pop_front()
+        {
+            // Skip uploads whose Allocation was removed while queued.
+            if !self.store.contains(id) || self.generation(id) != generation {
+                continue;
+            }
+
+            let size = bytes.len() as u64;
+
+            if self.uploaded_bytes > 0
+                && self
+                    .upload_budget
+                    .is_some_and(|budget| self.uploaded_bytes + size > budget)
+            {
+                self.pending_uploads.push_front((id, generation, bytes));
+                break;
+            }
+
+            let allocation = self.store[id].0;
+            self.upload_allocation(&bytes, &allocation, renderer);
+            self.uploaded_bytes += size;
         }
 
-        let (allocation, _) = self.store.remove(id);
-        self.last_used.remove(&id);
-        self.lookup.remove(key);
-        self.layers
-            .get_mut(allocation.layer)?
-            .deallocate(id, allocation.allocation);
-        Some(allocation.layer)
+        self.submit_uploads(renderer);
+        self.pending_uploads.len()
     }
 
-    /// Removed Texture by index.
-    /// Removing will leave anything using the texture inable to load the correct texture if
-    /// a new texture is loaded in the olds place.
-    /// TODO Redo texture system so texture allocations are not held by the images but instead
-    /// TODO are held by the system so we can reload images later on if they got unloaded.
+    /// Sets the [`AtlasSet`] to copy uploads through a ring of reusable staging
+    /// Buffers of chunk_size bytes instead of a new staging Buffer per upload.
+    /// None writes each upload directly using the Queue.
     ///
-    /// returns the layer id if removed otherwise None for everything else.
+    /// Staged uploads only reach the Texture once [`AtlasSet::submit_uploads`]
+    /// or [`AtlasSet::flush_pending`] is called so call either once per frame.
     ///
-    pub fn remove(&mut self, id: usize) -> Option<usize> {
-        let refcount = self.cache.pop(&id)?.saturating_sub(1);
+    pub fn set_staging_belt(
+        &mut self,
+        renderer: &GpuRenderer,
+        chunk_size: Option<u64>,
+    ) -> &mut Self {
+        self.submit_uploads(renderer);
+        self.staging = chunk_size.map(TextureStagingBelt::new);
+        self
+    }
 
-        if self.use_ref_count && refcount > 0 {
-            self.cache.push(id, refcount);
-            return None;
+    /// Submits the uploads copied into the staging belt to the GPU.
+    /// Does nothing if staging is disabled or nothing was uploaded.
+    ///
+    pub fn submit_uploads(&mut self, renderer: &GpuRenderer) {
+        if let Some(staging) = &mut self.staging {
+            staging.submit(renderer.queue());
         }
-
-        let (allocation, key) = self.store.remove(id);
-        self.last_used.remove(&id);
-        self.lookup.remove(&key);
-        self.layers
-            .get_mut(allocation.layer)?
-            .deallocate(id, allocation.allocation);
-        Some(allocation.layer)
     }
 
-    /// Uploads Texture byte array to the AtlasSet returning the created [`Allocation`]s Index.
-    ///
-    /// # Arguments
-    /// - bytes: Textures Byte array.
-    /// - width: Width of the Texture.
-    /// - height: Height of the Texture.
-    /// - data: any specail generic data for the texture.
+    /// If the texture data of the [`Allocation`] at index is still queued.
     ///
-    #[allow(clippy::too_many_arguments)]
-    pub fn upload(
+    pub fn is_pending(&self, id: usize) -> bool {
+        self.pending_uploads.iter().any(|(pending, generation, _)| {
+            *pending == id && *generation == self.generation(id)
+        })
+    }
+
+    //Writes the texture data now if within the upload budget otherwise queues it.
+    fn queue_upload(
         &mut self,
-        key: U,
+        id: usize,
         bytes: &[u8],
-        width: u32,
-        height: u32,
-        data: Data,
         renderer: &GpuRenderer,
-    ) -> Option<usize> {
-        if let Some(&id) = self.lookup.get(&key) {
-            Some(id)
-        } else {
-            let allocation = {
-                let nlayers = self.layers.len();
-                let allocation = self.allocate(width, height, data)?;
-                self.grow(self.layers.len() - nlayers, renderer);
-
-                allocation
-            };
+    ) {
+        let size = bytes.len() as u64;
+        let over_budget = self.upload_budget.is_some_and(|budget| {
+            !self.pending_uploads.is_empty()
+                || self.uploaded_bytes + size > budget
+        });
 
+        if over_budget {
+            self.pending_uploads.push_back((
+                id,
+                self.generation(id),
+                bytes.to_vec(),
+            ));
+        } else {
+            let allocation = self.store[id].0;
             self.upload_allocation(bytes, &allocation, renderer);
-            let id = self.store.insert((allocation, key.clone()));
-            self.layers[allocation.layer].insert_index(id);
-            self.lookup.insert(key, id);
-            self.cache.push(id, 1);
-            Some(id)
+            self.uploaded_bytes += size;
         }
     }
 
-    /// Uploads Texture byte array to the AtlasSet returning the created [`Allocation`] and Index.
+    /// Returns how many bytes the uploaded textures use within the Layers.
+    ///
+    pub fn memory_usage(&self) -> u64 {
+        self.store
+            .iter()
+            .filter(|(id, _)| !self.frames.contains_key(id))
+            .map(|(_, (allocation, _))| {
+                let (width, height) = allocation.size();
+                self.bytes_per_row(width) as u64 * height as u64
+            })
+            .sum()
+    }
+
+    //Downscales the texture by half until it fits within the memory budget.
+    //Only Rgba8UnormSrgb and R8Unorm textures can be downscaled.
+    fn fit_budget<'a>(
+        &self,
+        bytes: &'a [u8],
+        width: u32,
+        height: u32,
+    ) -> (Cow<'a, [u8]>, u32, u32) {
+        let budget = match self.memory_budget {
+            Some(budget) => budget,
+            None => return (Cow::Borrowed(bytes), width, height),
+        };
+
+        let usage = self.memory_usage();
+        let size_of = |width: u32, height: u32| {
+            self.bytes_per_row(width) as u64 * height as u64
+        };
+        let (mut new_width, mut new_height) = (width, height);
+
+        while usage + size_of(new_width, new_height) > budget
+            && (new_width > 1 || new_height > 1)
+        {
+            new_width = (new_width / 2).max(1);
+            new_height = (new_height / 2).max(1);
+        }
+
+        if (new_width, new_height) == (width, height) {
+            return (Cow::Borrowed(bytes), width, height);
+        }
+
+        let filter = image::imageops::FilterType::Triangle;
+        let resized = match self.format {
+            wgpu::TextureFormat::Rgba8UnormSrgb => image::RgbaImage::from_raw(
+                width,
+                height,
+                bytes.to_vec(),
+            )
+            .map(|image| {
+                image::imageops::resize(&image, new_width, new_height, filter)
+                    .into_raw()
+            }),
+            wgpu::TextureFormat::R8Unorm => image::GrayImage::from_raw(
+                width,
+                height,
+                bytes.to_vec(),
+            )
+            .map(|image| {
+                image::imageops::resize(&image, new_width, new_height, filter)
+                    .into_raw()
+            }),
+            _ => None,
+        };
+
+        match resized {
+            Some(resized) => (Cow::Owned(resized), new_width, new_height),
+            None => (Cow::Borrowed(bytes), width, height),
+        }
+    }
+
+    /// Uploads a new Texture Byte Array into the GPU AtlasSets Layer.
+    ///
+    pub fn upload_allocation(
+        &mut self,
+        buffer: &[u8],
+        allocation: &Allocation<Data>,
+        renderer: &GpuRenderer,
+    ) {
+        let (x, y) = allocation.position();
+        let (width, height) = allocation.size();
+        let layer = allocation.layer;
+
+        self.write_region(
+            renderer,
+            buffer,
+            wgpu::Origin3d {
+                x,
+                y,
+                z: layer as u32,
+            },
+            width,
+            height,
+        );
+    }
+
+    //Writes texture data into the Texture through the staging belt if enabled.
+    fn write_region(
+        &mut self,
+        renderer: &GpuRenderer,
+        buffer: &[u8],
+        origin: wgpu::Origin3d,
+        width: u32,
+        height: u32,
+    ) {
+        self.mark_mutated();
+        let bytes_per_row = self.bytes_per_row(width);
+        let destination = wgpu::ImageCopyTexture {
+            texture: &self.texture,
+            mip_level: 0,
+            origin,
+            aspect: wgpu::TextureAspect::All,
+        };
+        let extent = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+
+        if let Some(staging) = &mut self.staging {
+            staging.write_texture(
+                renderer.device(),
+                buffer,
+                bytes_per_row,
+                destination,
+                extent,
+            );
+        } else {
+            renderer.queue().write_texture(
+                destination,
+                buffer,
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+                extent,
+            );
+        }
+    }
+
+    /// Uploads a Byte Array into only a Sub Region of an existing [`Allocation`].
+    /// Used for dynamic textures that only change a small area each frame.
+    /// The region is given within the uploaded Texture. Trimmed Textures only
+    /// get the part of the region within their trimmed area written. Uploads
+    /// still waiting on the upload budget get the region patched in.
+    /// Returns None if the id does not exist, the region is outside of the
+    /// Texture or the Texture got downscaled to fit the memory budget.
+    ///
+    /// # Arguments
+    /// - id: Index of the [`Allocation`] to update.
+    /// - offset: X and Y offset within the Texture to start writing at.
+    /// - size: Width and Height of the region to write.
+    /// - buffer: Byte array of the region only. Must be size.x * size.y pixels.
+    ///
+    pub fn update_sub_region(
+        &mut self,
+        id: usize,
+        offset: UVec2,
+        size: UVec2,
+        buffer: &[u8],
+        renderer: &GpuRenderer,
+    ) -> Option<()> {
+        let (allocation, _) = self.store.get(id)?;
+        let (x, y) = allocation.position();
+        let layer = allocation.layer;
+        let trim = self.trim_offset(id);
+        let (width, height) = match trim {
+            Some(trim) => (trim.source_width, trim.source_height),
+            None => allocation.size(),
+        };
+
+        if self.downscaled.contains(&id)
+            || size.x == 0
+            || size.y == 0
+            || offset.x.checked_add(size.x)? > width
+            || offset.y.checked_add(size.y)? > height
+            || buffer.len() < (self.bytes_per_row(size.x) * size.y) as usize
+        {
+            return None;
+        }
+
+        // Clips the region to the trimmed area the Allocation holds.
+        let (min, max) = match trim {
+            Some(trim) => (
+                offset.max(UVec2::new(trim.x, trim.y)),
+                (offset + size)
+                    .min(UVec2::new(trim.x + trim.width, trim.y + trim.height)),
+            ),
+            None => (offset, offset + size),
+        };
+
+        if min.x >= max.x || min.y >= max.y {
+            return Some(());
+        }
+
+        let clipped = max - min;
+        let bytes: Cow<[u8]> = if clipped == size {
+            Cow::Borrowed(buffer)
+        } else {
+            let row = self.bytes_per_row(size.x) as usize;
+            let skip = self.bytes_per_row(min.x - offset.x) as usize;
+            let len = self.bytes_per_row(clipped.x) as usize;
+
+            Cow::Owned(
+                (min.y - offset.y..max.y - offset.y)
+                    .flat_map(|y| {
+                        let start = y as usize * row + skip;
+                        buffer[start..start + len].iter().copied()
+                    })
+                    .collect(),
+            )
+        };
+        let (trim_x, trim_y) = trim.map_or((0, 0), |trim| (trim.x, trim.y));
+        let (local_x, local_y) = (min.x - trim_x, min.y - trim_y);
+
+        // A queued upload would overwrite the region once flushed so the
+        // queued bytes get patched instead.
+        let generation = self.generation(id);
+        let pending_row = self.bytes_per_row(allocation.size().0) as usize;
+        let skip = self.bytes_per_row(local_x) as usize;
+        let len = self.bytes_per_row(clipped.x) as usize;
+
+        if let Some((_, _, pending)) =
+            self.pending_uploads
+                .iter_mut()
+                .find(|(pending, queued, _)| {
+                    *pending == id && *queued == generation
+                })
+        {
+            for row in 0..clipped.y as usize {
+                let start = (local_y as usize + row) * pending_row + skip;
+
+                pending[start..start + len]
+                    .copy_from_slice(&bytes[row * len..(row + 1) * len]);
+            }
+
+            return Some(());
+        }
+
+        self.write_region(
+            renderer,
+            &bytes,
+            wgpu::Origin3d {
+                x: x + local_x,
+                y: y + local_y,
+                z: layer as u32,
+            },
+            clipped.x,
+            clipped.y,
+        );
+
+        Some(())
+    }
+
+    //Returns how many bytes a row of width pixels takes within the Texture.
+    fn bytes_per_row(&self, width: u32) -> u32 {
+        if self.format == wgpu::TextureFormat::Rgba8UnormSrgb {
+            4 * width
+        } else {
+            width
+        }
+    }
+
+    /// Clears all information of stored Textures and Allocations.
+    ///
+    /// This Does not Empty the [`AtlasSet`]s GPU Texture Buffer.
+    /// As we normally just overwrite the buffer when we add new Allocations.
+    ///
+    pub fn clear(&mut self) {
+        self.clear_entries();
+    }
+
+    /// Removes every stored Texture and resets each layer's Allocator while
+    /// keeping the GPU Texture and its layers allocated. Use between scenes
+    /// to start over without recreating the Texture. Older [`TextureHandle`]s
+    /// become invalid.
+    ///
+    pub fn clear_entries(&mut self) {
+        self.mark_mutated();
+
+        for layer in self.layers.iter_mut() {
+            layer.clear();
+        }
+
+        // Indexs never removed before are still at their first generation.
+        let ids: Vec<usize> = self.store.iter().map(|(id, _)| id).collect();
+
+        for id in ids {
+            self.bump_generation(id);
+        }
+
+        self.store.clear();
+        self.lookup.clear();
+        self.frames.clear();
+        self.user_data.clear();
+        self.trims.clear();
+        self.paddings.clear();
+        self.downscaled.clear();
+        self.pending_uploads.clear();
+        self.cache.clear();
+        self.last_used.clear();
+        self.uploaded_bytes = 0;
+        self.compaction = None;
+    }
+
+    //TODO Make function that checks for unloading and migrating.
+    /// Clears the last_used cache's.
+    ///
+    pub fn trim(&mut self) {
+        self.last_used.clear();
+    }
+
+    /// Promotes the cache's Allocation by key making it recently used..
+    ///
+    pub fn promote_by_key(&mut self, key: U) {
+        if let Some(id) = self.lookup.get(&key) {
+            self.cache.promote(id);
+            self.last_used.insert(*id);
+        }
+    }
+
+    /// Promotes the cache's Allocation by index making it recently used..
+    ///
+    pub fn promote(&mut self, id: usize) {
+        self.cache.promote(&id);
+        self.last_used.insert(id);
+    }
+
+    /// Gets the [`Allocation`]'s index if it exists.
+    ///
+    pub fn lookup(&self, key: &U) -> Option<usize> {
+        self.lookup.get(key).copied()
+    }
+
+    /// Gets using key the reference of [`Allocation`] with key if it exists.
+    ///
+    pub fn peek_by_key(&mut self, key: &U) -> Option<&(Allocation<Data>, U)> {
+        if let Some(id) = self.lookup.get(key) {
+            self.store.get(*id)
+        } else {
+            None
+        }
+    }
+
+    /// Gets using index the reference of [`Allocation`] with key if it exists.
+    ///
+    pub fn peek(&mut self, id: usize) -> Option<&(Allocation<Data>, U)> {
+        self.store.get(id)
+    }
+
+    /// If [`Allocation`] using key exists.
+    ///
+    pub fn contains_key(&mut self, key: &U) -> bool {
+        self.lookup.contains_key(key)
+    }
+
+    /// If [`Allocation`] at id exists.
+    ///
+    pub fn contains(&mut self, id: usize) -> bool {
+        self.store.contains(id)
+    }
+
+    /// Gets using key the [`Allocation`] if it exists.
+    /// Also Increments the Cache and adds to last_used list.
+    ///
+    pub fn get_by_key(&mut self, key: &U) -> Option<Allocation<Data>> {
+        let id = *self.lookup.get(key)?;
+        if let Some((allocation, _)) = self.store.get(id) {
+            self.cache.promote(&id);
+            self.last_used.insert(id);
+            return Some(*allocation);
+        }
+
+        None
+    }
+
+    /// Gets using index the [`Allocation`] if it exists.
+    /// Also Increments the Cache and adds to last_used list.
+    ///
+    pub fn get(&mut self, id: usize) -> Option<Allocation<Data>> {
+        if let Some((allocation, _)) = self.store.get(id) {
+            self.cache.promote(&id);
+            self.last_used.insert(id);
+            return Some(*allocation);
+        }
+
+        None
+    }
+
+    /// Returns the size the [`Allocation`] at index is meant to be displayed at.
+    /// Trimmed Textures return their original untrimmed size, otherwise the
+    /// packed size is returned. Does not touch the Cache.
+    ///
+    pub fn dimensions(&self, id: usize) -> Option<Vec2> {
+        let (allocation, _) = self.store.get(id)?;
+        let (width, height) = match self.trim_offset(id) {
+            Some(trim) => (trim.source_width, trim.source_height),
+            None => allocation.size(),
+        };
+
+        Some(Vec2::new(width as f32, height as f32))
+    }
+
+    /// Returns a [`TextureHandle`] for the [`Allocation`] at index if it exists.
+    ///
+    pub fn handle(&self, id: usize) -> Option<TextureHandle> {
+        if self.store.contains(id) {
+            Some(TextureHandle::new(id, self.generation(id)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a [`TextureHandle`] for the [`Allocation`] using key if it exists.
+    ///
+    pub fn handle_by_key(&self, key: &U) -> Option<TextureHandle> {
+        self.handle(*self.lookup.get(key)?)
+    }
+
+    /// If the [`TextureHandle`] still points to the same [`Allocation`].
+    ///
+    pub fn contains_handle(&self, handle: TextureHandle) -> bool {
+        self.store.contains(handle.index)
+            && self.generation(handle.index) == handle.generation
+    }
+
+    /// Gets using [`TextureHandle`] the [`Allocation`] if it exists.
+    /// Returns None if the Handle is outdated.
+    /// Also Increments the Cache and adds to last_used list.
+    ///
+    pub fn get_by_handle(
+        &mut self,
+        handle: TextureHandle,
+    ) -> Option<Allocation<Data>> {
+        if !self.contains_handle(handle) {
+            return None;
+        }
+
+        self.get(handle.index)
+    }
+
+    /// Attaches Application Data to the [`Allocation`] at index.
+    /// Replaces any Data already attached. Returns None if the index does not exist.
+    ///
+    pub fn set_user_data(
+        &mut self,
+        id: usize,
+        data: Box<dyn Any>,
+    ) -> Option<()> {
+        if !self.store.contains(id) {
+            return None;
+        }
+
+        self.user_data.insert(id, data);
+        Some(())
+    }
+
+    /// Gets a reference to the Application Data attached to the [`Allocation`] at index.
+    /// Returns None if no Data is attached or it is not of type T.
+    ///
+    pub fn user_data<T: Any>(&self, id: usize) -> Option<&T> {
+        self.user_data.get(&id)?.downcast_ref::<T>()
+    }
+
+    /// Gets a mutable reference to the Application Data attached to the [`Allocation`] at index.
+    /// Returns None if no Data is attached or it is not of type T.
+    ///
+    pub fn user_data_mut<T: Any>(&mut self, id: usize) -> Option<&mut T> {
+        self.user_data.get_mut(&id)?.downcast_mut::<T>()
+    }
+
+    /// Removes and returns the Application Data attached to the [`Allocation`] at index.
+    ///
+    pub fn take_user_data(&mut self, id: usize) -> Option<Box<dyn Any>> {
+        self.user_data.remove(&id)
+    }
+
+    //Returns the current generation of the store index.
+    fn generation(&self, id: usize) -> u32 {
+        self.generations.get(id).copied().unwrap_or(0)
+    }
+
+    /// Returns the current revision of the [`Allocation`] at index.
+    /// The revision changes whenever the [`Allocation`] moves within the Texture.
+    ///
+    pub fn revision(&self, id: usize) -> u32 {
+        self.revisions.get(id).copied().unwrap_or(0)
+    }
+
+    //Increments the revision of the index so users refresh their UV's.
+    fn bump_revision(&mut self, id: usize) {
+        if self.revisions.len() <= id {
+            self.revisions.resize(id + 1, 0);
+        }
+
+        self.revisions[id] = self.revisions[id].wrapping_add(1);
+    }
+
+    /// Repacks every layer's [`Allocation`]s from largest to smallest to undo
+    /// fragmentation and moves their Texture data to match.
+    /// Layers that fail to repack are left untouched.
+    /// Moved [`Allocation`]s get a new revision so [`crate::Rect`]'s using them
+    /// refresh their UV's on their next update.
+    ///
+    /// Returns the remap of each moved index and its new [`Allocation`].
+    ///
+    pub fn compact(
+        &mut self,
+        renderer: &GpuRenderer,
+    ) -> Vec<(usize, Allocation<Data>)> {
+        let mut remap = Vec::new();
+        let mut moves = Vec::new();
+        self.compaction = None;
+
+        for layer in self.layers.iter_mut() {
+            let mut ids: Vec<usize> = layer.allocated.iter().copied().collect();
+            ids.sort_by_key(|&id| {
+                let (width, height) = self.store[id].0.size();
+                std::cmp::Reverse(width * height)
+            });
+
+            let mut allocator =
+                Allocator::with_options(layer.size(), &self.allocator_options);
+            let mut allocations = Vec::with_capacity(ids.len());
+
+            for &id in &ids {
+                let (width, height) = self.store[id].0.size();
+                let padding = self.paddings.get(&id).copied().unwrap_or(0);
+
+                match allocator.allocate_padded(width, height, padding) {
+                    Some(allocation) => allocations.push((id, allocation)),
+                    None => break,
+                }
+            }
+
+            if allocations.len() != ids.len() {
+                continue;
+            }
+
+            layer.allocator = allocator;
+
+            for (id, allocation) in allocations {
+                let (old, _) = &mut self.store[id];
+
+                if old.allocation.rectangle.min != allocation.rectangle.min {
+                    moves.push((id, *old, allocation));
+                }
+
+                old.allocation = allocation;
+            }
+        }
+
+        if moves.is_empty() {
+            return remap;
+        }
+
+        // Staged uploads must land before their regions get moved.
+        self.submit_uploads(renderer);
+
+        // Copy the whole texture first so moved regions can not overwrite
+        // each other while being copied back.
+        let extent = wgpu::Extent3d {
+            width: self.extent.width,
+            height: self.extent.height,
+            depth_or_array_layers: self.layers.len() as u32,
+        };
+        let scratch =
+            renderer.device().create_texture(&wgpu::TextureDescriptor {
+                label: Some("Texture compaction scratch"),
+                size: extent,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.format,
+                usage: wgpu::TextureUsages::COPY_DST
+                    | wgpu::TextureUsages::COPY_SRC,
+                view_formats: &[self.format],
+            });
+
+        let mut encoder = renderer.device().create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("Texture compaction encoder"),
+            },
+        );
+
+        encoder.copy_texture_to_texture(
+            self.texture.as_image_copy(),
+            scratch.as_image_copy(),
+            extent,
+        );
+
+        for (id, old, allocation) in moves {
+            let (x, y) = old.position();
+            let (width, height) = old.size();
+
+            encoder.copy_texture_to_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &scratch,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x,
+                        y,
+                        z: old.layer as u32,
+                    },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                wgpu::ImageCopyTexture {
+                    texture: &self.texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x: allocation.rectangle.min.x as u32,
+                        y: allocation.rectangle.min.y as u32,
+                        z: old.layer as u32,
+                    },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+            );
+
+            self.move_frames(id, old, allocation, &mut remap);
+            self.bump_revision(id);
+            remap.push((id, self.store[id].0));
+        }
+
+        renderer.queue().submit(std::iter::once(encoder.finish()));
+        self.emit(AtlasEvent::Compacted(remap.len()));
+        remap
+    }
+
+    //Moves the Frames of a packed Sheet along with the Sheet.
+    fn move_frames(
+        &mut self,
+        id: usize,
+        old: Allocation<Data>,
+        allocation: guillotiere::Allocation,
+        remap: &mut Vec<(usize, Allocation<Data>)>,
+    ) {
+        let offset = allocation.rectangle.min - old.allocation.rectangle.min;
+        let frames: Vec<usize> = self
+            .frames
+            .iter()
+            .filter(|(_, &sheet)| sheet == id)
+            .map(|(&frame, _)| frame)
+            .collect();
+
+        for frame in frames {
+            if let Some((frame_allocation, _)) = self.store.get_mut(frame) {
+                frame_allocation.allocation.id = allocation.id;
+                frame_allocation.allocation.rectangle =
+                    frame_allocation.allocation.rectangle.translate(offset);
+                let frame_allocation = *frame_allocation;
+
+                self.bump_revision(frame);
+                remap.push((frame, frame_allocation));
+            }
+        }
+    }
+
+    /// Incremental version of [`AtlasSet::compact`] that copies at most
+    /// budget_bytes into a new Texture per call, always making at least one copy.
+    /// Rendering keeps using the old Texture and UV's until every copy is done,
+    /// then the new Texture and the repacked [`Allocation`]s get applied at once.
+    /// Uploading, removing or growing layers while in progress cancels it.
+    ///
+    /// Call it once per frame until it returns [`CompactProgress::Done`].
+    ///
+    pub fn compact_step(
+        &mut self,
+        renderer: &mut GpuRenderer,
+        budget_bytes: u64,
+    ) -> CompactProgress<Data> {
+        if let Some(compaction) = &self.compaction {
+            if compaction.mutations != self.mutations {
+                self.compaction = None;
+                return CompactProgress::Cancelled;
+            }
+        } else {
+            self.compaction = self.plan_compaction(renderer);
+        }
+
+        let Some(mut compaction) = self.compaction.take() else {
+            return CompactProgress::Idle;
+        };
+
+        // Staged uploads must land in the old Texture before copying from it.
+        self.submit_uploads(renderer);
+
+        let mut encoder = renderer.device().create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("Texture compaction encoder"),
+            },
+        );
+        let mut spent = 0u64;
+
+        while let Some(copy) = compaction.copies.front() {
+            let (layer, from, to, (width, height)) = match *copy {
+                CompactCopy::Layer(layer) => (
+                    layer,
+                    (0, 0),
+                    (0, 0),
+                    (self.extent.width, self.extent.height),
+                ),
+                CompactCopy::Region {
+                    layer,
+                    from,
+                    to,
+                    size,
+                } => (layer, from, to, size),
+            };
+            let bytes = self.bytes_per_row(width) as u64 * height as u64;
+
+            if spent > 0 && spent + bytes > budget_bytes {
+                break;
+            }
+
+            encoder.copy_texture_to_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &self.texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x: from.0,
+                        y: from.1,
+                        z: layer,
+                    },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                wgpu::ImageCopyTexture {
+                    texture: &compaction.target,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x: to.0,
+                        y: to.1,
+                        z: layer,
+                    },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+            );
+
+            compaction.copies.pop_front();
+            spent += bytes;
+        }
+
+        renderer.queue().submit(std::iter::once(encoder.finish()));
+        compaction.copied_bytes += spent;
+
+        if !compaction.copies.is_empty() {
+            let progress = CompactProgress::InProgress {
+                copied_bytes: compaction.copied_bytes,
+                total_bytes: compaction.total_bytes,
+            };
+
+            self.compaction = Some(compaction);
+            return progress;
+        }
+
+        CompactProgress::Done(self.finish_compaction(renderer, compaction))
+    }
+
+    //Repacks each layer like compact and lists the copies needed to build
+    //the compacted Texture. Returns None if nothing would move.
+    fn plan_compaction(&self, renderer: &GpuRenderer) -> Option<Compaction> {
+        let mut copies = VecDeque::new();
+        let mut layouts = Vec::new();
+        let mut total_bytes = 0u64;
+
+        for (index, layer) in self.layers.iter().enumerate() {
+            let mut ids: Vec<usize> = layer.allocated.iter().copied().collect();
+            ids.sort_by_key(|&id| {
+                let (width, height) = self.store[id].0.size();
+                std::cmp::Reverse(width * height)
+            });
+
+            let mut allocator =
+                Allocator::with_options(layer.size(), &self.allocator_options);
+            let mut allocations = Vec::with_capacity(ids.len());
+
+            for &id in &ids {
+                let (width, height) = self.store[id].0.size();
+                let padding = self.paddings.get(&id).copied().unwrap_or(0);
+
+                match allocator.allocate_padded(width, height, padding) {
+                    Some(allocation) => allocations.push((id, allocation)),
+                    None => break,
+                }
+            }
+
+            let moved = allocations.iter().any(|(id, allocation)| {
+                self.store[*id].0.allocation.rectangle.min
+                    != allocation.rectangle.min
+            });
+
+            if allocations.len() != ids.len() || !moved {
+                copies.push_back(CompactCopy::Layer(index as u32));
+                total_bytes += self.bytes_per_row(self.extent.width) as u64
+                    * self.extent.height as u64;
+                continue;
+            }
+
+            for (id, allocation) in &allocations {
+                let old = self.store[*id].0;
+                let (width, height) = old.size();
+
+                copies.push_back(CompactCopy::Region {
+                    layer: index as u32,
+                    from: old.position(),
+                    to: (
+                        allocation.rectangle.min.x as u32,
+                        allocation.rectangle.min.y as u32,
+                    ),
+                    size: (width, height),
+                });
+                total_bytes += self.bytes_per_row(width) as u64 * height as u64;
+            }
+
+            layouts.push((index, allocator, allocations));
+        }
+
+        if layouts.is_empty() {
+            return None;
+        }
+
+        let target =
+            renderer.device().create_texture(&wgpu::TextureDescriptor {
+                label: Some("Texture"),
+                size: wgpu::Extent3d {
+                    width: self.extent.width,
+                    height: self.extent.height,
+                    depth_or_array_layers: self.layers.len() as u32,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.format,
+                usage: self.texture_usage(),
+                view_formats: &[self.format],
+            });
+
+        Some(Compaction {
+            target,
+            copies,
+            layouts,
+            mutations: self.mutations,
+            copied_bytes: 0,
+            total_bytes,
+        })
+    }
+
+    //Swaps in the compacted Texture and applies the repacked allocations.
+    fn finish_compaction(
+        &mut self,
+        renderer: &mut GpuRenderer,
+        compaction: Compaction,
+    ) -> Vec<(usize, Allocation<Data>)> {
+        let mut remap = Vec::new();
+
+        self.texture = compaction.target;
+        self.texture_view =
+            self.texture.create_view(&wgpu::TextureViewDescriptor {
+                label: Some("Texture Atlas"),
+                format: Some(self.format),
+                dimension: Some(wgpu::TextureViewDimension::D2Array),
+                aspect: wgpu::TextureAspect::All,
+                base_mip_level: 0,
+                mip_level_count: Some(1),
+                base_array_layer: 0,
+                array_layer_count: Some(self.layers.len() as u32),
+            });
+        self.layer_views = Self::create_layer_views(
+            &self.texture,
+            self.format,
+            self.layers.len() as u32,
+        );
+        self.texture_group = TextureGroup::from_view_with_anisotropy(
+            renderer,
+            &self.texture_view,
+            TextureLayout,
+            wgpu::FilterMode::Nearest,
+            self.anisotropy,
+        );
+
+        for (layer, allocator, allocations) in compaction.layouts {
+            self.layers[layer].allocator = allocator;
+
+            for (id, allocation) in allocations {
+                let old = self.store[id].0;
+
+                if old.allocation.rectangle.min == allocation.rectangle.min {
+                    self.store[id].0.allocation = allocation;
+                    continue;
+                }
+
+                self.store[id].0.allocation = allocation;
+                self.move_frames(id, old, allocation, &mut remap);
+                self.bump_revision(id);
+                remap.push((id, self.store[id].0));
+            }
+        }
+
+        self.emit(AtlasEvent::Compacted(remap.len()));
+        remap
+    }
+
+    //Increments the mutation count so an in progress compaction gets cancelled.
+    fn mark_mutated(&mut self) {
+        self.mutations = self.mutations.wrapping_add(1);
+    }
+
+    //Increments the generation of the index so old handles become invalid.
+    fn bump_generation(&mut self, id: usize) {
+        if self.generations.len() <= id {
+            self.generations.resize(id + 1, 0);
+        }
+
+        self.generations[id] = self.generations[id].wrapping_add(1);
+    }
+
+    /// Adds a reference to the [`Allocation`] at index.
+    /// Uploading counts as the first reference.
+    ///
+    /// returns the new reference count if the index exists.
+    ///
+    pub fn retain(&mut self, id: usize) -> Option<usize> {
+        let refcount = self.cache.peek_mut(&id)?;
+        *refcount = refcount.saturating_add(1);
+        Some(*refcount)
+    }
+
+    /// Drops a reference to the [`Allocation`] at index without removing it.
+    /// Allocations with no references are freed by [`AtlasSet::collect_unreferenced`].
+    ///
+    /// returns the new reference count if the index exists.
+    ///
+    pub fn release(&mut self, id: usize) -> Option<usize> {
+        let refcount = self.cache.peek_mut(&id)?;
+        *refcount = refcount.saturating_sub(1);
+        Some(*refcount)
+    }
+
+    /// Returns the reference count of the [`Allocation`] at index if it exists.
+    ///
+    pub fn ref_count(&self, id: usize) -> Option<usize> {
+        self.cache.peek(&id).copied()
+    }
+
+    /// Returns every resident [`Allocation`] with its key, layer, rect and
+    /// reference count. Packed frames are included and share their Sheets layer.
+    ///
+    pub fn entries(&self) -> impl Iterator<Item = AtlasEntry<'_, U>> + '_ {
+        self.store.iter().map(|(id, (allocation, key))| AtlasEntry {
+            key,
+            id,
+            layer: allocation.layer,
+            rect: allocation.rect(),
+            ref_count: self.ref_count(id).unwrap_or(0),
+        })
+    }
+
+    /// Deallocates every [`Allocation`] with no references left.
+    /// Meant to be called at a safe point such as after a scene change.
+    ///
+    /// returns how many [`Allocation`]s were freed including packed frames.
+    ///
+    pub fn collect_unreferenced(&mut self) -> usize {
+        let ids: Vec<usize> = self
+            .cache
+            .iter()
+            .filter(|(_, &refcount)| refcount == 0)
+            .map(|(&id, _)| id)
+            .collect();
+        let count = self.store.len();
+
+        for id in ids {
+            self.evict(id);
+        }
+
+        count - self.store.len()
+    }
+
+    /// Removed Texture by key.
+    /// Removing will leave anything using the texture inable to load the correct texture if
+    /// a new texture is loaded in the olds place.
+    /// TODO Redo texture system so texture allocations are not held by the images but instead
+    /// TODO are held by the system so we can reload images later on if they got unloaded.
+    ///
+    /// returns the layer id if removed otherwise None for everything else.
+    ///
+    pub fn remove_by_key(&mut self, key: &U) -> Option<usize> {
+        let id = *self.lookup.get(key)?;
+        self.remove(id)
+    }
+
+    /// Removed Texture by index.
+    /// Removing will leave anything using the texture inable to load the correct texture if
+    /// a new texture is loaded in the olds place.
+    /// TODO Redo texture system so texture allocations are not held by the images but instead
+    /// TODO are held by the system so we can reload images later on if they got unloaded.
+    ///
+    /// returns the layer id if removed otherwise None for everything else.
+    ///
+    pub fn remove(&mut self, id: usize) -> Option<usize> {
+        self.remove_with_event(id, AtlasEvent::Removed)
+    }
+
+    //Removes an unused Texture to make room reporting it as evicted.
+    fn evict(&mut self, id: usize) -> Option<usize> {
+        self.remove_with_event(id, AtlasEvent::Evicted)
+    }
+
+    //Removes the Texture and any Frames packed in it reporting each with event.
+    fn remove_with_event(
+        &mut self,
+        id: usize,
+        event: fn(usize) -> AtlasEvent,
+    ) -> Option<usize> {
+        let refcount = self.cache.pop(&id)?.saturating_sub(1);
+
+        if self.use_ref_count && refcount > 0 {
+            self.cache.push(id, refcount);
+            return None;
+        }
+
+        let (allocation, key) = self.store.remove(id);
+        self.mark_mutated();
+        self.bump_generation(id);
+        self.user_data.remove(&id);
+        self.trims.remove(&id);
+        self.paddings.remove(&id);
+        self.downscaled.remove(&id);
+        self.last_used.remove(&id);
+        self.lookup.remove(&key);
+        self.emit(event(id));
+
+        // Frames only point into their Sheets Allocation.
+        if self.frames.remove(&id).is_some() {
+            return Some(allocation.layer);
+        }
+
+        // Frames can not outlive the Sheet they point into.
+        let frames: Vec<usize> = self
+            .frames
+            .iter()
+            .filter(|(_, &sheet)| sheet == id)
+            .map(|(&frame, _)| frame)
+            .collect();
+
+        for frame in frames {
+            self.frames.remove(&frame);
+
+            if self.store.contains(frame) {
+                let (_, frame_key) = self.store.remove(frame);
+                self.bump_generation(frame);
+                self.user_data.remove(&frame);
+                self.trims.remove(&frame);
+                self.last_used.remove(&frame);
+                self.lookup.remove(&frame_key);
+                self.cache.pop(&frame);
+                self.emit(event(frame));
+            }
+        }
+
+        let layer = self.layers.get_mut(allocation.layer)?;
+        layer.deallocate(id, allocation.allocation);
+
+        if layer.deallocations() == self.deallocations_limit {
+            self.emit(AtlasEvent::PurgeThresholdHit(allocation.layer));
+        }
+
+        Some(allocation.layer)
+    }
+
+    /// Uploads Texture byte array to the AtlasSet returning the created [`Allocation`]s Index.
+    ///
+    /// # Arguments
+    /// - bytes: Textures Byte array.
+    /// - width: Width of the Texture.
+    /// - height: Height of the Texture.
+    /// - data: any specail generic data for the texture.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn upload(
+        &mut self,
+        key: U,
+        bytes: &[u8],
+        width: u32,
+        height: u32,
+        data: Data,
+        renderer: &GpuRenderer,
+    ) -> Option<usize> {
+        if let Some(&id) = self.lookup.get(&key) {
+            Some(id)
+        } else {
+            let (bytes, width, height, trim) =
+                self.trim_upload(bytes, width, height);
+            let (bytes, scaled_width, scaled_height) =
+                self.fit_budget(&bytes, width, height);
+            let allocation = {
+                let nlayers = self.layers.len();
+                let allocation =
+                    self.allocate(scaled_width, scaled_height, data)?;
+                self.grow(self.layers.len() - nlayers, renderer);
+
+                allocation
+            };
+
+            let id = self.store.insert((allocation, key.clone()));
+            self.queue_upload(id, &bytes, renderer);
+            self.layers[allocation.layer].insert_index(id);
+            self.lookup.insert(key, id);
+            self.cache.push(id, 1);
+
+            if let Some(trim) = trim {
+                self.trims.insert(id, trim);
+            }
+
+            if self.padding > 0 {
+                self.paddings.insert(id, self.padding);
+            }
+
+            if (scaled_width, scaled_height) != (width, height) {
+                self.downscaled.insert(id);
+            }
+
+            Some(id)
+        }
+    }
+
+    /// Uploads Texture byte array to the AtlasSet returning the created [`Allocation`] and Index.
     ///
     /// # Arguments
     /// - bytes: Textures Byte array.
@@ -558,23 +2056,237 @@ impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
             let (allocation, _) = self.store.get(id)?;
             Some((id, *allocation))
         } else {
+            let (bytes, width, height, trim) =
+                self.trim_upload(bytes, width, height);
+            let (bytes, scaled_width, scaled_height) =
+                self.fit_budget(&bytes, width, height);
             let allocation = {
                 let nlayers = self.layers.len();
-                let allocation = self.allocate(width, height, data)?;
+                let allocation =
+                    self.allocate(scaled_width, scaled_height, data)?;
                 self.grow(self.layers.len() - nlayers, renderer);
 
                 allocation
             };
 
-            self.upload_allocation(bytes, &allocation, renderer);
             let id = self.store.insert((allocation, key.clone()));
+            self.queue_upload(id, &bytes, renderer);
             self.layers[allocation.layer].insert_index(id);
             self.lookup.insert(key.clone(), id);
             self.cache.push(id, 1);
+
+            if let Some(trim) = trim {
+                self.trims.insert(id, trim);
+            }
+
+            if self.padding > 0 {
+                self.paddings.insert(id, self.padding);
+            }
+
+            if (scaled_width, scaled_height) != (width, height) {
+                self.downscaled.insert(id);
+            }
+
             Some((id, allocation))
         }
     }
 
+    /// Uploads Texture byte array to the AtlasSet returning the created [`Allocation`]
+    /// and its [`TextureHandle`].
+    ///
+    /// # Arguments
+    /// - bytes: Textures Byte array.
+    /// - width: Width of the Texture.
+    /// - height: Height of the Texture.
+    /// - data: any specail generic data for the texture.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn upload_with_handle(
+        &mut self,
+        key: U,
+        bytes: &[u8],
+        width: u32,
+        height: u32,
+        data: Data,
+        renderer: &GpuRenderer,
+    ) -> Option<(TextureHandle, Allocation<Data>)> {
+        let (id, allocation) =
+            self.upload_with_alloc(key, bytes, width, height, data, renderer)?;
+
+        Some((self.handle(id)?, allocation))
+    }
+
+    /// Allocates an empty region that is rendered into instead of uploaded.
+    /// Returns the existing index if the key is already loaded.
+    /// Returns None if [`AtlasSet::render_target`] is not enabled.
+    ///
+    /// # Arguments
+    /// - key: Key to reference the region like any other texture.
+    /// - width: Width of the region.
+    /// - height: Height of the region.
+    /// - data: any specail generic data for the region.
+    ///
+    pub fn allocate_render_target(
+        &mut self,
+        key: U,
+        width: u32,
+        height: u32,
+        data: Data,
+        renderer: &GpuRenderer,
+    ) -> Option<(usize, Allocation<Data>)> {
+        if !self.render_target {
+            return None;
+        }
+
+        if let Some(&id) = self.lookup.get(&key) {
+            let (allocation, _) = self.store.get(id)?;
+            return Some((id, *allocation));
+        }
+
+        let allocation = {
+            let nlayers = self.layers.len();
+            let allocation = self.allocate(width, height, data)?;
+            self.grow(self.layers.len() - nlayers, renderer);
+
+            allocation
+        };
+
+        let id = self.store.insert((allocation, key.clone()));
+        self.layers[allocation.layer].insert_index(id);
+        self.lookup.insert(key, id);
+        self.cache.push(id, 1);
+
+        if self.padding > 0 {
+            self.paddings.insert(id, self.padding);
+        }
+
+        Some((id, allocation))
+    }
+
+    /// Begins a [`wgpu::RenderPass`] into an [`Allocation`]'s region.
+    /// The viewport and scissor are set to the region so drawing can not
+    /// touch neighbouring textures. The region is loaded not cleared.
+    /// Pipelines used within must target the Atlas format.
+    /// Returns None if the index does not exist or
+    /// [`AtlasSet::render_target`] is not enabled.
+    ///
+    pub fn begin_render_pass<'a>(
+        &'a self,
+        encoder: &'a mut wgpu::CommandEncoder,
+        id: usize,
+    ) -> Option<wgpu::RenderPass<'a>> {
+        if !self.render_target {
+            return None;
+        }
+
+        let (allocation, _) = self.store.get(id)?;
+        let view = self.layer_views.get(allocation.layer)?;
+        let (x, y, width, height) = allocation.rect();
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Atlas render target pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        pass.set_viewport(
+            x as f32,
+            y as f32,
+            width as f32,
+            height as f32,
+            0.0,
+            1.0,
+        );
+        pass.set_scissor_rect(x, y, width, height);
+
+        Some(pass)
+    }
+
+    /// Uploads a offline packed Sheet once and registers each [`PackedFrame`]
+    /// as its own index pointing into the Sheets [`Allocation`].
+    /// Returns the Sheets index and the Frames indexs in the order given.
+    /// Returns None if the Sheet fails to upload or any Frame is outside of the Sheet.
+    ///
+    /// # Arguments
+    /// - key: Key for the whole Sheet.
+    /// - bytes: Sheets Byte array.
+    /// - width: Width of the Sheet.
+    /// - height: Height of the Sheet.
+    /// - frames: Named Frames within the Sheet.
+    /// - data: any specail generic data for the Sheet and its Frames.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn upload_packed(
+        &mut self,
+        key: U,
+        bytes: &[u8],
+        width: u32,
+        height: u32,
+        frames: &[PackedFrame<U>],
+        data: Data,
+        renderer: &GpuRenderer,
+    ) -> Option<(usize, Vec<usize>)> {
+        if frames.iter().any(|frame| {
+            frame.width == 0
+                || frame.height == 0
+                || frame.x.saturating_add(frame.width) > width
+                || frame.y.saturating_add(frame.height) > height
+        }) {
+            return None;
+        }
+
+        // Frames are placed relative to the untrimmed Sheet.
+        let trim = std::mem::replace(&mut self.trim_transparent, false);
+        let sheet =
+            self.upload_with_alloc(key, bytes, width, height, data, renderer);
+        self.trim_transparent = trim;
+        let (sheet_id, sheet) = sheet?;
+        let (sheet_x, sheet_y) = sheet.position();
+        let (sheet_width, sheet_height) = sheet.size();
+        // The Sheet may have been downscaled to fit the memory budget.
+        let scale_x = sheet_width as f32 / width as f32;
+        let scale_y = sheet_height as f32 / height as f32;
+        let mut ids = Vec::with_capacity(frames.len());
+
+        for frame in frames {
+            if let Some(&id) = self.lookup.get(&frame.key) {
+                ids.push(id);
+                continue;
+            }
+
+            let x = sheet_x + (frame.x as f32 * scale_x) as u32;
+            let y = sheet_y + (frame.y as f32 * scale_y) as u32;
+            let frame_width = ((frame.width as f32 * scale_x) as u32).max(1);
+            let frame_height = ((frame.height as f32 * scale_y) as u32).max(1);
+
+            let mut allocation = sheet;
+            allocation.allocation.rectangle = guillotiere::Rectangle {
+                min: guillotiere::point2(x as i32, y as i32),
+                max: guillotiere::point2(
+                    (x + frame_width) as i32,
+                    (y + frame_height) as i32,
+                ),
+            };
+
+            let id = self.store.insert((allocation, frame.key.clone()));
+            self.lookup.insert(frame.key.clone(), id);
+            self.frames.insert(id, sheet_id);
+            self.cache.push(id, 1);
+            ids.push(id);
+        }
+
+        Some((sheet_id, ids))
+    }
+
     /// Returns the Width and Height of the [`AtlasSet`] and how many Layers Exist.
     ///
     pub fn size(&self) -> UVec3 {
@@ -585,8 +2297,157 @@ impl<U: Hash + Eq + Clone, Data: Copy + Default> AtlasSet<U, Data> {
         )
     }
 
+    /// Returns a reference to the [`wgpu::Texture`] holding all the Layers.
+    ///
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// Returns the [`wgpu::TextureFormat`] the Layers use.
+    ///
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.format
+    }
+
+    /// Returns a reference to the D2Array [`wgpu::TextureView`] of all Layers.
+    ///
+    pub fn array_view(&self) -> &wgpu::TextureView {
+        &self.texture_view
+    }
+
+    /// Returns a reference to a single Layers D2 [`wgpu::TextureView`] if it exists.
+    /// Used to sample the Atlas within other pipelines.
+    ///
+    pub fn texture_view(&self, layer: u32) -> Option<&wgpu::TextureView> {
+        self.layer_views.get(layer as usize)
+    }
+
+    //Creates a D2 View per Layer of the Texture.
+    fn create_layer_views(
+        texture: &wgpu::Texture,
+        format: wgpu::TextureFormat,
+        count: u32,
+    ) -> Vec<wgpu::TextureView> {
+        (0..count)
+            .map(|layer| {
+                texture.create_view(&wgpu::TextureViewDescriptor {
+                    label: Some("Texture Atlas Layer"),
+                    format: Some(format),
+                    dimension: Some(wgpu::TextureViewDimension::D2),
+                    aspect: wgpu::TextureAspect::All,
+                    base_mip_level: 0,
+                    mip_level_count: Some(1),
+                    base_array_layer: layer,
+                    array_layer_count: Some(1),
+                })
+            })
+            .collect()
+    }
+
     /// Returns a [`BindGroup`] Reference to the AtlasSets Texture Binding.
     pub fn bind_group(&self) -> &BindGroup {
         &self.texture_group.bind_group
     }
 }
+
+impl AtlasSet<String, i32> {
+    /// Loads a offline packed Sheet from File and uploads it once, registering each
+    /// [`PackedFrame`] as its own index that can be looked up by the Frames key.
+    /// Returns the Frames indexs in the order given.
+    ///
+    pub fn import_packed(
+        &mut self,
+        image_path: impl AsRef<Path>,
+        frames: &[PackedFrame],
+        renderer: &GpuRenderer,
+    ) -> Option<Vec<usize>> {
+        let texture = Texture::from_file(image_path).ok()?;
+        let (width, height) = texture.size();
+        let (_, ids) = self.upload_packed(
+            texture.name().to_owned(),
+            texture.bytes(),
+            width,
+            height,
+            frames,
+            0,
+            renderer,
+        )?;
+
+        Some(ids)
+    }
+
+    /// Uploads every image within dir whose extension is one of extensions
+    /// such as `&["png"]`. Extensions are matched ignoring case and an empty
+    /// list uploads every file. Sub directories are skipped.
+    /// Returns each file name paired with its [`AtlasSet`] index sorted by name.
+    ///
+    /// # Arguments
+    /// - renderer: [`GpuRenderer`] the images are uploaded with.
+    /// - dir: Directory the images are loaded from.
+    /// - extensions: File extensions without the dot of the images to upload.
+    ///
+    pub fn upload_directory(
+        &mut self,
+        renderer: &GpuRenderer,
+        dir: &Path,
+        extensions: &[&str],
+    ) -> Result<Vec<(String, usize)>, GraphicsError> {
+        let mut paths = Vec::new();
+
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+
+            if !path.is_file() {
+                continue;
+            }
+
+            let matches = extensions.is_empty()
+                || path
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .is_some_and(|extension| {
+                        extensions
+                            .iter()
+                            .any(|ext| ext.eq_ignore_ascii_case(extension))
+                    });
+
+            if matches {
+                paths.push(path);
+            }
+        }
+
+        paths.sort();
+
+        let mut uploaded = Vec::with_capacity(paths.len());
+
+        for path in paths {
+            let file_name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .ok_or_else(|| {
+                    OtherError::new("could not convert file name to String")
+                })?
+                .to_owned();
+            let name = path
+                .to_str()
+                .ok_or_else(|| {
+                    OtherError::new("could not convert path to String")
+                })?
+                .to_owned();
+
+            // Decoded only when not already uploaded like Texture::upload_from.
+            let id = match self.lookup(&name) {
+                Some(id) => id,
+                None => {
+                    Texture::from_file_with_color_key(&path, self.color_key)?
+                        .upload(self, renderer)
+                        .ok_or(GraphicsError::AtlasFull)?
+                }
+            };
+
+            uploaded.push((file_name, id));
+        }
+
+        Ok(uploaded)
+    }
+}
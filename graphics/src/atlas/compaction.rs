@@ -0,0 +1,55 @@
+use crate::{Allocation, Allocator};
+use std::collections::VecDeque;
+
+/// Progress of an incremental [`crate::AtlasSet::compact_step`].
+///
+#[derive(Clone, Debug)]
+pub enum CompactProgress<Data: Copy + Default = i32> {
+    /// Nothing could be repacked so no compaction was started.
+    Idle,
+    /// Compaction is still copying. Rendering keeps using the old UV's
+    /// until it is done.
+    InProgress {
+        /// Bytes copied into the new Texture so far.
+        copied_bytes: u64,
+        /// Bytes that must be copied in total.
+        total_bytes: u64,
+    },
+    /// Compaction finished and got applied.
+    /// Contains the remap of each moved index and its new [`Allocation`].
+    Done(Vec<(usize, Allocation<Data>)>),
+    /// Textures got uploaded, updated, removed, cleared or the layers grew
+    /// while compacting so the compaction was discarded.
+    Cancelled,
+}
+
+//A single copy into the compacted Texture.
+pub(crate) enum CompactCopy {
+    //Copies a layer that is not repacked as a whole.
+    Layer(u32),
+    //Copies an allocation from its old position to its repacked one.
+    Region {
+        layer: u32,
+        from: (u32, u32),
+        to: (u32, u32),
+        size: (u32, u32),
+    },
+}
+
+//A repacked layer with its new allocator and each index's allocation.
+pub(crate) type CompactLayout =
+    (usize, Allocator, Vec<(usize, guillotiere::Allocation)>);
+
+//State of an in progress incremental compaction.
+pub(crate) struct Compaction {
+    //Texture the allocations are copied into.
+    pub(crate) target: wgpu::Texture,
+    //Copies still left to do.
+    pub(crate) copies: VecDeque<CompactCopy>,
+    //Repacked layers with their new allocator and each index's allocation.
+    pub(crate) layouts: Vec<CompactLayout>,
+    //AtlasSet mutation count when planned to detect changes.
+    pub(crate) mutations: u64,
+    pub(crate) copied_bytes: u64,
+    pub(crate) total_bytes: u64,
+}
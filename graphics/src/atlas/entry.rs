@@ -0,0 +1,16 @@
+/// Read only view of a resident Texture within a [`crate::AtlasSet`].
+/// Returned by [`crate::AtlasSet::entries`] for tooling such as Atlas inspectors.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AtlasEntry<'a, U> {
+    /// Key the Texture was uploaded with.
+    pub key: &'a U,
+    /// Store index of the [`crate::Allocation`].
+    pub id: usize,
+    /// Texture Atlas Array Layer the Texture is within.
+    pub layer: usize,
+    /// X, Y, Width and Height of the Texture within the layer.
+    pub rect: (u32, u32, u32, u32),
+    /// Reference count of the Texture. 0 if it is not tracked by the cache.
+    pub ref_count: usize,
+}
@@ -0,0 +1,19 @@
+/// Changes within an [`crate::AtlasSet`] reported to the handler given to
+/// [`crate::AtlasSet::on_event`] such as to log how the atlas grows.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AtlasEvent {
+    /// The Texture grew by new layers. Contains the layer count after growing.
+    LayerAdded(usize),
+    /// The unused [`crate::Allocation`] at the index got evicted to make room
+    /// or by [`crate::AtlasSet::collect_unreferenced`] freeing its space.
+    Evicted(usize),
+    /// The [`crate::Allocation`] at the index got removed by
+    /// [`crate::AtlasSet::remove`] or along with the Sheet it was packed in.
+    Removed(usize),
+    /// Allocations got repacked. Contains how many indexs moved.
+    Compacted(usize),
+    /// The layer reached [`crate::AtlasSet`]'s deallocations_limit so it is
+    /// fragmented enough that compacting is advised.
+    PurgeThresholdHit(usize),
+}
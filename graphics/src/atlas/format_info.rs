@@ -0,0 +1,14 @@
+/// Channel layout of an [`crate::Allocation`]'s Texture data.
+/// Returned by [`crate::AtlasSet::format_info`] so read back bytes are
+/// interpreted correctly.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FormatInfo {
+    /// [`wgpu::TextureFormat`] of the Atlas the Texture is within.
+    pub format: wgpu::TextureFormat,
+    /// Bytes each pixel takes within the format.
+    pub bytes_per_pixel: u32,
+    /// If the color channels are premultiplied by alpha and need to be
+    /// divided by it to get the uploaded colors back.
+    pub premultiplied: bool,
+}
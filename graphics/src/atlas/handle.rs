@@ -0,0 +1,25 @@
+/// Generational Handle to an [`crate::Allocation`] within a [`crate::AtlasSet`].
+/// The generation is used to detect when the Index was removed and reused
+/// by a newer Allocation so outdated Handles can not Alias them.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TextureHandle {
+    /// Index of the Allocation within the AtlasSet's Store.
+    pub index: usize,
+    /// Generation of the Index when the Handle was created.
+    pub generation: u32,
+}
+
+impl TextureHandle {
+    /// Creates a new [`TextureHandle`] from Index and Generation.
+    ///
+    pub fn new(index: usize, generation: u32) -> Self {
+        Self { index, generation }
+    }
+
+    /// Returns the Store Index of the [`TextureHandle`].
+    ///
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
@@ -0,0 +1,31 @@
+/// A named Frame within an offline packed Sprite Sheet.
+/// Used with [`crate::AtlasSet::upload_packed`] to register each Frame
+/// as its own addressable index without runtime packing.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PackedFrame<U = String> {
+    /// Key the Frame can be looked up by within the [`crate::AtlasSet`].
+    pub key: U,
+    /// X position of the Frame within the Sheet.
+    pub x: u32,
+    /// Y position of the Frame within the Sheet.
+    pub y: u32,
+    /// Width of the Frame.
+    pub width: u32,
+    /// Height of the Frame.
+    pub height: u32,
+}
+
+impl<U> PackedFrame<U> {
+    /// Creates a new [`PackedFrame`] from key and its location within the Sheet.
+    ///
+    pub fn new(key: U, x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self {
+            key,
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
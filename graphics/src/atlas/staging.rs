@@ -0,0 +1,214 @@
+use std::sync::{mpsc, Arc};
+
+//Alignment of each write within a chunk. Also the row alignment wgpu
+//requires when copying a Buffer into a Texture.
+const STAGING_ALIGNMENT: u64 = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as u64;
+
+//Rounds value up to the next multiple of alignment.
+fn align_to(value: u64, alignment: u64) -> u64 {
+    value.div_ceil(alignment) * alignment
+}
+
+/// Mappable Buffer sub allocated by the [`TextureStagingBelt`].
+///
+struct StagingChunk {
+    /// Buffer the texture data is written into before copying to the Texture.
+    buffer: Arc<wgpu::Buffer>,
+    /// Size of the Buffer in bytes.
+    size: u64,
+    /// Where the next write within the Buffer starts.
+    offset: u64,
+}
+
+/// Ring of reusable staging Buffers used to copy texture data into a Texture.
+/// Chunks are recycled once the GPU is done reading them so streaming many
+/// textures does not create a new staging Buffer for each one.
+///
+/// Writes are recorded into an internal [`wgpu::CommandEncoder`] and only
+/// reach the Texture after [`TextureStagingBelt::submit`].
+///
+pub struct TextureStagingBelt {
+    /// Size new chunks are created with.
+    chunk_size: u64,
+    /// Mapped chunks currently being written into.
+    active: Vec<StagingChunk>,
+    /// Unmapped chunks the submitted copies are reading from.
+    closed: Vec<StagingChunk>,
+    /// Mapped chunks ready to be written into again.
+    free: Vec<StagingChunk>,
+    /// Sends chunks back once they are mapped again.
+    sender: mpsc::Sender<StagingChunk>,
+    /// Receives the chunks that got mapped again.
+    receiver: mpsc::Receiver<StagingChunk>,
+    /// Encoder the copies are recorded into until submitted.
+    encoder: Option<wgpu::CommandEncoder>,
+    /// How many chunks have been created over the belts lifetime.
+    chunks_created: usize,
+}
+
+impl TextureStagingBelt {
+    /// Creates a new [`TextureStagingBelt`].
+    /// chunk_size should be larger than most single uploads.
+    ///
+    pub fn new(chunk_size: u64) -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        Self {
+            chunk_size: chunk_size.max(STAGING_ALIGNMENT),
+            active: Vec::new(),
+            closed: Vec::new(),
+            free: Vec::new(),
+            sender,
+            receiver,
+            encoder: None,
+            chunks_created: 0,
+        }
+    }
+
+    /// Copies bytes into a staging chunk and records a copy into the Texture.
+    /// Rows are padded to the alignment wgpu requires.
+    ///
+    /// # Arguments
+    /// - bytes: Tightly packed rows of texture data.
+    /// - bytes_per_row: Bytes per row within bytes.
+    /// - destination: Texture, layer and origin to copy into.
+    /// - extent: Width and Height to copy.
+    ///
+    pub fn write_texture(
+        &mut self,
+        device: &wgpu::Device,
+        bytes: &[u8],
+        bytes_per_row: u32,
+        destination: wgpu::ImageCopyTexture,
+        extent: wgpu::Extent3d,
+    ) {
+        let height = extent.height as usize;
+        let row = bytes_per_row as usize;
+
+        if height == 0 || row == 0 || bytes.len() < row * height {
+            return;
+        }
+
+        let padded_row = align_to(bytes_per_row as u64, STAGING_ALIGNMENT);
+        let size = padded_row * height as u64;
+        let mut chunk = self.take_chunk(device, size);
+        let offset = chunk.offset;
+
+        {
+            let mut view = chunk
+                .buffer
+                .slice(offset..offset + size)
+                .get_mapped_range_mut();
+
+            for (y, src) in bytes.chunks_exact(row).take(height).enumerate() {
+                let start = y * padded_row as usize;
+                view[start..start + row].copy_from_slice(src);
+            }
+        }
+
+        let encoder = self.encoder.get_or_insert_with(|| {
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Texture staging encoder"),
+            })
+        });
+
+        encoder.copy_buffer_to_texture(
+            wgpu::ImageCopyBuffer {
+                buffer: &chunk.buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset,
+                    bytes_per_row: Some(padded_row as u32),
+                    rows_per_image: Some(extent.height),
+                },
+            },
+            destination,
+            extent,
+        );
+
+        chunk.offset = align_to(offset + size, STAGING_ALIGNMENT);
+        self.active.push(chunk);
+    }
+
+    /// Submits the recorded copies and recalls the chunks for reuse once the
+    /// GPU is done with them. Does nothing if nothing was written.
+    ///
+    pub fn submit(&mut self, queue: &wgpu::Queue) {
+        let encoder = match self.encoder.take() {
+            Some(encoder) => encoder,
+            None => return,
+        };
+
+        for chunk in self.active.drain(..) {
+            chunk.buffer.unmap();
+            self.closed.push(chunk);
+        }
+
+        queue.submit(Some(encoder.finish()));
+        self.receive_chunks();
+
+        for chunk in self.closed.drain(..) {
+            let sender = self.sender.clone();
+
+            chunk.buffer.clone().slice(..).map_async(
+                wgpu::MapMode::Write,
+                move |_| {
+                    let _ = sender.send(chunk);
+                },
+            );
+        }
+    }
+
+    /// If copies are recorded that have not been submitted yet.
+    ///
+    pub fn has_pending(&self) -> bool {
+        self.encoder.is_some()
+    }
+
+    /// How many staging chunks have been created over the belts lifetime.
+    ///
+    pub fn chunks_created(&self) -> usize {
+        self.chunks_created
+    }
+
+    //Finds a chunk with room for size bytes or creates a new one.
+    fn take_chunk(&mut self, device: &wgpu::Device, size: u64) -> StagingChunk {
+        if let Some(index) = self
+            .active
+            .iter()
+            .position(|chunk| chunk.offset + size <= chunk.size)
+        {
+            return self.active.swap_remove(index);
+        }
+
+        self.receive_chunks();
+
+        if let Some(index) =
+            self.free.iter().position(|chunk| size <= chunk.size)
+        {
+            return self.free.swap_remove(index);
+        }
+
+        let size = self.chunk_size.max(size);
+        self.chunks_created += 1;
+
+        StagingChunk {
+            buffer: Arc::new(device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Texture staging buffer"),
+                size,
+                usage: wgpu::BufferUsages::MAP_WRITE
+                    | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: true,
+            })),
+            size,
+            offset: 0,
+        }
+    }
+
+    //Moves the chunks the GPU is done with back into the free list.
+    fn receive_chunks(&mut self) {
+        while let Ok(mut chunk) = self.receiver.try_recv() {
+            chunk.offset = 0;
+            self.free.push(chunk);
+        }
+    }
+}
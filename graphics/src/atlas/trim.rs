@@ -0,0 +1,69 @@
+/// Where a trimmed Texture sits within its original untrimmed Texture.
+/// Stored by [`crate::AtlasSet`] when transparent trimming is enabled so
+/// [`crate::Rect`] can place the trimmed Texture where it originally was.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TrimOffset {
+    /// X offset of the trimmed area from the left of the original Texture.
+    pub x: u32,
+    /// Y offset of the trimmed area from the top of the original Texture.
+    pub y: u32,
+    /// Width of the trimmed area.
+    pub width: u32,
+    /// Height of the trimmed area.
+    pub height: u32,
+    /// Width of the original Texture.
+    pub source_width: u32,
+    /// Height of the original Texture.
+    pub source_height: u32,
+}
+
+/// Removes fully transparent rows and columns from the edges of a 4 byte per pixel
+/// Texture whose alpha is its last byte.
+/// Returns None if nothing could be trimmed or the Texture is fully transparent.
+///
+pub(crate) fn trim_transparent(
+    bytes: &[u8],
+    width: u32,
+    height: u32,
+) -> Option<(Vec<u8>, TrimOffset)> {
+    let (w, h) = (width as usize, height as usize);
+
+    if bytes.len() < w * h * 4 {
+        return None;
+    }
+
+    let opaque = |x: usize, y: usize| bytes[(y * w + x) * 4 + 3] != 0;
+    let row_opaque = |y: usize| (0..w).any(|x| opaque(x, y));
+    let column_opaque = |x: usize, top: usize, bottom: usize| {
+        (top..bottom).any(|y| opaque(x, y))
+    };
+
+    let top = (0..h).find(|&y| row_opaque(y))?;
+    let bottom = (top..h).rev().find(|&y| row_opaque(y))? + 1;
+    let left = (0..w).find(|&x| column_opaque(x, top, bottom))?;
+    let right = (left..w).rev().find(|&x| column_opaque(x, top, bottom))? + 1;
+
+    if (left, top, right, bottom) == (0, 0, w, h) {
+        return None;
+    }
+
+    let mut trimmed = Vec::with_capacity((right - left) * (bottom - top) * 4);
+
+    for y in top..bottom {
+        let row = (y * w + left) * 4;
+        trimmed.extend_from_slice(&bytes[row..row + (right - left) * 4]);
+    }
+
+    Some((
+        trimmed,
+        TrimOffset {
+            x: left as u32,
+            y: top as u32,
+            width: (right - left) as u32,
+            height: (bottom - top) as u32,
+            source_width: width,
+            source_height: height,
+        },
+    ))
+}
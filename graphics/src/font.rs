@@ -1,10 +1,17 @@
+mod align;
+mod missing;
 mod pipeline;
 mod render;
+mod sdf;
 mod text;
 mod vertex;
 
+pub use align::{TextAlign, VerticalAlign};
 pub use cosmic_text::Shaping;
+pub(crate) use missing::generate_tofu;
+pub use missing::MissingGlyph;
 pub use pipeline::TextRenderPipeline;
 pub use render::*;
+pub use sdf::*;
 pub use text::*;
 pub use vertex::*;
@@ -0,0 +1,28 @@
+/// Horizontal alignment of each line of a [`crate::Text`] within its size.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TextAlign {
+    /// Lines start at the left edge.
+    #[default]
+    Left,
+    /// Lines are centered between the left and right edges.
+    Center,
+    /// Lines end at the right edge.
+    Right,
+}
+
+/// Vertical alignment of the lines of a [`crate::Text`] within its size.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum VerticalAlign {
+    /// The first line starts at the top edge.
+    #[default]
+    Top,
+    /// The lines are centered between the top and bottom edges.
+    Middle,
+    /// The first line's baseline sits on the bottom edge so texts with
+    /// different fonts line up. Descenders hang below the edge.
+    Baseline,
+    /// The last line ends at the bottom edge.
+    Bottom,
+}
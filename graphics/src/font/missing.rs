@@ -0,0 +1,40 @@
+/// How [`crate::Text`] renders a glyph that no loaded font contains.
+/// Fonts loaded with [`crate::GpuRenderer::load_fallback_font`] are tried
+/// before a glyph is treated as missing.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MissingGlyph {
+    /// Renders an outlined box in place of the glyph.
+    #[default]
+    Tofu,
+    /// Leaves an empty space where the glyph would be.
+    Skip,
+}
+
+/// Rasterizes an outlined box coverage mask used as the missing glyph placeholder.
+/// Returns the mask along with its Width and Height.
+///
+pub(crate) fn generate_tofu(font_size: f32) -> (Vec<u8>, u32, u32) {
+    let width = (font_size * 0.5).round().max(3.0) as u32;
+    let height = (font_size * 0.7).round().max(3.0) as u32;
+    let stroke = (font_size / 16.0).round().max(1.0) as u32;
+
+    let mask = (0..height)
+        .flat_map(|y| {
+            (0..width).map(move |x| {
+                let edge = x < stroke
+                    || y < stroke
+                    || x >= width - stroke
+                    || y >= height - stroke;
+
+                if edge {
+                    255
+                } else {
+                    0
+                }
+            })
+        })
+        .collect();
+
+    (mask, width, height)
+}
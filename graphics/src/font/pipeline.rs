@@ -29,6 +29,8 @@ impl PipeLineLayout for TextRenderPipeline {
         let system_layout = layouts.create_layout(gpu_device, SystemLayout);
         let texture_layout = layouts.create_layout(gpu_device, TextureLayout);
 
+        let constants = gpu_device.pipeline_constants();
+
         // Create the render pipeline.
         gpu_device.device().create_render_pipeline(
             &wgpu::RenderPipelineDescriptor {
@@ -40,6 +42,7 @@ impl PipeLineLayout for TextRenderPipeline {
                             &system_layout,
                             &texture_layout,
                             &texture_layout,
+                            &texture_layout,
                         ],
                         push_constant_ranges: &[],
                     },
@@ -61,7 +64,10 @@ impl PipeLineLayout for TextRenderPipeline {
                             attributes: &TextVertex::attributes(),
                         },
                     ],
-                    compilation_options: Default::default(),
+                    compilation_options: wgpu::PipelineCompilationOptions {
+                        constants: &constants,
+                        ..Default::default()
+                    },
                 },
                 primitive: wgpu::PrimitiveState {
                     topology: wgpu::PrimitiveTopology::TriangleList,
@@ -72,13 +78,7 @@ impl PipeLineLayout for TextRenderPipeline {
                     polygon_mode: wgpu::PolygonMode::Fill,
                     conservative: false,
                 },
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth32Float,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::LessEqual,
-                    stencil: wgpu::StencilState::default(),
-                    bias: wgpu::DepthBiasState::default(),
-                }),
+                depth_stencil: gpu_device.depth_stencil_state(),
                 multisample: wgpu::MultisampleState::default(),
                 fragment: Some(wgpu::FragmentState {
                     module: &shader,
@@ -90,7 +90,10 @@ impl PipeLineLayout for TextRenderPipeline {
                         ),
                         write_mask: wgpu::ColorWrites::ALL,
                     })],
-                    compilation_options: Default::default(),
+                    compilation_options: wgpu::PipelineCompilationOptions {
+                        constants: &constants,
+                        ..Default::default()
+                    },
                 }),
                 multiview: None,
             },
@@ -1,7 +1,7 @@
 use crate::{
     AsBufferPass, AtlasSet, GpuRenderer, GraphicsError, InstanceBuffer,
     OrderedIndex, SetBuffers, StaticVertexBuffer, Text, TextRenderPipeline,
-    TextVertex, Vec2,
+    TextVertex, TextureGroup, TextureLayout, Vec2,
 };
 use cosmic_text::{CacheKey, SwashCache};
 use log::{error, warn};
@@ -13,12 +13,25 @@ pub struct TextAtlas {
     pub(crate) text: AtlasSet<CacheKey, Vec2>,
     /// AtlasSet holding data from Colored Emoji's only.
     pub(crate) emoji: AtlasSet<CacheKey, Vec2>,
+    /// AtlasSet holding Signed Distance Field Text only.
+    pub(crate) sdf: AtlasSet<CacheKey, Vec2>,
 }
 
 impl TextAtlas {
     /// Creates a new [`TextAtlas`].
     ///
     pub fn new(renderer: &mut GpuRenderer) -> Result<Self, GraphicsError> {
+        let mut sdf =
+            AtlasSet::new(renderer, wgpu::TextureFormat::R8Unorm, false);
+
+        // Distance Fields must be linearly filtered to stay smooth when scaled.
+        sdf.texture_group = TextureGroup::from_view_with_filter(
+            renderer,
+            &sdf.texture_view,
+            TextureLayout,
+            wgpu::FilterMode::Linear,
+        );
+
         Ok(Self {
             text: AtlasSet::new(renderer, wgpu::TextureFormat::R8Unorm, false),
             emoji: AtlasSet::new(
@@ -26,14 +39,16 @@ impl TextAtlas {
                 wgpu::TextureFormat::Rgba8UnormSrgb,
                 false,
             ),
+            sdf,
         })
     }
 
-    /// Calles Trim on both internal [`AtlasSet`]'s
+    /// Calles Trim on all internal [`AtlasSet`]'s
     ///
     pub fn trim(&mut self) {
         self.emoji.trim();
         self.text.trim();
+        self.sdf.trim();
     }
 }
 
@@ -143,9 +158,15 @@ where
                 self.set_buffers(renderer.buffer_object.as_buffer_pass());
                 self.set_bind_group(1, atlas.text.bind_group(), &[]);
                 self.set_bind_group(2, atlas.emoji.bind_group(), &[]);
+                self.set_bind_group(3, atlas.sdf.bind_group(), &[]);
                 self.set_vertex_buffer(1, buffer.buffer.instances(None));
-                self.set_pipeline(
-                    renderer.get_pipelines(TextRenderPipeline).unwrap(),
+                let pipeline =
+                    renderer.get_pipelines(TextRenderPipeline).unwrap();
+                renderer.record_pipeline(pipeline);
+                self.set_pipeline(pipeline);
+                renderer.record_draw(
+                    0..StaticVertexBuffer::index_count(),
+                    details.start..details.end,
                 );
                 self.draw_indexed(
                     0..StaticVertexBuffer::index_count(),
@@ -0,0 +1,56 @@
+/// Font Size Signed Distance Field glyphs are rasterized at once before scaling.
+pub const SDF_FONT_SIZE: f32 = 48.0;
+/// Distance in pixels the Signed Distance Field spreads out from each glyph edge.
+/// Glyphs are padded by this amount on every side.
+pub const SDF_SPREAD: u32 = 6;
+
+/// Converts a glyph coverage mask into a Signed Distance Field padded by spread.
+/// Each output byte stores the distance to the nearest edge where 128 is the edge,
+/// above is inside and below is outside of the glyph.
+///
+pub(crate) fn generate_sdf(
+    mask: &[u8],
+    width: u32,
+    height: u32,
+    spread: u32,
+) -> Vec<u8> {
+    let (width, height, spread) = (width as i32, height as i32, spread as i32);
+    let out_width = width + spread * 2;
+    let out_height = height + spread * 2;
+    let max_dist = spread as f32;
+
+    let inside = |x: i32, y: i32| -> bool {
+        x >= 0
+            && y >= 0
+            && x < width
+            && y < height
+            && mask[(y * width + x) as usize] >= 128
+    };
+
+    let mut sdf = Vec::with_capacity((out_width * out_height) as usize);
+
+    for oy in 0..out_height {
+        for ox in 0..out_width {
+            let (x, y) = (ox - spread, oy - spread);
+            let is_inside = inside(x, y);
+            let mut nearest = max_dist * max_dist;
+
+            for sy in (y - spread)..=(y + spread) {
+                for sx in (x - spread)..=(x + spread) {
+                    if inside(sx, sy) != is_inside {
+                        let (dx, dy) = ((sx - x) as f32, (sy - y) as f32);
+                        nearest = nearest.min(dx * dx + dy * dy);
+                    }
+                }
+            }
+
+            let dist = nearest.sqrt().min(max_dist);
+            let signed = if is_inside { dist } else { -dist };
+            let value = 0.5 + signed / (max_dist * 2.0);
+
+            sdf.push((value.clamp(0.0, 1.0) * 255.0) as u8);
+        }
+    }
+
+    sdf
+}
@@ -1,9 +1,12 @@
 use crate::{
-    Bounds, CameraType, Color, DrawOrder, GpuRenderer, GraphicsError, Index,
-    OrderedIndex, TextAtlas, TextVertex, Vec2, Vec3,
+    generate_sdf, generate_tofu, Allocation, Bounds, CameraType, Color,
+    DrawOrder, GpuRenderer, GraphicsError, Index, MissingGlyph, OrderedIndex,
+    TextAlign, TextAtlas, TextVertex, Vec2, Vec3, VerticalAlign, SDF_FONT_SIZE,
+    SDF_SPREAD,
 };
 use cosmic_text::{
-    Attrs, Buffer, Cursor, FontSystem, Metrics, SwashCache, SwashContent, Wrap,
+    Affinity, Attrs, Buffer, CacheKey, Cursor, FontSystem, Metrics, SwashCache,
+    SwashContent, Wrap,
 };
 
 /// [`Text`] Option Handler for [`Text::measure_string`].
@@ -49,6 +52,14 @@ pub struct Text {
     pub wrap: Wrap,
     /// [`CameraType`] used to render with.
     pub camera_type: CameraType,
+    /// Renders glyphs from a Signed Distance Field so they stay sharp at any scale.
+    pub sdf: bool,
+    /// How glyphs no loaded font contains are rendered.
+    pub missing_glyph: MissingGlyph,
+    /// Horizontal alignment of each line within the size.
+    pub align: TextAlign,
+    /// Vertical alignment of the lines within the size.
+    pub vertical_align: VerticalAlign,
     /// If anything got updated we need to update the buffers too.
     pub changed: bool,
 }
@@ -67,20 +78,55 @@ impl Text {
         let mut text_buf = Vec::with_capacity(count);
         let mut is_alpha = false;
         let mut width = 0.0;
+        let align_y = self.vertical_offset();
 
         for run in self.buffer.layout_runs() {
             width = run.line_w.max(width);
+            let align_x = self.horizontal_offset(run.line_w);
 
             for glyph in run.glyphs.iter() {
                 let physical_glyph = glyph.physical(
                     (
-                        self.pos.x + self.offsets.x,
-                        self.pos.y + self.offsets.y + self.size.y,
+                        self.pos.x + self.offsets.x + align_x,
+                        self.pos.y + self.offsets.y + self.size.y - align_y,
                     ),
                     self.scale,
                 );
 
-                let (allocation, is_color) = if let Some(allocation) =
+                // Glyph 0 is what shaping gives when no font has the character.
+                let missing = glyph.glyph_id == 0;
+
+                if missing && self.missing_glyph == MissingGlyph::Skip {
+                    continue;
+                }
+
+                let sdf = if self.sdf
+                    && !missing
+                    && !atlas.emoji.contains_key(&physical_glyph.cache_key)
+                {
+                    Self::sdf_glyph(
+                        cache,
+                        atlas,
+                        renderer,
+                        physical_glyph.cache_key,
+                    )?
+                } else {
+                    None
+                };
+                let is_sdf = sdf.is_some();
+
+                let (allocation, is_color) = if let Some(allocation) = sdf {
+                    (allocation, false)
+                } else if missing {
+                    (
+                        Self::tofu_glyph(
+                            atlas,
+                            renderer,
+                            physical_glyph.cache_key,
+                        )?,
+                        false,
+                    )
+                } else if let Some(allocation) =
                     atlas.text.get_by_key(&physical_glyph.cache_key)
                 {
                     (allocation, false)
@@ -88,13 +134,10 @@ impl Text {
                     atlas.emoji.get_by_key(&physical_glyph.cache_key)
                 {
                     (allocation, true)
-                } else {
-                    let image = cache
-                        .get_image_uncached(
-                            &mut renderer.font_sys,
-                            physical_glyph.cache_key,
-                        )
-                        .unwrap();
+                } else if let Some(image) = cache.get_image_uncached(
+                    &mut renderer.font_sys,
+                    physical_glyph.cache_key,
+                ) {
                     let bitmap = image.data;
                     let is_color = match image.content {
                         SwashContent::Color => true,
@@ -142,12 +185,35 @@ impl Text {
                     } else {
                         continue;
                     }
+                } else if self.missing_glyph == MissingGlyph::Tofu {
+                    (
+                        Self::tofu_glyph(
+                            atlas,
+                            renderer,
+                            physical_glyph.cache_key,
+                        )?,
+                        false,
+                    )
+                } else {
+                    continue;
                 };
 
-                let position = allocation.data;
+                // Signed Distance Field glyphs are stored at SDF_FONT_SIZE
+                // so they get scaled up or down to the glyphs font size.
+                let glyph_scale = if is_sdf {
+                    f32::from_bits(physical_glyph.cache_key.font_size_bits)
+                        / SDF_FONT_SIZE
+                } else {
+                    1.0
+                };
+                let position = allocation.data * glyph_scale;
                 let (u, v, width, height) = allocation.rect();
-                let (mut u, mut v, mut width, mut height) =
-                    (u as f32, v as f32, width as f32, height as f32);
+                let (mut u, mut v, mut width, mut height) = (
+                    u as f32,
+                    v as f32,
+                    width as f32 * glyph_scale,
+                    height as f32 * glyph_scale,
+                );
 
                 let (mut x, mut y) = (
                     physical_glyph.x as f32 + position.x,
@@ -194,7 +260,7 @@ impl Text {
 
                         x = bounds_min_x;
                         width = max_x - bounds_min_x;
-                        u += right_shift;
+                        u += right_shift / glyph_scale;
                     }
 
                     // Clip right edge
@@ -212,7 +278,7 @@ impl Text {
                     if y + height > bounds_max_y {
                         let bottom_shift = (y + height) - bounds_max_y;
 
-                        v += bottom_shift;
+                        v += bottom_shift / glyph_scale;
                         height -= bottom_shift;
                     }
                 }
@@ -224,7 +290,8 @@ impl Text {
                     layer: allocation.layer as u32,
                     color: color.0,
                     camera_type: self.camera_type as u32,
-                    is_color: is_color as u32,
+                    is_color: if is_sdf { 2 } else { is_color as u32 },
+                    tex_hw: [width / glyph_scale, height / glyph_scale],
                 };
 
                 text_buf.push(default);
@@ -245,6 +312,120 @@ impl Text {
         Ok(())
     }
 
+    //Gets or Rasterizes the glyph as a Signed Distance Field at SDF_FONT_SIZE.
+    //Returns None for colored or empty glyphs so they use the normal path.
+    fn sdf_glyph(
+        cache: &mut SwashCache,
+        atlas: &mut TextAtlas,
+        renderer: &mut GpuRenderer,
+        cache_key: CacheKey,
+    ) -> Result<Option<Allocation<Vec2>>, GraphicsError> {
+        let (key, _, _) = CacheKey::new(
+            cache_key.font_id,
+            cache_key.glyph_id,
+            SDF_FONT_SIZE,
+            (0.0, 0.0),
+            cache_key.flags,
+        );
+
+        if let Some(allocation) = atlas.sdf.get_by_key(&key) {
+            return Ok(Some(allocation));
+        }
+
+        let image = match cache.get_image_uncached(&mut renderer.font_sys, key)
+        {
+            Some(image) => image,
+            None => return Ok(None),
+        };
+
+        let width = image.placement.width;
+        let height = image.placement.height;
+
+        if !matches!(image.content, SwashContent::Mask)
+            || width == 0
+            || height == 0
+        {
+            return Ok(None);
+        }
+
+        let bitmap = generate_sdf(&image.data, width, height, SDF_SPREAD);
+        let (_, allocation) = atlas
+            .sdf
+            .upload_with_alloc(
+                key,
+                &bitmap,
+                width + SDF_SPREAD * 2,
+                height + SDF_SPREAD * 2,
+                Vec2::new(
+                    (image.placement.left - SDF_SPREAD as i32) as f32,
+                    (image.placement.top + SDF_SPREAD as i32) as f32,
+                ),
+                renderer,
+            )
+            .ok_or(GraphicsError::AtlasFull)?;
+
+        Ok(Some(allocation))
+    }
+
+    //Gets or Uploads the placeholder box drawn for glyphs no font contains.
+    //Stored under glyph 0 of the font as that is what missing glyphs shape too.
+    fn tofu_glyph(
+        atlas: &mut TextAtlas,
+        renderer: &mut GpuRenderer,
+        cache_key: CacheKey,
+    ) -> Result<Allocation<Vec2>, GraphicsError> {
+        let key = CacheKey {
+            glyph_id: 0,
+            ..cache_key
+        };
+
+        if let Some(allocation) = atlas.text.get_by_key(&key) {
+            return Ok(allocation);
+        }
+
+        let font_size = f32::from_bits(cache_key.font_size_bits);
+        let (mask, width, height) = generate_tofu(font_size);
+        let (_, allocation) = atlas
+            .text
+            .upload_with_alloc(
+                key,
+                &mask,
+                width,
+                height,
+                Vec2::new((font_size * 0.05).round(), height as f32),
+                renderer,
+            )
+            .ok_or(GraphicsError::AtlasFull)?;
+
+        Ok(allocation)
+    }
+
+    /// Sets how glyphs no loaded font contains are rendered.
+    ///
+    pub fn set_missing_glyph(
+        &mut self,
+        missing_glyph: MissingGlyph,
+    ) -> &mut Self {
+        self.missing_glyph = missing_glyph;
+        self.changed = true;
+        self
+    }
+
+    /// Sets how the [`Text`]'s lines are aligned within its size.
+    /// Use [`TextAlign::Center`] and [`VerticalAlign::Middle`] to center a
+    /// label within a button.
+    ///
+    pub fn set_align(
+        &mut self,
+        align: TextAlign,
+        vertical_align: VerticalAlign,
+    ) -> &mut Self {
+        self.align = align;
+        self.vertical_align = vertical_align;
+        self.changed = true;
+        self
+    }
+
     /// Creates a new [`Text`].
     ///
     pub fn new(
@@ -272,6 +453,10 @@ impl Text {
             changed: true,
             default_color: Color::rgba(0, 0, 0, 255),
             camera_type: CameraType::None,
+            sdf: false,
+            missing_glyph: MissingGlyph::Tofu,
+            align: TextAlign::Left,
+            vertical_align: VerticalAlign::Top,
             cursor: Cursor::default(),
             wrap: Wrap::Word,
             line: 0,
@@ -288,6 +473,16 @@ impl Text {
         self.changed = true;
     }
 
+    /// Sets the [`Text`] to render using Signed Distance Field glyphs.
+    /// Each glyph is rasterized once and stays sharp at any scale.
+    /// Colored glyphs like Emoji still use the normal path.
+    ///
+    pub fn set_sdf(&mut self, sdf: bool) -> &mut Self {
+        self.sdf = sdf;
+        self.changed = true;
+        self
+    }
+
     /// Unloads the [`Text`] from the Instance Buffers Store.
     ///
     pub fn unload(&self, renderer: &mut GpuRenderer) {
@@ -528,6 +723,182 @@ impl Text {
             && mouse_pos[1] < self.pos.y + self.size.y
     }
 
+    /// Converts a byte index within the full text into a [`Cursor`].
+    /// Lines are counted as if joined by a single newline.
+    ///
+    pub fn cursor_from_index(&self, index: usize) -> Cursor {
+        let mut start = 0;
+
+        for (line_i, line) in self.buffer.lines.iter().enumerate() {
+            let len = line.text().len();
+
+            if index <= start + len {
+                return Cursor::new(line_i, index - start);
+            }
+
+            start += len + 1;
+        }
+
+        let line_i = self.buffer.lines.len().saturating_sub(1);
+
+        Cursor::new(
+            line_i,
+            self.buffer
+                .lines
+                .get(line_i)
+                .map(|line| line.text().len())
+                .unwrap_or(0),
+        )
+    }
+
+    /// Converts a [`Cursor`] into a byte index within the full text.
+    /// Lines are counted as if joined by a single newline.
+    ///
+    pub fn index_from_cursor(&self, cursor: Cursor) -> usize {
+        self.buffer
+            .lines
+            .iter()
+            .take(cursor.line)
+            .map(|line| line.text().len() + 1)
+            .sum::<usize>()
+            + cursor.index
+    }
+
+    /// Returns the Screen [`Bounds`] of the caret placed before the byte index.
+    /// Returns None if the caret's line is not visible.
+    ///
+    pub fn caret_rect(&self, index: usize) -> Option<Bounds> {
+        let cursor = self.cursor_from_index(index);
+        let before = Cursor::new_with_affinity(
+            cursor.line,
+            cursor.index,
+            Affinity::Before,
+        );
+        let after = Cursor::new_with_affinity(
+            cursor.line,
+            cursor.index,
+            Affinity::After,
+        );
+        let mut empty_line = None;
+
+        for run in self.buffer.layout_runs() {
+            if run.line_i != cursor.line {
+                continue;
+            }
+
+            if let Some((x, _)) = run.highlight(before, after) {
+                return Some(self.run_bounds(&run, x, 1.0));
+            }
+
+            if run.glyphs.is_empty() {
+                empty_line = Some(self.run_bounds(&run, 0.0, 1.0));
+            }
+        }
+
+        empty_line
+    }
+
+    /// Returns the Screen [`Bounds`] covering the text between two byte indexes.
+    /// Returns one [`Bounds`] per visible line the selection spans.
+    ///
+    pub fn selection_rects(&self, start: usize, end: usize) -> Vec<Bounds> {
+        let (start, end) = (start.min(end), start.max(end));
+        let start = self.cursor_from_index(start);
+        let end = self.cursor_from_index(end);
+        let start = Cursor::new_with_affinity(
+            start.line,
+            start.index,
+            Affinity::Before,
+        );
+        let end =
+            Cursor::new_with_affinity(end.line, end.index, Affinity::After);
+
+        self.buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let (x, width) = run.highlight(start, end)?;
+
+                (width > 0.0).then(|| self.run_bounds(&run, x, width))
+            })
+            .collect()
+    }
+
+    /// Returns the byte index within the full text closest to a Screen position.
+    /// Used to place the caret where the text was clicked.
+    ///
+    pub fn index_at(&self, point: Vec2) -> usize {
+        let y = (self.pos.y + self.offsets.y + self.size.y
+            - self.vertical_offset()
+            - point.y)
+            / self.scale;
+        let line_height = self.buffer.metrics().line_height;
+        let align_x = self
+            .buffer
+            .layout_runs()
+            .find(|run| y >= run.line_top && y < run.line_top + line_height)
+            .map(|run| self.horizontal_offset(run.line_w))
+            .unwrap_or(0.0);
+        let x = (point.x - self.pos.x - self.offsets.x - align_x) / self.scale;
+
+        self.buffer
+            .hit(x, y)
+            .map(|cursor| self.index_from_cursor(cursor))
+            .unwrap_or(0)
+    }
+
+    //Converts a layout runs line area into Screen Bounds the way create_quad places glyphs.
+    fn run_bounds(
+        &self,
+        run: &cosmic_text::LayoutRun,
+        x: f32,
+        width: f32,
+    ) -> Bounds {
+        let left = self.pos.x
+            + self.offsets.x
+            + self.horizontal_offset(run.line_w)
+            + x * self.scale;
+        let top = self.pos.y + self.offsets.y + self.size.y
+            - self.vertical_offset()
+            - run.line_top * self.scale;
+
+        Bounds::new(
+            left,
+            top - self.buffer.metrics().line_height * self.scale,
+            left + width * self.scale,
+            top,
+        )
+    }
+
+    //Returns how far a line of line_w is shifted right by the TextAlign.
+    fn horizontal_offset(&self, line_w: f32) -> f32 {
+        match self.align {
+            TextAlign::Left => 0.0,
+            TextAlign::Center => (self.size.x - line_w * self.scale) / 2.0,
+            TextAlign::Right => self.size.x - line_w * self.scale,
+        }
+    }
+
+    //Returns how far the lines are shifted down by the VerticalAlign.
+    fn vertical_offset(&self) -> f32 {
+        let height = || {
+            self.buffer.layout_runs().count() as f32
+                * self.buffer.metrics().line_height
+                * self.scale
+        };
+
+        match self.vertical_align {
+            VerticalAlign::Top => 0.0,
+            VerticalAlign::Middle => (self.size.y - height()) / 2.0,
+            VerticalAlign::Bottom => self.size.y - height(),
+            VerticalAlign::Baseline => self
+                .buffer
+                .layout_runs()
+                .next()
+                .map(|run| self.size.y - run.line_y * self.scale)
+                .unwrap_or(0.0),
+        }
+    }
+
     /// measure's the [`Text`]'s Rendering Size.
     ///
     pub fn measure(&self) -> Vec2 {
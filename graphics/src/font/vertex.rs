@@ -13,6 +13,7 @@ pub struct TextVertex {
     pub color: u32,
     pub camera_type: u32,
     pub is_color: u32,
+    pub tex_hw: [f32; 2],
 }
 
 impl Default for TextVertex {
@@ -25,13 +26,14 @@ impl Default for TextVertex {
             color: 0,
             camera_type: 0,
             is_color: 0,
+            tex_hw: [0.0; 2],
         }
     }
 }
 
 impl BufferLayout for TextVertex {
     fn attributes() -> Vec<wgpu::VertexAttribute> {
-        wgpu::vertex_attr_array![1 => Float32x3, 2 => Float32x2, 3 => Float32x2, 4 => Uint32, 5 => Uint32, 6 => Uint32, 7 => Uint32]
+        wgpu::vertex_attr_array![1 => Float32x3, 2 => Float32x2, 3 => Float32x2, 4 => Uint32, 5 => Uint32, 6 => Uint32, 7 => Uint32, 8 => Float32x2]
             .to_vec()
     }
 
@@ -54,6 +56,6 @@ impl BufferLayout for TextVertex {
     }
 
     fn stride() -> usize {
-        std::mem::size_of::<[f32; 11]>()
+        std::mem::size_of::<[f32; 13]>()
     }
 }
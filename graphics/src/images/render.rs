@@ -109,9 +109,10 @@ where
                 if buffer.buffer.count() > 0 {
                     self.set_bind_group(1, atlas.bind_group(), &[]);
                     self.set_vertex_buffer(1, buffer.buffer.instances(None));
-                    self.set_pipeline(
-                        renderer.get_pipelines(ImageRenderPipeline).unwrap(),
-                    );
+                    let pipeline =
+                        renderer.get_pipelines(ImageRenderPipeline).unwrap();
+                    renderer.record_pipeline(pipeline);
+                    self.set_pipeline(pipeline);
                     for (details, bounds, camera_type) in details {
                         if let Some(bounds) = bounds {
                             let bounds =
@@ -126,6 +127,10 @@ where
                             scissor_is_default = false;
                         }
 
+                        renderer.record_draw(
+                            0..StaticVertexBuffer::index_count(),
+                            details.start..details.end,
+                        );
                         self.draw_indexed(
                             0..StaticVertexBuffer::index_count(),
                             0,
@@ -150,10 +155,15 @@ where
             if buffer.buffer.count() > 0 {
                 self.set_bind_group(1, atlas.bind_group(), &[]);
                 self.set_vertex_buffer(1, buffer.buffer.instances(None));
-                self.set_pipeline(
-                    renderer.get_pipelines(ImageRenderPipeline).unwrap(),
-                );
+                let pipeline =
+                    renderer.get_pipelines(ImageRenderPipeline).unwrap();
+                renderer.record_pipeline(pipeline);
+                self.set_pipeline(pipeline);
 
+                renderer.record_draw(
+                    0..StaticVertexBuffer::index_count(),
+                    details.start..details.end,
+                );
                 self.draw_indexed(
                     0..StaticVertexBuffer::index_count(),
                     0,
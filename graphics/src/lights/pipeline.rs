@@ -31,6 +31,8 @@ impl PipeLineLayout for LightRenderPipeline {
             layouts.create_layout(gpu_device, AreaLightLayout);
         let dir_light_layout =
             layouts.create_layout(gpu_device, DirLightLayout);
+        let constants = gpu_device.pipeline_constants();
+
         // Create the render pipeline.
         gpu_device.device().create_render_pipeline(
             &wgpu::RenderPipelineDescriptor {
@@ -63,7 +65,10 @@ impl PipeLineLayout for LightRenderPipeline {
                             attributes: &LightsVertex::attributes(),
                         },
                     ],
-                    compilation_options: Default::default(),
+                    compilation_options: wgpu::PipelineCompilationOptions {
+                        constants: &constants,
+                        ..Default::default()
+                    },
                 },
                 primitive: wgpu::PrimitiveState {
                     topology: wgpu::PrimitiveTopology::TriangleList,
@@ -74,13 +79,7 @@ impl PipeLineLayout for LightRenderPipeline {
                     polygon_mode: wgpu::PolygonMode::Fill,
                     conservative: false,
                 },
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth32Float,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::LessEqual,
-                    stencil: wgpu::StencilState::default(),
-                    bias: wgpu::DepthBiasState::default(),
-                }),
+                depth_stencil: gpu_device.depth_stencil_state(),
                 multisample: wgpu::MultisampleState::default(),
                 fragment: Some(wgpu::FragmentState {
                     module: &shader,
@@ -90,7 +89,10 @@ impl PipeLineLayout for LightRenderPipeline {
                         blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                         write_mask: wgpu::ColorWrites::ALL,
                     })],
-                    compilation_options: Default::default(),
+                    compilation_options: wgpu::PipelineCompilationOptions {
+                        constants: &constants,
+                        ..Default::default()
+                    },
                 }),
                 multiview: None,
             },
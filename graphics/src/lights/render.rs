@@ -185,10 +185,15 @@ where
                 self.set_bind_group(1, &buffer.area_bind_group, &[]);
                 self.set_bind_group(2, &buffer.dir_bind_group, &[]);
                 self.set_vertex_buffer(1, buffer.buffer.instances(None));
-                self.set_pipeline(
-                    renderer.get_pipelines(LightRenderPipeline).unwrap(),
-                );
+                let pipeline =
+                    renderer.get_pipelines(LightRenderPipeline).unwrap();
+                renderer.record_pipeline(pipeline);
+                self.set_pipeline(pipeline);
 
+                renderer.record_draw(
+                    0..StaticVertexBuffer::index_count(),
+                    details.start..details.end,
+                );
                 self.draw_indexed(
                     0..StaticVertexBuffer::index_count(),
                     0,
@@ -113,8 +113,13 @@ where
                 self.set_buffers(renderer.buffer_object.as_buffer_pass());
                 self.set_bind_group(1, atlas.bind_group(), &[]);
                 self.set_vertex_buffer(1, buffer.buffer.instances(None));
-                self.set_pipeline(
-                    renderer.get_pipelines(MapRenderPipeline).unwrap(),
+                let pipeline =
+                    renderer.get_pipelines(MapRenderPipeline).unwrap();
+                renderer.record_pipeline(pipeline);
+                self.set_pipeline(pipeline);
+                renderer.record_draw(
+                    0..StaticVertexBuffer::index_count(),
+                    details.start..details.end,
                 );
                 self.draw_indexed(
                     0..StaticVertexBuffer::index_count(),
@@ -28,6 +28,8 @@ impl PipeLineLayout for Mesh2DRenderPipeline {
 
         let system_layout = layouts.create_layout(gpu_device, SystemLayout);
 
+        let constants = gpu_device.pipeline_constants();
+
         // Create the render pipeline.
         gpu_device.device().create_render_pipeline(
             &wgpu::RenderPipelineDescriptor {
@@ -47,7 +49,10 @@ impl PipeLineLayout for Mesh2DRenderPipeline {
                         step_mode: wgpu::VertexStepMode::Vertex,
                         attributes: &Mesh2DVertex::attributes(),
                     }],
-                    compilation_options: Default::default(),
+                    compilation_options: wgpu::PipelineCompilationOptions {
+                        constants: &constants,
+                        ..Default::default()
+                    },
                 },
                 primitive: wgpu::PrimitiveState {
                     topology: wgpu::PrimitiveTopology::TriangleList,
@@ -58,13 +63,7 @@ impl PipeLineLayout for Mesh2DRenderPipeline {
                     polygon_mode: wgpu::PolygonMode::Fill,
                     conservative: false,
                 },
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth32Float,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::LessEqual,
-                    stencil: wgpu::StencilState::default(),
-                    bias: wgpu::DepthBiasState::default(),
-                }),
+                depth_stencil: gpu_device.depth_stencil_state(),
                 multisample: wgpu::MultisampleState::default(),
                 fragment: Some(wgpu::FragmentState {
                     module: &shader,
@@ -74,7 +73,10 @@ impl PipeLineLayout for Mesh2DRenderPipeline {
                         blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                         write_mask: wgpu::ColorWrites::ALL,
                     })],
-                    compilation_options: Default::default(),
+                    compilation_options: wgpu::PipelineCompilationOptions {
+                        constants: &constants,
+                        ..Default::default()
+                    },
                 }),
                 multiview: None,
             },
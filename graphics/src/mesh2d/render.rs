@@ -98,9 +98,10 @@ where
         if let Some(vbos) = buffer.vbos.buffers.get(buffer_layer) {
             if !vbos.is_empty() {
                 self.set_buffers(buffer.vbos.as_buffer_pass());
-                self.set_pipeline(
-                    renderer.get_pipelines(Mesh2DRenderPipeline).unwrap(),
-                );
+                let pipeline =
+                    renderer.get_pipelines(Mesh2DRenderPipeline).unwrap();
+                renderer.record_pipeline(pipeline);
+                self.set_pipeline(pipeline);
 
                 if buffer.vbos.is_clipped() {
                     let mut scissor_is_default = true;
@@ -118,6 +119,10 @@ where
                             );
                             scissor_is_default = false;
                         }
+                        renderer.record_draw(
+                            details.indices_start..details.indices_end,
+                            0..1,
+                        );
                         // Indexs can always start at 0 per mesh data.
                         // Base vertex is the Addition to the Index
                         self.draw_indexed(
@@ -138,6 +143,10 @@ where
                     }
                 } else {
                     for (details, _bounds, _camer_type) in vbos {
+                        renderer.record_draw(
+                            details.indices_start..details.indices_end,
+                            0..1,
+                        );
                         // Indexs can always start at 0 per mesh data.
                         // Base vertex is the Addition to the Index
                         self.draw_indexed(
@@ -26,7 +26,14 @@ struct VertexOutput {
     @location(0) color: vec4<f32>,
 };
 
+// Set when the Colors given are already linear. See ColorSpace.
+override LINEAR_COLORS: bool = false;
+
 fn srgb_to_linear(c: f32) -> f32 {
+    if LINEAR_COLORS {
+        return c;
+    }
+
     if c <= 0.04045 {
         return c / 12.92;
     } else {
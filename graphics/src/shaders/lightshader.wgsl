@@ -72,7 +72,14 @@ var<uniform> u_areas: array<AreaLights, c_area_lights>;
 @binding(0)
 var<uniform> u_dirs: array<DirLights, c_dir_lights>;
 
+// Set when the Colors given are already linear. See ColorSpace.
+override LINEAR_COLORS: bool = false;
+
 fn srgb_to_linear(c: f32) -> f32 {
+    if LINEAR_COLORS {
+        return c;
+    }
+
     if c <= 0.04045 {
         return c / 12.92;
     } else {
@@ -39,7 +39,14 @@ var tex: texture_2d_array<f32>;
 @binding(1)
 var tex_sample: sampler;
 
+// Set when the Colors given are already linear. See ColorSpace.
+override LINEAR_COLORS: bool = false;
+
 fn srgb_to_linear(c: f32) -> f32 {
+    if LINEAR_COLORS {
+        return c;
+    }
+
     if c <= 0.04045 {
         return c / 12.92;
     } else {
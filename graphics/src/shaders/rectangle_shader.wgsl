@@ -14,24 +14,56 @@ struct Global {
 @binding(0)
 var<uniform> global: Global;
 
+struct Frame {
+    time: f32,
+    delta_time: f32,
+    resolution: vec2<f32>,
+};
+
+@group(2)
+@binding(0)
+var<uniform> frame: Frame;
+
+struct Cameras {
+    view_proj: array<mat4x4<f32>, 16>,
+    // World position divided by zoom of each Camera used by parallax
+    // followed by the World position at the center of its view.
+    positions: array<vec4<f32>, 16>,
+};
+
+@group(2)
+@binding(1)
+var<uniform> cameras: Cameras;
+
 struct VertexInput {
     @builtin(vertex_index) vertex_idx: u32,
     @location(0) v_pos: vec2<f32>,
     @location(1) position: vec3<f32>,
-    @location(2) size: vec2<f32>,
+    // Width and Height followed by the parallax factor.
+    @location(2) size: vec4<f32>,
     @location(3) uv: vec4<f32>,
-    @location(4) color: u32,
+    // Color followed by the content inset packed as u16's.
+    @location(4) color: vec3<u32>,
     @location(5) border_width: f32,
-    @location(6) border_color: u32,
+    // Border Color followed by the faded in Texture's bounds packed as u16's.
+    @location(6) border_color: vec3<u32>,
     @location(7) layer: u32,
-    @location(8) radius: f32,
-    @location(9) camera_type: u32,
+    // Radius followed by the distance the fade starts and ends at.
+    @location(8) radius: vec3<f32>,
+    @location(9) clip: vec4<f32>,
+    @location(10) gradient: vec2<u32>,
+    @location(11) gradient_angle: f32,
+    @location(12) flags: u32,
+    @location(13) border_gradient: vec2<u32>,
+    @location(14) border_gradient_angle: f32,
+    @location(15) gradient_map: vec4<u32>,
 };
 
 struct VertexOutput {
     @invariant @builtin(position) clip_position: vec4<f32>,
     @location(0) position: vec2<f32>,
     @location(1) uv: vec2<f32>,
+    @location(2) @interpolate(flat) border_gradient: vec2<u32>,
     @location(3) container_data: vec4<f32>,
     @location(4) color: vec4<f32>,
     @location(5) border_color: vec4<f32>,
@@ -39,9 +71,49 @@ struct VertexOutput {
     @location(7) border_width: f32,
     @location(8) radius: f32,
     @location(9) layer: i32,
-    @location(10) tex_size: vec2<f32>,
+    @location(10) @interpolate(flat) gradient: vec2<u32>,
+    @location(11) gradient_angle: f32,
+    @location(12) @interpolate(flat) flags: u32,
+    @location(13) border_gradient_angle: f32,
+    @location(14) @interpolate(flat) gradient_map: vec2<u32>,
+    @location(15) @interpolate(flat) uv_offset: vec2<f32>,
+    @location(16) @interpolate(flat) clip_rect: vec4<f32>,
+    @location(17) @interpolate(flat) clip_feather: f32,
+    @location(18) @interpolate(flat) distance_fade: f32,
+    @location(19) @interpolate(flat) content_inset: vec4<f32>,
+    @location(20) gradient_fill: vec4<f32>,
+    @location(21) @interpolate(flat) crossfade: vec4<f32>,
+    @location(22) @interpolate(flat) crossfade_bounds: vec2<u32>,
 };
 
+const RECT_FLAG_GRADIENT: u32 = 1u;
+const RECT_FLAG_DITHER: u32 = 2u;
+const RECT_FLAG_BORDER_GRADIENT: u32 = 4u;
+const RECT_FLAG_GRADIENT_MAP: u32 = 8u;
+const RECT_FLAG_PROGRESS_X: u32 = 16u;
+const RECT_FLAG_PROGRESS_Y: u32 = 32u;
+const RECT_FLAG_PROGRESS_RADIAL: u32 = 64u;
+const RECT_FLAG_GLOW: u32 = 128u;
+const RECT_PROGRESS_SHIFT: u32 = 20u;
+const RECT_PROGRESS_MAX: f32 = 4095.0;
+const RECT_CORNER_SHIFT: u32 = 8u;
+const RECT_CORNER_MASK: u32 = 127u;
+const RECT_FLAG_BORDER_OVER_FILL: u32 = 32768u;
+const RECT_FLAG_QUARTER_FRAME: u32 = 65536u;
+const RECT_FLAG_CHECKER: u32 = 131072u;
+const RECT_FILL_COMBINE_SHIFT: u32 = 18u;
+const RECT_FILL_COMBINE_MASK: u32 = 3u;
+const RECT_FILL_COMBINE_REPLACE: u32 = 1u;
+const RECT_FILL_COMBINE_ADD: u32 = 2u;
+const RECT_GRADIENT_CELLS_SHIFT: u32 = 16u;
+const RECT_FLAG_CROSSFADE: u32 = 2147483648u;
+const RECT_CROSSFADE_MIX_SHIFT: u32 = 12u;
+const RECT_CROSSFADE_MIX_MAX: u32 = 65535u;
+const RECT_LAYER_MASK: u32 = 4095u;
+const RECT_CAMERA_SHIFT: u32 = 12u;
+const RECT_CAMERA_MASK: u32 = 255u;
+const RECT_CLIP_FEATHER_SHIFT: u32 = 20u;
+
 @group(1)
 @binding(0)
 var tex: texture_2d_array<f32>;
@@ -58,7 +130,14 @@ fn unpack_tex_data(data: vec2<u32>) -> vec4<u32> {
     );
 }
 
+// Set when the Colors given are already linear. See ColorSpace.
+override LINEAR_COLORS: bool = false;
+
 fn srgb_to_linear(c: f32) -> f32 {
+    if LINEAR_COLORS {
+        return c;
+    }
+
     if c <= 0.04045 {
         return c / 12.92;
     } else {
@@ -82,34 +161,281 @@ fn vertex(
     var result: VertexOutput;
     let v = vertex.vertex_idx % 4u;
     let tex_data = vertex.uv;
-    let size = textureDimensions(tex);
-    let fsize = vec2<f32> (f32(size.x), f32(size.y));
-    var pos = vertex.position;
+    let camera_type = (vertex.layer >> RECT_CAMERA_SHIFT) & RECT_CAMERA_MASK;
+    let rect_size = vertex.size.xy;
+    let position = vec3<f32>(
+        vertex.position.xy + parallax_offset(camera_type, vertex.size.zw),
+        vertex.position.z,
+    );
+    let fraction = corner_fraction(v, vertex.flags, vertex.gradient_map.x);
+    let pos = vec3<f32>(position.xy + rect_size * fraction, position.z);
 
-     switch v {
+    result.uv = vec2<f32>(fraction.x * tex_data[2], (1.0 - fraction.y) * tex_data[3]);
+
+    if (camera_type >= 5u) {
+        let view_proj = cameras.view_proj[min(camera_type - 5u, 15u)];
+        let origin = view_proj * vec4<f32>(position, 1.0);
+        let corner = view_proj * vec4<f32>(position.xy + rect_size, position.z, 1.0);
+        let origin_px = (origin.xy / origin.w + 1.0) * 0.5 * frame.resolution;
+        let corner_px = (corner.xy / corner.w + 1.0) * 0.5 * frame.resolution;
+
+        result.clip_position = view_proj * vec4<f32>(pos, 1.0);
+        result.size = abs(corner_px - origin_px);
+        result.position = min(origin_px, corner_px);
+
+        // Y Down cameras flip the quad so flip the texture back upright.
+        if (corner_px.y < origin_px.y) {
+            result.uv.y = tex_data[3] - result.uv.y;
+        }
+    } else {
+        switch camera_type {
+            case 1u: {
+                result.clip_position = (global.proj * global.view) * vec4<f32>(pos, 1.0);
+                result.size = rect_size;
+                result.position = (global.view  * vec4<f32>(position.xy, 1.0, 1.0)).xy;
+            }
+            case 2u: {
+                let scale_mat = mat4x4<f32> (
+                    vec4<f32>(global.scale, 0.0, 0.0, 0.0),
+                    vec4<f32>(0.0, global.scale, 0.0, 0.0),
+                    vec4<f32>(0.0, 0.0, 1.0, 0.0),
+                    vec4<f32>(0.0, 0.0, 0.0, 1.0),
+                );
+
+                result.clip_position = (global.proj * global.view * scale_mat) * vec4<f32>(pos, 1.0);
+                result.size = rect_size * global.scale;
+                result.position = ((global.view * scale_mat) * vec4<f32>(position.xy, 1.0, 1.0)).xy;
+            }
+            case 3u: {
+                result.clip_position = (global.proj * global.manual_view) * vec4<f32>(pos, 1.0);
+                result.size = rect_size;
+                result.position = (global.manual_view  * vec4<f32>(position.xy, 1.0, 1.0)).xy;
+            }
+            case 4u: {
+                let scale_mat = mat4x4<f32> (
+                    vec4<f32>(global.manual_scale, 0.0, 0.0, 0.0),
+                    vec4<f32>(0.0, global.manual_scale, 0.0, 0.0),
+                    vec4<f32>(0.0, 0.0, 1.0, 0.0),
+                    vec4<f32>(0.0, 0.0, 0.0, 1.0),
+                );
+
+                result.clip_position = (global.proj * global.manual_view * scale_mat) * vec4<f32>(pos, 1.0);
+                result.size = rect_size * global.manual_scale;
+                result.position = ((global.manual_view * scale_mat) * vec4<f32>(position.xy, 1.0, 1.0)).xy;
+            }
+            default: {
+                result.clip_position = global.proj * vec4<f32>(pos, 1.0);
+                result.size = rect_size;
+                result.position = position.xy;
+            }
+        }
+    }
+
+    result.container_data = tex_data;
+    result.border_width = vertex.border_width;
+    result.radius = vertex.radius.x;
+    result.layer = i32(vertex.layer & RECT_LAYER_MASK);
+    result.color = unpack_color(vertex.color.x);
+    result.border_color = unpack_color(vertex.border_color.x);
+    result.crossfade_bounds = vertex.border_color.yz;
+    result.gradient = vertex.gradient;
+    result.gradient_angle = vertex.gradient_angle;
+    result.flags = vertex.flags;
+    result.border_gradient = vertex.border_gradient;
+    result.border_gradient_angle = vertex.border_gradient_angle;
+    result.gradient_map = vertex.gradient_map.xy;
+
+    // While cross fading the scroll fields hold the faded in Texture's origin.
+    if ((vertex.flags & RECT_FLAG_GRADIENT_MAP) == 0u
+        && (vertex.gradient_map.y & RECT_FLAG_CROSSFADE) != 0u) {
+        let mix = (vertex.gradient_map.y >> RECT_CROSSFADE_MIX_SHIFT) & RECT_CROSSFADE_MIX_MAX;
+
+        result.uv_offset = vec2<f32>(0.0);
+        result.crossfade = vec4<f32>(
+            bitcast<vec2<f32>>(vertex.gradient_map.zw),
+            f32(vertex.gradient_map.y & RECT_LAYER_MASK),
+            f32(mix) / f32(RECT_CROSSFADE_MIX_MAX),
+        );
+    } else {
+        result.uv_offset = scroll_offset(
+            bitcast<vec2<f32>>(vertex.gradient_map.zw),
+            tex_data.zw,
+        );
+        result.crossfade = vec4<f32>(0.0);
+    }
+    result.clip_feather = f32(vertex.layer >> RECT_CLIP_FEATHER_SHIFT) / 16.0;
+    result.content_inset = unpack_content_inset(vertex.color.yz, rect_size);
+
+    // The Fill Gradient is evaluated at each vertex of the tessellated grid
+    // and interpolated in between.
+    if ((vertex.flags & RECT_FLAG_GRADIENT) != 0u) {
+        let ndc = result.clip_position.xy / result.clip_position.w;
+
+        result.gradient_fill = gradient_color(
+            (ndc + 1.0) * 0.5 * global.size,
+            result.position,
+            result.size,
+            unpack_color(vertex.gradient[0]),
+            unpack_color(vertex.gradient[1]),
+            result.gradient_angle,
+            false,
+        );
+    }
+    result.distance_fade = distance_fade(
+        camera_type,
+        position.xy + rect_size * 0.5,
+        vertex.radius.yz,
+    );
+
+    if (result.clip_feather > 0.0) {
+        let view_proj = camera_matrix(camera_type);
+        let start = view_proj * vec4<f32>(vertex.clip.xy, position.z, 1.0);
+        let end = view_proj * vec4<f32>(vertex.clip.zw, position.z, 1.0);
+        let start_px = (start.xy / start.w + 1.0) * 0.5 * global.size;
+        let end_px = (end.xy / end.w + 1.0) * 0.5 * global.size;
+
+        result.clip_rect = vec4<f32>(min(start_px, end_px), max(start_px, end_px));
+    }
+
+    return result;
+}
+
+// Returns the fraction of the Rect the quad's corner lies at. Tessellated
+// Gradient cells store their column, row and the cells per side within the
+// gradient map data when no gradient map is used.
+fn corner_fraction(v: u32, flags: u32, cell: u32) -> vec2<f32> {
+    var corner = vec2<f32>(0.0);
+
+    switch v {
         case 1u: {
-            result.uv = vec2<f32>(tex_data[2], tex_data[3]);
-            pos.x += vertex.size.x;
+            corner = vec2<f32>(1.0, 0.0);
         }
         case 2u: {
-            result.uv = vec2<f32>(tex_data[2], 0.0);
-            pos.x += vertex.size.x;
-            pos.y += vertex.size.y;
+            corner = vec2<f32>(1.0, 1.0);
         }
         case 3u: {
-            result.uv = vec2<f32>(0.0, 0.0);
-            pos.y += vertex.size.y;
+            corner = vec2<f32>(0.0, 1.0);
+        }
+        default: {}
+    }
+
+    let cells = cell >> RECT_GRADIENT_CELLS_SHIFT;
+
+    if ((flags & RECT_FLAG_GRADIENT_MAP) != 0u || cells <= 1u) {
+        return corner;
+    }
+
+    let index = vec2<f32>(f32((cell >> 8u) & 0xffu), f32(cell & 0xffu));
+    return (index + corner) / f32(cells);
+}
+
+// Returns the content inset of each side as a fraction of the Rect's size.
+fn unpack_content_inset(data: vec2<u32>, size: vec2<f32>) -> vec4<f32> {
+    let inset = vec4<f32>(unpack_tex_data(data)) / 16.0;
+    let safe_size = max(size, vec2<f32>(0.0001));
+
+    return inset / vec4<f32>(safe_size, safe_size);
+}
+
+// Returns how far the Rect moves so the camera type scrolls it by the parallax
+// factor. 1.0 follows the camera and 0.0 stays fixed on the screen.
+fn parallax_offset(camera_type: u32, factor: vec2<f32>) -> vec2<f32> {
+    let follow = vec2<f32>(1.0) - factor;
+
+    if (all(follow == vec2<f32>(0.0))) {
+        return vec2<f32>(0.0);
+    }
+
+    if (camera_type >= 5u) {
+        return follow * cameras.positions[min(camera_type - 5u, 15u)].xy;
+    }
+
+    switch camera_type {
+        case 1u: {
+            return follow * view_position(global.view, 1.0);
+        }
+        case 2u: {
+            return follow * view_position(global.view, global.scale);
+        }
+        case 3u: {
+            return follow * view_position(global.manual_view, 1.0);
+        }
+        case 4u: {
+            return follow * view_position(global.manual_view, global.manual_scale);
         }
         default: {
-            result.uv = vec2<f32>(0.0, tex_data[3]);
+            return vec2<f32>(0.0);
+        }
+    }
+}
+
+// Returns the World position the view translates to its origin.
+fn view_position(view: mat4x4<f32>, scale: f32) -> vec2<f32> {
+    let axis = vec2<f32>(view[0].x, view[1].y) * scale;
+
+    if (any(axis == vec2<f32>(0.0))) {
+        return vec2<f32>(0.0);
+    }
+
+    return -view[3].xy / axis;
+}
+
+// Returns the alpha of a Rect whose center lies at point as it fades out
+// between the start and end distance from the center of the camera's view.
+fn distance_fade(camera_type: u32, point: vec2<f32>, fade: vec2<f32>) -> f32 {
+    if (fade.y <= 0.0) {
+        return 1.0;
+    }
+
+    var center: vec2<f32>;
+
+    if (camera_type >= 5u) {
+        center = cameras.positions[min(camera_type - 5u, 15u)].zw;
+    } else {
+        switch camera_type {
+            case 1u: {
+                center = view_center(global.view, 1.0);
+            }
+            case 2u: {
+                center = view_center(global.view, global.scale);
+            }
+            case 3u: {
+                center = view_center(global.manual_view, 1.0);
+            }
+            case 4u: {
+                center = view_center(global.manual_view, global.manual_scale);
+            }
+            default: {
+                return 1.0;
+            }
         }
     }
 
-    switch vertex.camera_type {
+    let dist = distance(point, center);
+    return clamp((fade.y - dist) / max(fade.y - fade.x, 0.0001), 0.0, 1.0);
+}
+
+// Returns the World position shown at the center of the view.
+fn view_center(view: mat4x4<f32>, scale: f32) -> vec2<f32> {
+    let axis = vec2<f32>(view[0].x, view[1].y) * scale;
+    let center = global.inverse_proj * vec4<f32>(0.0, 0.0, 0.0, 1.0);
+
+    if (any(axis == vec2<f32>(0.0)) || center.w == 0.0) {
+        return vec2<f32>(0.0);
+    }
+
+    return (center.xy / center.w - view[3].xy) / axis;
+}
+
+// Returns the matrix the camera type projects the Rect with.
+fn camera_matrix(camera_type: u32) -> mat4x4<f32> {
+    if (camera_type >= 5u) {
+        return cameras.view_proj[min(camera_type - 5u, 15u)];
+    }
+
+    switch camera_type {
         case 1u: {
-            result.clip_position = (global.proj * global.view) * vec4<f32>(pos, 1.0);
-            result.size = vertex.size;
-            result.position = (global.view  * vec4<f32>(vertex.position.xy, 1.0, 1.0)).xy;
+            return global.proj * global.view;
         }
         case 2u: {
             let scale_mat = mat4x4<f32> (
@@ -119,14 +445,10 @@ fn vertex(
                 vec4<f32>(0.0, 0.0, 0.0, 1.0),
             );
 
-            result.clip_position = (global.proj * global.view * scale_mat) * vec4<f32>(pos, 1.0);
-            result.size = vertex.size * global.scale;
-            result.position = ((global.view * scale_mat) * vec4<f32>(vertex.position.xy, 1.0, 1.0)).xy;
+            return global.proj * global.view * scale_mat;
         }
         case 3u: {
-            result.clip_position = (global.proj * global.manual_view) * vec4<f32>(pos, 1.0);
-            result.size = vertex.size;
-            result.position = (global.manual_view  * vec4<f32>(vertex.position.xy, 1.0, 1.0)).xy;
+            return global.proj * global.manual_view;
         }
         case 4u: {
             let scale_mat = mat4x4<f32> (
@@ -136,25 +458,206 @@ fn vertex(
                 vec4<f32>(0.0, 0.0, 0.0, 1.0),
             );
 
-            result.clip_position = (global.proj * global.manual_view * scale_mat) * vec4<f32>(pos, 1.0);
-            result.size = vertex.size * global.manual_scale;
-            result.position = ((global.manual_view * scale_mat) * vec4<f32>(vertex.position.xy, 1.0, 1.0)).xy;
+            return global.proj * global.manual_view * scale_mat;
         }
         default: {
-            result.clip_position = global.proj * vec4<f32>(pos, 1.0);
-            result.size = vertex.size;
-            result.position = vertex.position.xy;
+            return global.proj;
+        }
+    }
+}
+
+// Fades the alpha out within clip_feather pixels of the Clipping Bounds edges.
+fn clip_fade(vertex: VertexOutput) -> f32 {
+    if (vertex.clip_feather <= 0.0) {
+        return 1.0;
+    }
+
+    let clippy = vec2<f32>(vertex.clip_position.x, global.size.y - vertex.clip_position.y);
+    let inside = min(clippy - vertex.clip_rect.xy, vertex.clip_rect.zw - clippy);
+    return clamp(min(inside.x, inside.y) / vertex.clip_feather, 0.0, 1.0);
+}
+
+// Composites the border with its coverage over the fill as premultiplied colors
+// in one step so translucent borders and fills do not double blend.
+fn border_over_fill(fill: vec4<f32>, border: vec4<f32>, coverage: f32) -> vec4<f32> {
+    let border_alpha = border.a * coverage;
+    let alpha = border_alpha + fill.a * (1.0 - border_alpha);
+
+    if (alpha <= 0.0) {
+        return vec4<f32>(0.0);
+    }
+
+    let color = border.rgb * border_alpha + fill.rgb * fill.a * (1.0 - border_alpha);
+    return vec4<f32>(color / alpha, alpha);
+}
+
+// Combines the sampled texture with the fill color or gradient.
+fn combine_fill(tex_color: vec4<f32>, fill: vec4<f32>, flags: u32) -> vec4<f32> {
+    switch ((flags >> RECT_FILL_COMBINE_SHIFT) & RECT_FILL_COMBINE_MASK) {
+        case RECT_FILL_COMBINE_REPLACE: {
+            return tex_color;
+        }
+        case RECT_FILL_COMBINE_ADD: {
+            return vec4<f32>(min(tex_color.rgb + fill.rgb * fill.a, vec3<f32>(1.0)), tex_color.a);
+        }
+        default: {
+            return tex_color * fill;
         }
     }
- 
-    result.container_data = tex_data;
-    result.border_width = vertex.border_width;
-    result.radius = vertex.radius;
-    result.tex_size = fsize;
-    result.layer = i32(vertex.layer);
-    result.color = unpack_color(vertex.color);
-    result.border_color = unpack_color(vertex.border_color);
-    return result;
+}
+
+// Returns the checkerboard color of the cell the pixel falls within.
+// Cells are counted from the bottom left corner of the Rect.
+fn checker_color(
+    pixel: vec2<f32>,
+    position: vec2<f32>,
+    color_a: vec4<f32>,
+    color_b: vec4<f32>,
+    size: f32,
+) -> vec4<f32> {
+    let cell = floor((pixel - position) / max(size, 1.0));
+
+    if ((i32(cell.x) + i32(cell.y)) % 2 == 0) {
+        return color_a;
+    }
+
+    return color_b;
+}
+
+// Returns how far the texture has scrolled wrapped within its region size.
+fn scroll_offset(velocity: vec2<f32>, size: vec2<f32>) -> vec2<f32> {
+    if (all(velocity == vec2<f32>(0.0)) || any(size <= vec2<f32>(0.0))) {
+        return vec2<f32>(0.0);
+    }
+
+    let offset = velocity * frame.time;
+    return offset - floor(offset / size) * size;
+}
+
+// Samples the layer at a texel position blending the four nearest texels.
+fn sample_texel(
+    texel: vec2<f32>,
+    layer: i32,
+    tex_size: vec2<f32>,
+    origin: vec2<f32>,
+    region: vec2<f32>,
+    scrolling: bool,
+) -> vec4<f32> {
+    let coords = texel / tex_size;
+
+    var step = vec2<f32>(0.5, 0.5);
+    var tex_pixel = tex_size * coords - step.xy / 2.0;
+
+    let corner = floor(tex_pixel) + 1.0;
+    let frac = min((corner - tex_pixel) * vec2<f32>(2.0, 2.0), vec2<f32>(1.0, 1.0));
+
+    var t1 = floor(tex_pixel + vec2<f32>(0.0, 0.0));
+    var t2 = floor(tex_pixel + vec2<f32>(step.x, 0.0));
+    var t3 = floor(tex_pixel + vec2<f32>(0.0, step.y));
+    var t4 = floor(tex_pixel + step.xy);
+
+    if (scrolling) {
+        t1 = wrap_texel(t1, origin, region);
+        t2 = wrap_texel(t2, origin, region);
+        t3 = wrap_texel(t3, origin, region);
+        t4 = wrap_texel(t4, origin, region);
+    }
+
+    var c1 = textureSampleLevel(tex, tex_sample, (t1 + 0.5) / tex_size, layer, 1.0);
+    var c2 = textureSampleLevel(tex, tex_sample, (t2 + 0.5) / tex_size, layer, 1.0);
+    var c3 = textureSampleLevel(tex, tex_sample, (t3 + 0.5) / tex_size, layer, 1.0);
+    var c4 = textureSampleLevel(tex, tex_sample, (t4 + 0.5) / tex_size, layer, 1.0);
+
+    c1 = c1 * (frac.x * frac.y);
+    c2 = c2 *((1.0 - frac.x) * frac.y);
+    c3 = c3 * (frac.x * (1.0 - frac.y));
+    c4 = c4 *((1.0 - frac.x) * (1.0 - frac.y));
+
+    return c1 + c2 + c3 + c4;
+}
+
+// Wraps a texel back within the region so scrolled samples never bleed into neighbours.
+fn wrap_texel(texel: vec2<f32>, origin: vec2<f32>, size: vec2<f32>) -> vec2<f32> {
+    let local = texel - origin;
+    return origin + local - floor(local / size) * size;
+}
+
+fn gradient_color(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    start: vec4<f32>,
+    end: vec4<f32>,
+    angle: f32,
+    dither: bool,
+) -> vec4<f32> {
+    let dir = vec2<f32>(cos(angle), sin(angle));
+    let p = (frag_coord - position) / max(size, vec2<f32>(1.0)) - 0.5;
+    let t = clamp(dot(p, dir) / (abs(dir.x) + abs(dir.y)) + 0.5, 0.0, 1.0);
+    let color = mix(start, end, t);
+
+    if (dither) {
+        return dither_color(color, frag_coord);
+    }
+
+    return color;
+}
+
+// Interleaved gradient noise spreads the 8 bit steps so they do not band.
+fn dither_color(color: vec4<f32>, frag_coord: vec2<f32>) -> vec4<f32> {
+    let noise = fract(52.9829189 * fract(dot(frag_coord, vec2<f32>(0.06711056, 0.00583715))));
+    return vec4<f32>(color.rgb + (noise - 0.5) / 255.0, color.a);
+}
+
+fn gradient_map_color(
+    color: vec4<f32>,
+    ramp_data: vec2<u32>,
+    tex_size: vec2<f32>,
+) -> vec4<f32> {
+    let ramp = unpack_tex_data(ramp_data);
+    // Luminance is taken in gamma space so mid grays land mid ramp.
+    let luminance = pow(dot(color.rgb, vec3<f32>(0.2126, 0.7152, 0.0722)), 1.0 / 2.2);
+    let x = f32(ramp[0]) + 0.5 + clamp(luminance, 0.0, 1.0) * max(f32(ramp[2]) - 1.0, 0.0);
+    let coords = vec2<f32>(x, f32(ramp[1]) + 0.5) / tex_size;
+    let ramp_color = textureSampleLevel(tex, tex_sample, coords, i32(ramp[3]), 0.0);
+
+    return vec4<f32>(ramp_color.rgb, ramp_color.a * color.a);
+}
+
+// Returns the coverage 0.0 to 1.0 of the pixel within the revealed Progress area.
+fn progress_alpha(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    flags: u32,
+) -> f32 {
+    let progress = f32(flags >> RECT_PROGRESS_SHIFT) / RECT_PROGRESS_MAX;
+    let local = frag_coord - position;
+
+    if ((flags & RECT_FLAG_PROGRESS_X) != 0u) {
+        return clamp(progress * size.x - local.x + 0.5, 0.0, 1.0);
+    }
+
+    if ((flags & RECT_FLAG_PROGRESS_Y) != 0u) {
+        return clamp(progress * size.y - local.y + 0.5, 0.0, 1.0);
+    }
+
+    if ((flags & RECT_FLAG_PROGRESS_RADIAL) != 0u) {
+        let pi = 3.14159265;
+        let dir = local - size * 0.5;
+        // Angle clockwise from the top as 0.0 to 1.0.
+        let sweep = fract(atan2(dir.x, dir.y) / (2.0 * pi) + 1.0);
+        // Convert the angular distance to pixels at this radius for a smooth edge.
+        let edge = (progress - sweep) * 2.0 * pi * length(dir);
+
+        if (progress <= 0.0) {
+            return 0.0;
+        }
+
+        return clamp(edge + 0.5, 0.0, 1.0);
+    }
+
+    return 1.0;
 }
 
 fn distance_alg(
@@ -178,47 +681,216 @@ fn distance_alg(
     return sqrt(dist.x * dist.x + dist.y * dist.y);
 }
 
+// Same as distance_alg but measures the corners with a superellipse exponent.
+// An exponent of 2.0 gives circular corners and higher gives squircles.
+fn corner_distance(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radius: f32,
+    exponent: f32,
+) -> f32 {
+    if (exponent <= 2.0) {
+        return distance_alg(frag_coord, position, size, radius);
+    }
+
+    let top_left = position + vec2<f32>(radius);
+    let bottom_right = position + size - vec2<f32>(radius);
+    let dist = max(max(top_left - frag_coord, frag_coord - bottom_right), vec2<f32>(0.0));
+
+    return pow(pow(dist.x, exponent) + pow(dist.y, exponent), 1.0 / exponent);
+}
+
+fn corner_exponent(flags: u32) -> f32 {
+    return 2.0 + f32((flags >> RECT_CORNER_SHIFT) & RECT_CORNER_MASK) / f32(RECT_CORNER_MASK) * 8.0;
+}
+
+// Additive halo outside of the Rect's shape. The quad was expanded by the glow
+// radius which is stored in border_width with the intensity in gradient_angle.
+fn glow_color(vertex: VertexOutput, clippy: vec2<f32>) -> vec4<f32> {
+    let glow_radius = vertex.border_width;
+    let dist = corner_distance(
+        clippy,
+        vertex.position.xy + vec2<f32>(glow_radius),
+        vertex.size - vec2<f32>(glow_radius * 2.0),
+        vertex.radius,
+        corner_exponent(vertex.flags),
+    ) - vertex.radius;
+
+    // Inside the shape is left to the Rect itself.
+    if (dist <= 0.0) {
+        return vec4<f32>(0.0);
+    }
+
+    let falloff = 1.0 - clamp(dist / glow_radius, 0.0, 1.0);
+    let strength = vertex.color.a * vertex.gradient_angle * falloff * falloff;
+
+    return vec4<f32>(vertex.color.rgb * strength, strength);
+}
+
 @fragment
 fn fragment(vertex: VertexOutput,) -> @location(0) vec4<f32> {
+    let color = rect_color(vertex);
+    return vec4<f32>(color.rgb, color.a * clip_fade(vertex) * vertex.distance_fade);
+}
+
+// Premultiplied output used by the Multiply, Screen and Add composite ops.
+@fragment
+fn fragment_premultiplied(vertex: VertexOutput,) -> @location(0) vec4<f32> {
+    let color = rect_color(vertex);
+    let alpha = color.a * clip_fade(vertex) * vertex.distance_fade;
+    return vec4<f32>(color.rgb * alpha, alpha);
+}
+
+fn rect_color(vertex: VertexOutput) -> vec4<f32> {
     var container_color = vertex.color;
+    let tex_size = vec2<f32>(textureDimensions(tex));
+    let clippy = vec2<f32>(vertex.clip_position.x, global.size.y - vertex.clip_position.y);
 
-    if (vertex.container_data[2] > 0.0 || vertex.container_data[3] > 0.0 ) {
-        let coords = vec2<f32>(
-            (vertex.container_data[0] + vertex.uv.x) / vertex.tex_size.x,
-            (vertex.container_data[1] + vertex.uv.y) / vertex.tex_size.y
-        );
+    if ((vertex.flags & RECT_FLAG_GLOW) != 0u) {
+        let glow = glow_color(vertex, clippy);
+
+        if (glow.a <= 0.0) {
+            discard;
+        }
+
+        return glow;
+    }
+
+    let checker = (vertex.flags & RECT_FLAG_CHECKER) != 0u;
+
+    if ((vertex.flags & RECT_FLAG_GRADIENT) != 0u) {
+        container_color = vertex.gradient_fill;
+
+        if ((vertex.flags & RECT_FLAG_DITHER) != 0u) {
+            container_color = dither_color(container_color, clippy);
+        }
+    }
+
+    var content_uv = vertex.uv;
+    var in_content = true;
 
-        var step = vec2<f32>(0.5, 0.5);
-        var tex_pixel = vertex.tex_size * coords - step.xy / 2.0;
+    // Squeezes the texture within the content inset leaving the fill around it.
+    if (any(vertex.content_inset != vec4<f32>(0.0))) {
+        let region = vertex.container_data.zw;
+        // Texture rows go down so the top inset starts the V axis.
+        let low = vec2<f32>(vertex.content_inset.x, vertex.content_inset.w) * region;
+        let high = vec2<f32>(vertex.content_inset.z, vertex.content_inset.y) * region;
+        let inner = max(region - low - high, vec2<f32>(0.0001));
 
-        let corner = floor(tex_pixel) + 1.0;
-        let frac = min((corner - tex_pixel) * vec2<f32>(2.0, 2.0), vec2<f32>(1.0, 1.0));
+        content_uv = (vertex.uv - low) / inner * region;
+        in_content = all(content_uv >= vec2<f32>(0.0)) && all(content_uv <= region);
+    }
+
+    if ((vertex.container_data[2] > 0.0 || vertex.container_data[3] > 0.0) && in_content) {
+        let origin = vertex.container_data.xy;
+        let region = vertex.container_data.zw;
+        let scrolling = any(vertex.uv_offset != vec2<f32>(0.0));
+        var uv = content_uv;
+
+        // Mirrors the corner into each quadrant and stretches its last texel.
+        if ((vertex.flags & RECT_FLAG_QUARTER_FRAME) != 0u) {
+            let local = vertex.uv / region * vertex.size;
+            uv = clamp(min(local, vertex.size - local), vec2<f32>(0.0), region - 0.5);
+        }
 
-        var c1 = textureSampleLevel(tex, tex_sample, (floor(tex_pixel + vec2<f32>(0.0, 0.0)) + 0.5) / vertex.tex_size, vertex.layer, 1.0);
-        var c2 = textureSampleLevel(tex, tex_sample, (floor(tex_pixel + vec2<f32>(step.x, 0.0)) + 0.5) / vertex.tex_size, vertex.layer, 1.0);
-        var c3 = textureSampleLevel(tex, tex_sample, (floor(tex_pixel + vec2<f32>(0.0, step.y)) + 0.5) / vertex.tex_size, vertex.layer, 1.0);
-        var c4 = textureSampleLevel(tex, tex_sample, (floor(tex_pixel + step.xy) + 0.5) / vertex.tex_size, vertex.layer, 1.0);
+        if (scrolling) {
+            uv = uv + vertex.uv_offset;
+            uv = uv - floor(uv / region) * region;
+        }
+
+        var tex_color = sample_texel(
+            origin + uv,
+            vertex.layer,
+            tex_size,
+            origin,
+            region,
+            scrolling,
+        );
+
+        // Mixes in the same area of the Texture fading in.
+        if (vertex.crossfade.w > 0.0) {
+            let fade_origin = vertex.crossfade.xy;
+            // Keeps the taps within the faded in Texture when it is smaller.
+            let fade_low = vec2<f32>(
+                f32(vertex.crossfade_bounds.x & 0xffffu),
+                f32(vertex.crossfade_bounds.x >> 16u),
+            );
+            let fade_high = vec2<f32>(
+                f32(vertex.crossfade_bounds.y & 0xffffu),
+                f32(vertex.crossfade_bounds.y >> 16u),
+            );
+
+            tex_color = mix(
+                tex_color,
+                sample_texel(
+                    clamp(fade_origin + uv, fade_low + 0.5, fade_high - 0.5),
+                    i32(vertex.crossfade.z),
+                    tex_size,
+                    fade_origin,
+                    region,
+                    false,
+                ),
+                vertex.crossfade.w,
+            );
+        }
 
-        c1 = c1 * (frac.x * frac.y);
-        c2 = c2 *((1.0 - frac.x) * frac.y);
-        c3 = c3 * (frac.x * (1.0 - frac.y));
-        c4 = c4 *((1.0 - frac.x) * (1.0 - frac.y));
+        if ((vertex.flags & RECT_FLAG_GRADIENT_MAP) != 0u) {
+            tex_color = gradient_map_color(tex_color, vertex.gradient_map, tex_size);
+        }
 
-        container_color = (c1 + c2 + c3 + c4) * container_color;
+        container_color = combine_fill(tex_color, container_color, vertex.flags);
+
+        // The tinted texture is drawn over the checkerboard.
+        if (checker) {
+            container_color = border_over_fill(
+                checker_color(
+                    clippy,
+                    vertex.position.xy,
+                    unpack_color(vertex.gradient[0]),
+                    unpack_color(vertex.gradient[1]),
+                    vertex.gradient_angle,
+                ),
+                container_color,
+                1.0,
+            );
+        }
+    } else if (checker) {
+        container_color = checker_color(
+            clippy,
+            vertex.position.xy,
+            unpack_color(vertex.gradient[0]),
+            unpack_color(vertex.gradient[1]),
+            vertex.gradient_angle,
+        );
     }
 
     var mixed_color: vec4<f32> = container_color;
     let radius = vertex.radius;
-    let clippy = vec2<f32>(vertex.clip_position.x, global.size.y - vertex.clip_position.y);
 
     if (vertex.border_width > 0.0) {
+        var border_color = vertex.border_color;
+
+        if ((vertex.flags & RECT_FLAG_BORDER_GRADIENT) != 0u) {
+            border_color = gradient_color(
+                clippy,
+                vertex.position.xy,
+                vertex.size,
+                unpack_color(vertex.border_gradient[0]),
+                unpack_color(vertex.border_gradient[1]),
+                vertex.border_gradient_angle,
+                (vertex.flags & RECT_FLAG_DITHER) != 0u,
+            );
+        }
+
         var border: f32 = max(radius - vertex.border_width, 0.0);
 
-        let distance = distance_alg( 
-            clippy, 
-            vertex.position.xy + vec2<f32>(vertex.border_width), 
-            vertex.size - vec2<f32>(vertex.border_width * 2.0), 
-            border 
+        let distance = corner_distance(
+            clippy,
+            vertex.position.xy + vec2<f32>(vertex.border_width),
+            vertex.size - vec2<f32>(vertex.border_width * 2.0),
+            border,
+            corner_exponent(vertex.flags),
         );
 
         let border_mix: f32 = smoothstep(
@@ -227,14 +899,19 @@ fn fragment(vertex: VertexOutput,) -> @location(0) vec4<f32> {
             distance
         );
 
-        mixed_color = mix(container_color, vertex.border_color, vec4<f32>(border_mix));
+        if ((vertex.flags & RECT_FLAG_BORDER_OVER_FILL) != 0u) {
+            mixed_color = border_over_fill(container_color, border_color, border_mix);
+        } else {
+            mixed_color = mix(container_color, border_color, vec4<f32>(border_mix));
+        }
     }
 
-    let dist: f32 = distance_alg(
+    let dist: f32 = corner_distance(
         clippy,
         vertex.position.xy,
         vertex.size,
-        radius
+        radius,
+        corner_exponent(vertex.flags),
     );
 
     let radius_alpha: f32 = 1.0 - smoothstep(
@@ -242,7 +919,12 @@ fn fragment(vertex: VertexOutput,) -> @location(0) vec4<f32> {
         radius + 0.5,
         dist);
 
-    let alpha = mixed_color.a * radius_alpha;
+    let alpha = mixed_color.a * radius_alpha * progress_alpha(
+        clippy,
+        vertex.position.xy,
+        vertex.size,
+        vertex.flags,
+    );
 
     if (alpha <= 0.0) {
         discard;
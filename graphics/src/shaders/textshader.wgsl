@@ -24,6 +24,7 @@ struct VertexInput {
     @location(5) color: u32,
     @location(6) camera_type: u32,
     @location(7) is_color: u32,
+    @location(8) tex_hw: vec2<f32>,
 };
 
 struct VertexOutput {
@@ -48,7 +49,21 @@ var emoji_tex: texture_2d_array<f32>;
 @binding(1)
 var emoji_tex_sample: sampler;
 
+@group(3)
+@binding(0)
+var sdf_tex: texture_2d_array<f32>;
+@group(3)
+@binding(1)
+var sdf_tex_sample: sampler;
+
+// Set when the Colors given are already linear. See ColorSpace.
+override LINEAR_COLORS: bool = false;
+
 fn srgb_to_linear(c: f32) -> f32 {
+    if LINEAR_COLORS {
+        return c;
+    }
+
     if c <= 0.04045 {
         return c / 12.92;
     } else {
@@ -75,6 +90,8 @@ fn vertex(
 
     if vertex.is_color == 1u {
         size = textureDimensions(emoji_tex);
+    } else if vertex.is_color == 2u {
+        size = textureDimensions(sdf_tex);
     } else {
         size = textureDimensions(tex);
     }
@@ -84,11 +101,11 @@ fn vertex(
 
     switch v {
         case 1u: {
-            result.uv = vec2<f32>(vertex.uv.x + vertex.hw.x, vertex.uv.y + vertex.hw.y) /  fsize;
+            result.uv = vec2<f32>(vertex.uv.x + vertex.tex_hw.x, vertex.uv.y + vertex.tex_hw.y) /  fsize;
             pos.x += vertex.hw.x;
         }
         case 2u: {
-            result.uv = vec2<f32>(vertex.uv.x + vertex.hw.x, vertex.uv.y) /  fsize;
+            result.uv = vec2<f32>(vertex.uv.x + vertex.tex_hw.x, vertex.uv.y) /  fsize;
             pos.x += vertex.hw.x;
             pos.y += vertex.hw.y;
         }
@@ -97,7 +114,7 @@ fn vertex(
             pos.y += vertex.hw.y;
         }
         default: {
-            result.uv = vec2<f32>(vertex.uv.x, vertex.uv.y + vertex.hw.y) /  fsize;
+            result.uv = vec2<f32>(vertex.uv.x, vertex.uv.y + vertex.tex_hw.y) /  fsize;
         }
     }
 
@@ -142,7 +159,19 @@ fn vertex(
 // Fragment shader
 @fragment
 fn fragment(vertex: VertexOutput,) -> @location(0) vec4<f32> {
-     if (vertex.is_color == 1u) {
+    // Sampled before branching as fwidth requires uniform control flow.
+    let distance = textureSample(sdf_tex, sdf_tex_sample, vertex.uv.xy, vertex.layer).r;
+    let edge_width = max(fwidth(distance) * 0.5, 0.001);
+
+     if (vertex.is_color == 2u) {
+        let alpha = smoothstep(0.5 - edge_width, 0.5 + edge_width, distance);
+
+        if alpha <= 0.0 {
+            discard;
+        }
+
+        return vertex.color.rgba * alpha;
+    } else if (vertex.is_color == 1u) {
         let object_color = textureSampleLevel(emoji_tex, emoji_tex_sample, vertex.uv.xy, vertex.layer, 1.0);
 
         if object_color.a <= 0.0 {
@@ -2,30 +2,45 @@ mod bounds;
 mod buffer;
 mod device;
 mod draw_order;
+mod frame;
+mod frame_stats;
+#[cfg(feature = "gpu-timing")]
+mod gpu_timer;
 mod instance_buffer;
 mod layout;
 mod pass;
 mod pipelines;
+mod render_layer;
 mod renderer;
 mod static_vbo;
+mod surface;
 mod system;
 mod vbo;
+mod world_camera;
 
-pub use bounds::Bounds;
+pub use bounds::{Bounds, BoundsStack};
 pub use buffer::{
     AsBufferPass, Buffer, BufferData, BufferLayout, BufferPass, BufferStore,
 };
 pub use device::*;
 pub use draw_order::{DrawOrder, Index, OrderedIndex};
+pub use frame::*;
+pub use frame_stats::FrameStats;
+pub(crate) use frame_stats::FrameStatsRecorder;
+#[cfg(feature = "gpu-timing")]
+pub use gpu_timer::GpuTimer;
 pub use instance_buffer::*;
 pub use layout::*;
 pub use pass::*;
 pub use pipelines::*;
+pub use render_layer::RenderLayers;
 pub use renderer::*;
 pub use slotmap::KeyData;
 pub use static_vbo::*;
+pub use surface::*;
 pub use system::*;
 pub use vbo::*;
+pub use world_camera::{Camera, CameraId, YAxis};
 
 pub(crate) use ahash::{AHashMap, AHashSet, AHasher};
 
@@ -43,6 +58,31 @@ pub enum CameraType {
     ManualViewWithScale,
 }
 
+/// How the Pipelines use the Depth Buffer.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DepthMode {
+    /// Depth tested and written so the Z of each Object decides what is on top.
+    #[default]
+    Depth,
+    /// No Depth Buffer. Objects are drawn in submission order, which follows
+    /// their sorted [`DrawOrder`], with later draws painted over earlier ones.
+    PainterOnly,
+}
+
+/// Color space the Colors given to setters such as [`crate::Rect::set_color`]
+/// are in. The Shaders convert them to linear so Gradients and alpha
+/// Blending happen in linear space before being written to the sRGB Surface.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Colors are sRGB like the values picked in design tools.
+    #[default]
+    Srgb,
+    /// Colors are already linear and are used as is.
+    Linear,
+}
+
 /// Type of Texture Flipping in shader.
 ///
 #[derive(Copy, Clone, Debug)]
@@ -85,6 +85,46 @@ impl Bounds {
             self.right = limits.right;
         }
     }
+
+    /// Returns the smallest [`Bounds`] containing both [`Bounds`].
+    ///
+    pub fn union(&self, other: &Bounds) -> Bounds {
+        Bounds {
+            left: self.left.min(other.left),
+            bottom: self.bottom.min(other.bottom),
+            right: self.right.max(other.right),
+            top: self.top.max(other.top),
+        }
+    }
+
+    /// Returns the overlapping area of both [`Bounds`].
+    /// If they do not overlap the returned [`Bounds`] has no width or height.
+    ///
+    pub fn intersect(&self, other: &Bounds) -> Bounds {
+        let left = self.left.max(other.left);
+        let bottom = self.bottom.max(other.bottom);
+
+        Bounds {
+            left,
+            bottom,
+            right: self.right.min(other.right).max(left),
+            top: self.top.min(other.top).max(bottom),
+        }
+    }
+
+    /// Returns true if the area overlaps the [`Bounds`] at all.
+    /// Used to cull anything outside of [`crate::Camera::visible_bounds`].
+    ///
+    /// # Arguments
+    /// - position: Bottom left of the area.
+    /// - size: Width and Height of the area.
+    ///
+    pub fn is_visible_in(&self, position: Vec2, size: Vec2) -> bool {
+        position.x < self.right
+            && position.x + size.x > self.left
+            && position.y < self.top
+            && position.y + size.y > self.bottom
+    }
 }
 
 impl Default for Bounds {
@@ -97,3 +137,66 @@ impl Default for Bounds {
         }
     }
 }
+
+/// Stack of nested Clipping [`Bounds`] for immediate mode rendering.
+/// Each pushed [`Bounds`] is intersected with the current top so the top is
+/// always the effective Clipping [`Bounds`] to give to [`crate::Rect::update_bounds`].
+///
+#[derive(Clone, Debug, Default)]
+pub struct BoundsStack {
+    /// Effective [`Bounds`] of each pushed level.
+    stack: Vec<Bounds>,
+}
+
+impl BoundsStack {
+    /// Creates a new empty [`BoundsStack`].
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes [`Bounds`] intersected with the current top.
+    /// Returns the new effective [`Bounds`].
+    ///
+    pub fn push(&mut self, bounds: Bounds) -> Bounds {
+        let bounds = match self.stack.last() {
+            Some(top) => top.intersect(&bounds),
+            None => bounds,
+        };
+
+        self.stack.push(bounds);
+        bounds
+    }
+
+    /// Pops the top [`Bounds`].
+    /// Returns the effective [`Bounds`] left or None if the stack is empty.
+    ///
+    pub fn pop(&mut self) -> Option<Bounds> {
+        self.stack.pop();
+        self.current()
+    }
+
+    /// Returns the current effective [`Bounds`] or None if the stack is empty.
+    ///
+    pub fn current(&self) -> Option<Bounds> {
+        self.stack.last().copied()
+    }
+
+    /// Returns how many [`Bounds`] are pushed.
+    ///
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// If no [`Bounds`] are pushed.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Removes all pushed [`Bounds`].
+    ///
+    pub fn clear(&mut self) {
+        self.stack.clear();
+    }
+}
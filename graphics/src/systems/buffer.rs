@@ -45,6 +45,15 @@ impl BufferStore {
             index_pos: Range::default(),
         }
     }
+
+    /// Replaces the store with bytes and marks the [`BufferStore`] as changed.
+    /// The store is never padded as Instance Buffers count Instances by its length.
+    ///
+    pub fn set_store(&mut self, bytes: &[u8]) {
+        self.store.clear();
+        self.store.extend_from_slice(bytes);
+        self.changed = true;
+    }
 }
 
 /// Pass of Data from a Vertex or Static Vertex used to Set the
@@ -1,7 +1,7 @@
-use crate::{GpuRenderer, GraphicsError};
+use crate::{ColorSpace, DepthMode, GpuRenderer, GraphicsError};
 use async_trait::async_trait;
 use log::{debug, info};
-use std::{path::Path, sync::Arc};
+use std::{collections::HashMap, path::Path, sync::Arc};
 use wgpu::{
     core::instance::RequestAdapterError, Adapter, Backend, Backends,
     DeviceType, Surface, TextureFormat,
@@ -12,9 +12,22 @@ use winit::{dpi::PhysicalSize, event::WindowEvent, window::Window};
 pub struct GpuDevice {
     pub device: wgpu::Device,
     pub queue: wgpu::Queue,
+    pub(crate) depth_mode: DepthMode,
+    pub(crate) color_space: ColorSpace,
 }
 
 impl GpuDevice {
+    /// Creates a [`GpuDevice`] from a [`wgpu::Device`] and its [`wgpu::Queue`].
+    ///
+    pub fn new(device: wgpu::Device, queue: wgpu::Queue) -> Self {
+        Self {
+            device,
+            queue,
+            depth_mode: DepthMode::Depth,
+            color_space: ColorSpace::Srgb,
+        }
+    }
+
     pub fn device(&self) -> &wgpu::Device {
         &self.device
     }
@@ -22,6 +35,48 @@ impl GpuDevice {
     pub fn queue(&self) -> &wgpu::Queue {
         &self.queue
     }
+
+    /// Returns the [`DepthMode`] Pipelines are created with.
+    ///
+    pub fn depth_mode(&self) -> DepthMode {
+        self.depth_mode
+    }
+
+    /// Returns the [`ColorSpace`] Pipelines are created with.
+    ///
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Returns the pipeline overridable constants the Shaders are created with.
+    /// LINEAR_COLORS is set when the [`ColorSpace`] is Linear so the Shaders
+    /// skip converting the Colors from sRGB.
+    ///
+    pub fn pipeline_constants(&self) -> HashMap<String, f64> {
+        let linear = match self.color_space {
+            ColorSpace::Srgb => 0.0,
+            ColorSpace::Linear => 1.0,
+        };
+
+        HashMap::from([(String::from("LINEAR_COLORS"), linear)])
+    }
+
+    /// Returns the [`wgpu::DepthStencilState`] Pipelines are created with.
+    /// None when the [`DepthMode`] is PainterOnly so the Pipelines can be
+    /// used in Render Passes without a Depth Attachment.
+    ///
+    pub fn depth_stencil_state(&self) -> Option<wgpu::DepthStencilState> {
+        match self.depth_mode {
+            DepthMode::Depth => Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::LessEqual,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            DepthMode::PainterOnly => None,
+        }
+    }
 }
 
 /// Our own Adapter Power Settings.
@@ -68,6 +123,9 @@ pub struct GpuWindow {
     pub(crate) inner_size: PhysicalSize<u32>,
     /// Currently Accepted Surface configurations.
     pub(crate) surface_config: wgpu::SurfaceConfiguration,
+    /// Set when the Surface got reconfigured after being Lost or Outdated
+    /// so the [`GpuRenderer`] knows to recreate its Depth Buffer.
+    pub(crate) reconfigured: bool,
 }
 
 impl GpuWindow {
@@ -97,6 +155,20 @@ impl GpuWindow {
         Ok(())
     }
 
+    /// Reconfigures the [`wgpu::Surface`] to the Windows current size.
+    /// Used to recover once the Surface is Lost or Outdated.
+    ///
+    pub fn reconfigure(
+        &mut self,
+        gpu_device: &GpuDevice,
+    ) -> Result<(), GraphicsError> {
+        self.inner_size = self.window.inner_size();
+        self.resize(gpu_device, self.inner_size)?;
+        self.reconfigured = true;
+
+        Ok(())
+    }
+
     /// Returns the Size of the [`wgpu::Surface`].
     ///
     pub fn size(&self) -> PhysicalSize<f32> {
@@ -151,13 +223,10 @@ impl GpuWindow {
                         self.window.request_redraw();
                         return Ok(Some(frame));
                     }
-                    Err(wgpu::SurfaceError::Lost) => {
-                        let size = PhysicalSize::new(
-                            self.size.width as u32,
-                            self.size.height as u32,
-                        );
-                        self.resize(gpu_device, size)?;
-                        self.inner_size = self.window.inner_size();
+                    Err(
+                        wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated,
+                    ) => {
+                        self.reconfigure(gpu_device)?;
 
                         if self.size.width == 0.0
                             || self.size.height == 0.0
@@ -167,9 +236,8 @@ impl GpuWindow {
                             return Ok(None);
                         }
                     }
-                    Err(wgpu::SurfaceError::Outdated) => {
-                        return Ok(None);
-                    }
+                    // The frame was not ready in time so just try again next redraw.
+                    Err(wgpu::SurfaceError::Timeout) => {}
                     Err(e) => return Err(GraphicsError::from(e)),
                 }
 
@@ -305,8 +373,9 @@ impl AdapterExt for wgpu::Adapter {
                 size: PhysicalSize::new(size.width as f32, size.height as f32),
                 surface_config,
                 inner_size,
+                reconfigured: false,
             },
-            GpuDevice { device, queue },
+            GpuDevice::new(device, queue),
         );
 
         // Creates the shader rendering pipelines for each renderer.
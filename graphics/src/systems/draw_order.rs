@@ -11,12 +11,15 @@ pub type Index = AscendingKey;
 
 /// Draw Order in which Buffers are sorted by for optimal rendering.
 /// Positions are all calculated as (pos * 10000.0) as u32 to increase speed of sorting.
-/// Sort Order is order_layer -> alpha -> y reversed -> x -> z reversed.
+/// Sort Order is order_layer -> z_index -> alpha -> y reversed -> x -> z reversed.
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
 pub struct DrawOrder {
     /// Layer to sort the buffer by. This is not the same as buffer_layer.
     /// Sorted by lowest to highest. First to Sort by.
     pub order_layer: u32,
+    /// Explicit stacking index within the order_layer independent of the
+    /// Position. Sorted by lowest to highest. Sorted After order_layer.
+    pub z_index: i32,
     /// If the Buffer includes any Alpha Rendering.
     /// This placed the buffer first above none Alpha in the Order Layer.
     pub alpha: bool,
@@ -41,6 +44,7 @@ impl Ord for DrawOrder {
     fn cmp(&self, other: &Self) -> Ordering {
         self.order_layer
             .cmp(&other.order_layer)
+            .then(self.z_index.cmp(&other.z_index))
             .then(self.alpha.cmp(&other.alpha))
             .then(self.y.cmp(&other.y).reverse())
             .then(self.x.cmp(&other.x))
@@ -53,12 +57,19 @@ impl DrawOrder {
     pub fn new(alpha: bool, pos: &Vec3, order_layer: u32) -> Self {
         Self {
             order_layer,
+            z_index: 0,
             alpha,
             x: (pos.x * 10000.0) as u32,
             y: (pos.y * 10000.0) as u32,
             z: (pos.z * 10000.0) as u32,
         }
     }
+
+    /// Returns the DrawOrder with an explicit stacking z_index.
+    pub fn with_z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
 }
 
 /// OrderIndex Contains the information needed to Order the buffers and
@@ -130,4 +141,41 @@ impl OrderedIndex {
             camera_type,
         }
     }
+
+    /// Returns the [`DrawOrder`] the Buffer is sorted by.
+    ///
+    pub fn order(&self) -> DrawOrder {
+        self.order
+    }
+
+    /// Returns the [`Index`] of the Buffer within the [`crate::GpuRenderer`]'s
+    /// Buffer store.
+    ///
+    pub fn store_id(&self) -> Index {
+        self.index
+    }
+
+    /// Returns the Buffers indices count.
+    ///
+    pub fn index_count(&self) -> u32 {
+        self.index_count
+    }
+
+    /// Returns the Buffers indices max count.
+    ///
+    pub fn index_max(&self) -> u32 {
+        self.index_max
+    }
+
+    /// Returns the Buffers optional Bounds used for scissor clipping.
+    ///
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Returns the Buffers [`CameraType`].
+    ///
+    pub fn camera_type(&self) -> CameraType {
+        self.camera_type
+    }
 }
@@ -0,0 +1,176 @@
+use crate::{Camera, CameraId, GpuDevice, Layout, LayoutStorage, Mat4, Vec4};
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+/// Frame Layout send to the Rect Shaders for struct Frame.
+#[repr(C)]
+#[derive(Clone, Copy, Hash, Pod, Zeroable)]
+pub struct FrameLayout;
+
+impl Layout for FrameLayout {
+    fn create_layout(
+        &self,
+        gpu_device: &mut GpuDevice,
+    ) -> wgpu::BindGroupLayout {
+        gpu_device.device().create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("frame_bind_group_layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX
+                            | wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::VERTEX
+                            | wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            },
+        )
+    }
+}
+
+/// Per Frame Data that matches the Shaders struct Frame.
+///
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+pub struct FrameData {
+    /// Seconds set by [`crate::GpuRenderer::set_time`].
+    pub time: f32,
+    /// Seconds since the last [`crate::GpuRenderer::set_time`].
+    pub delta_time: f32,
+    /// Width and Height of the Surface.
+    pub resolution: [f32; 2],
+}
+
+/// Max amount of [`Camera`]'s that can be registered at once.
+/// Must match the array size of the shaders struct Cameras.
+pub const MAX_CAMERAS: usize = 16;
+
+/// Handles the Buffer and Bind Group for the shaders struct Frame and Cameras.
+///
+pub struct FrameUniform {
+    /// CPU side copy of the Frame Data.
+    pub data: FrameData,
+    /// Registered Cameras indexed by [`CameraId`].
+    pub(crate) cameras: Vec<Camera>,
+    /// Buffer to shader struct Frame.
+    buffer: wgpu::Buffer,
+    /// Buffer to shader struct Cameras.
+    camera_buffer: wgpu::Buffer,
+    /// Bind group for shader struct Frame.
+    bind_group: wgpu::BindGroup,
+}
+
+impl FrameUniform {
+    /// Creates a new [`FrameUniform`].
+    ///
+    pub fn new(
+        gpu_device: &mut GpuDevice,
+        layouts: &mut LayoutStorage,
+        resolution: [f32; 2],
+    ) -> Self {
+        let data = FrameData {
+            resolution,
+            ..Default::default()
+        };
+
+        let buffer = gpu_device.device().create_buffer_init(
+            &wgpu::util::BufferInitDescriptor {
+                label: Some("frame buffer"),
+                contents: bytemuck::bytes_of(&data),
+                usage: wgpu::BufferUsages::UNIFORM
+                    | wgpu::BufferUsages::COPY_DST,
+            },
+        );
+
+        let camera_buffer = gpu_device.device().create_buffer_init(
+            &wgpu::util::BufferInitDescriptor {
+                label: Some("cameras buffer"),
+                contents: &[
+                    bytemuck::cast_slice(&[Mat4::IDENTITY; MAX_CAMERAS]),
+                    bytemuck::cast_slice(&[Vec4::ZERO; MAX_CAMERAS]),
+                ]
+                .concat(),
+                usage: wgpu::BufferUsages::UNIFORM
+                    | wgpu::BufferUsages::COPY_DST,
+            },
+        );
+
+        let layout = layouts.create_layout(gpu_device, FrameLayout);
+        let bind_group =
+            gpu_device
+                .device()
+                .create_bind_group(&wgpu::BindGroupDescriptor {
+                    layout: &layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: buffer.as_entire_binding(),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: camera_buffer.as_entire_binding(),
+                        },
+                    ],
+                    label: Some("frame_bind_group"),
+                });
+
+        Self {
+            data,
+            cameras: Vec::with_capacity(MAX_CAMERAS),
+            buffer,
+            camera_buffer,
+            bind_group,
+        }
+    }
+
+    /// Uploads the [`Camera`] at [`CameraId`] to the GPU.
+    ///
+    pub fn upload_camera(&self, gpu_device: &GpuDevice, id: CameraId) {
+        if let Some(camera) = self.cameras.get(id.0) {
+            gpu_device.queue().write_buffer(
+                &self.camera_buffer,
+                (id.0 * std::mem::size_of::<Mat4>()) as u64,
+                bytemuck::bytes_of(&camera.view_projection()),
+            );
+            gpu_device.queue().write_buffer(
+                &self.camera_buffer,
+                (MAX_CAMERAS * std::mem::size_of::<Mat4>()
+                    + id.0 * std::mem::size_of::<Vec4>())
+                    as u64,
+                bytemuck::bytes_of(&camera.shader_position()),
+            );
+        }
+    }
+
+    /// Returns a reference too [`wgpu::BindGroup`].
+    ///
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+
+    /// Uploads the current [`FrameData`] to the GPU.
+    ///
+    pub fn upload(&self, gpu_device: &GpuDevice) {
+        gpu_device.queue().write_buffer(
+            &self.buffer,
+            0,
+            bytemuck::bytes_of(&self.data),
+        );
+    }
+}
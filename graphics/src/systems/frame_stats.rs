@@ -0,0 +1,62 @@
+use std::{cell::Cell, ops::Range};
+
+/// Counts of the work submitted by the crate's renderers within a frame.
+/// See [`crate::GpuRenderer::frame_stats`].
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FrameStats {
+    /// Amount of draw calls issued.
+    pub draw_calls: u32,
+    /// Amount of Instances drawn across all draw calls.
+    pub instances: u32,
+    /// Amount of Triangles drawn across all draw calls.
+    pub triangles: u32,
+    /// Amount of times a different Pipeline got bound.
+    pub bound_pipeline_switches: u32,
+}
+
+//Accumulates FrameStats through shared references as the renderers only
+//borrow the GpuRenderer while recording their passes.
+#[derive(Debug, Default)]
+pub(crate) struct FrameStatsRecorder {
+    current: Cell<FrameStats>,
+    last: Cell<FrameStats>,
+    //Address of the last bound Pipeline used to skip rebinding the same one.
+    pipeline: Cell<usize>,
+}
+
+impl FrameStatsRecorder {
+    pub(crate) fn record_pipeline(&self, pipeline: &wgpu::RenderPipeline) {
+        let address = pipeline as *const wgpu::RenderPipeline as usize;
+
+        if self.pipeline.replace(address) != address {
+            let mut stats = self.current.get();
+            stats.bound_pipeline_switches += 1;
+            self.current.set(stats);
+        }
+    }
+
+    pub(crate) fn record_draw(
+        &self,
+        indices: Range<u32>,
+        instances: Range<u32>,
+    ) {
+        let instance_count = instances.end.saturating_sub(instances.start);
+        let triangles = indices.end.saturating_sub(indices.start) / 3;
+        let mut stats = self.current.get();
+
+        stats.draw_calls += 1;
+        stats.instances += instance_count;
+        stats.triangles += triangles * instance_count;
+        self.current.set(stats);
+    }
+
+    pub(crate) fn finish(&self) {
+        self.last.set(self.current.take());
+        self.pipeline.set(0);
+    }
+
+    pub(crate) fn last(&self) -> FrameStats {
+        self.last.get()
+    }
+}
@@ -0,0 +1,185 @@
+use crate::GpuDevice;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+//Size of the two u64 timestamps written around a pass.
+const TIMESTAMP_BYTES: u64 = 2 * std::mem::size_of::<u64>() as u64;
+
+/// Where a [`GpuTimer`] is within reading its timestamps back.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+enum GpuTimerState {
+    /// Timestamps can be written to a new pass.
+    #[default]
+    Idle,
+    /// Timestamps were resolved and copied but not yet mapped.
+    Resolved,
+    /// Waiting on the readback buffer to be mapped.
+    Mapping,
+}
+
+/// Measures how long a [`wgpu::RenderPass`] took on the GPU using timestamp queries.
+/// Only created when the Device has [`wgpu::Features::TIMESTAMP_QUERY`] enabled.
+///
+pub struct GpuTimer {
+    /// Query Set holding the begin and end timestamps.
+    query_set: wgpu::QuerySet,
+    /// Buffer the Query Set is resolved into.
+    resolve_buffer: wgpu::Buffer,
+    /// Mappable Buffer the resolved timestamps are copied into.
+    readback_buffer: wgpu::Buffer,
+    /// Nanoseconds per timestamp tick.
+    period: f32,
+    /// Set once the readback buffer has been mapped.
+    mapped: Arc<AtomicBool>,
+    /// Where we are within reading the timestamps back.
+    state: GpuTimerState,
+    /// Duration of the last timed pass that was read back.
+    last: Option<Duration>,
+}
+
+impl GpuTimer {
+    /// Creates a [`GpuTimer`] if timestamp queries are supported by the Device.
+    ///
+    pub fn new(gpu_device: &GpuDevice) -> Option<Self> {
+        if !gpu_device
+            .device()
+            .features()
+            .contains(wgpu::Features::TIMESTAMP_QUERY)
+        {
+            return None;
+        }
+
+        let query_set =
+            gpu_device
+                .device()
+                .create_query_set(&wgpu::QuerySetDescriptor {
+                    label: Some("gpu timer query set"),
+                    ty: wgpu::QueryType::Timestamp,
+                    count: 2,
+                });
+
+        let resolve_buffer =
+            gpu_device.device().create_buffer(&wgpu::BufferDescriptor {
+                label: Some("gpu timer resolve buffer"),
+                size: TIMESTAMP_BYTES,
+                usage: wgpu::BufferUsages::QUERY_RESOLVE
+                    | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            });
+
+        let readback_buffer =
+            gpu_device.device().create_buffer(&wgpu::BufferDescriptor {
+                label: Some("gpu timer readback buffer"),
+                size: TIMESTAMP_BYTES,
+                usage: wgpu::BufferUsages::MAP_READ
+                    | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+
+        Some(Self {
+            query_set,
+            resolve_buffer,
+            readback_buffer,
+            period: gpu_device.queue().get_timestamp_period(),
+            mapped: Arc::new(AtomicBool::new(false)),
+            state: GpuTimerState::Idle,
+            last: None,
+        })
+    }
+
+    /// Returns the timestamp writes to add to the timed [`wgpu::RenderPassDescriptor`].
+    /// Returns None while the previous timing is still being read back.
+    ///
+    pub fn timestamp_writes(&self) -> Option<wgpu::RenderPassTimestampWrites> {
+        if self.state != GpuTimerState::Idle {
+            return None;
+        }
+
+        Some(wgpu::RenderPassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some(0),
+            end_of_pass_write_index: Some(1),
+        })
+    }
+
+    /// Resolves the timestamps into the readback buffer.
+    /// Must be called after the timed pass has ended and before submitting the encoder.
+    ///
+    pub fn resolve(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if self.state != GpuTimerState::Idle {
+            return;
+        }
+
+        encoder.resolve_query_set(
+            &self.query_set,
+            0..2,
+            &self.resolve_buffer,
+            0,
+        );
+        encoder.copy_buffer_to_buffer(
+            &self.resolve_buffer,
+            0,
+            &self.readback_buffer,
+            0,
+            TIMESTAMP_BYTES,
+        );
+
+        self.state = GpuTimerState::Resolved;
+    }
+
+    /// Starts mapping or reads back the resolved timestamps without blocking.
+    /// Must be called after the encoder was submitted.
+    ///
+    pub fn collect(&mut self, gpu_device: &GpuDevice) {
+        match self.state {
+            GpuTimerState::Idle => {}
+            GpuTimerState::Resolved => {
+                let mapped = self.mapped.clone();
+
+                self.readback_buffer.slice(..).map_async(
+                    wgpu::MapMode::Read,
+                    move |result| {
+                        if result.is_ok() {
+                            mapped.store(true, Ordering::Release);
+                        }
+                    },
+                );
+
+                self.state = GpuTimerState::Mapping;
+            }
+            GpuTimerState::Mapping => {
+                gpu_device.device().poll(wgpu::Maintain::Poll);
+
+                if !self.mapped.swap(false, Ordering::Acquire) {
+                    return;
+                }
+
+                {
+                    let data =
+                        self.readback_buffer.slice(..).get_mapped_range();
+                    let timestamps: &[u64] = bytemuck::cast_slice(&data);
+                    let ticks = timestamps[1].saturating_sub(timestamps[0]);
+
+                    self.last = Some(Duration::from_nanos(
+                        (ticks as f64 * self.period as f64) as u64,
+                    ));
+                }
+
+                self.readback_buffer.unmap();
+                self.state = GpuTimerState::Idle;
+            }
+        }
+    }
+
+    /// Returns the GPU Duration of the last timed pass that was read back.
+    ///
+    pub fn last_time(&self) -> Option<Duration> {
+        self.last
+    }
+}
@@ -57,6 +57,17 @@ impl PipelineStorage {
         );
     }
 
+    /// Replaces the [`wgpu::RenderPipeline`] stored for the [`PipeLineLayout`].
+    /// Used to swap in a recompiled pipeline without touching anything else.
+    ///
+    pub fn replace_pipeline<K: PipeLineLayout>(
+        &mut self,
+        pipeline: K,
+        render_pipeline: wgpu::RenderPipeline,
+    ) {
+        self.map.insert(pipeline.layout_key(), render_pipeline);
+    }
+
     /// Retrieves a Reference to a [`wgpu::RenderPipeline`] within the internal map for rendering.
     ///
     pub fn get_pipeline<K: PipeLineLayout>(
@@ -0,0 +1,171 @@
+use crate::{AHashMap, GraphicsError, OtherError};
+use std::ops::Range;
+
+/// Registry of named rendering layers mapped to their [`crate::DrawOrder`]
+/// order_layer. Lets each system declare its layers once by name instead of
+/// using raw numbers that could silently collide.
+///
+#[derive(Clone, Debug, Default)]
+pub struct RenderLayers {
+    /// Layer names to their order_layer.
+    layers: AHashMap<String, u32>,
+    /// Names to the order_layer ranges reserved with them.
+    reserved: AHashMap<String, Range<u32>>,
+    /// order_layer given to the next Layer registered without one.
+    next: u32,
+}
+
+impl RenderLayers {
+    /// Creates an empty [`RenderLayers`] registry.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a Layer by name and returns its order_layer.
+    /// New Layers are placed above every Layer registered before them.
+    /// Registering an existing name returns its already set order_layer.
+    ///
+    pub fn register_layer(&mut self, name: &str) -> u32 {
+        if let Some(layer) = self.layers.get(name) {
+            return *layer;
+        }
+
+        let layer = self.next;
+
+        self.layers.insert(name.to_owned(), layer);
+        self.next = layer.saturating_add(1);
+        layer
+    }
+
+    /// Registers a Layer by name at a set order_layer.
+    /// Errors if another name already uses that order_layer or the name
+    /// was already registered at a different one.
+    ///
+    pub fn register_layer_at(
+        &mut self,
+        name: &str,
+        layer: u32,
+    ) -> Result<u32, GraphicsError> {
+        if let Some(existing) = self.layers.get(name) {
+            if *existing == layer {
+                return Ok(layer);
+            }
+
+            return Err(GraphicsError::Other(OtherError::new(&format!(
+                "Render layer {name} is already registered at {existing}."
+            ))));
+        }
+
+        if let Some(other) = self.name(layer) {
+            return Err(GraphicsError::Other(OtherError::new(&format!(
+                "Render layer {layer} is already used by {other}."
+            ))));
+        }
+
+        if let Some(other) = self.reserved_by(layer) {
+            return Err(GraphicsError::Other(OtherError::new(&format!(
+                "Render layer {layer} is reserved by {other}."
+            ))));
+        }
+
+        self.layers.insert(name.to_owned(), layer);
+        self.next = self.next.max(layer.saturating_add(1));
+        Ok(layer)
+    }
+
+    /// Reserves count order_layers above every Layer in use under a name so
+    /// a plugin can place its own Layers without colliding with others.
+    /// Reserving an existing name returns its already reserved range.
+    /// Errors if the name was reserved with a different count or the
+    /// range would pass u32::MAX.
+    ///
+    pub fn reserve_range(
+        &mut self,
+        name: &str,
+        count: u32,
+    ) -> Result<Range<u32>, GraphicsError> {
+        if let Some(range) = self.reserved.get(name) {
+            if range.len() == count as usize {
+                return Ok(range.clone());
+            }
+
+            return Err(GraphicsError::Other(OtherError::new(&format!(
+                "Render layer range {name} is already reserved as {range:?}."
+            ))));
+        }
+
+        let end = self.next.checked_add(count).ok_or_else(|| {
+            OtherError::new(&format!(
+                "Render layer range {name} does not fit within u32."
+            ))
+        })?;
+        let range = self.next..end;
+
+        self.reserved.insert(name.to_owned(), range.clone());
+        self.next = end;
+        Ok(range)
+    }
+
+    /// Returns the order_layer range reserved under a name.
+    ///
+    pub fn reserved_range(&self, name: &str) -> Option<Range<u32>> {
+        self.reserved.get(name).cloned()
+    }
+
+    /// Returns the name of the range an order_layer is reserved within.
+    ///
+    pub fn reserved_by(&self, layer: u32) -> Option<&str> {
+        self.reserved
+            .iter()
+            .find(|(_, range)| range.contains(&layer))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the lowest order_layer in use and the one after the highest
+    /// by registered Layers and reserved ranges. The second value is the first
+    /// free order_layer above everything. Both are 0 when nothing is in use.
+    /// Any u32 is a valid order_layer.
+    ///
+    pub fn layer_range(&self) -> (u32, u32) {
+        let lowest = self
+            .layers
+            .values()
+            .copied()
+            .chain(self.reserved.values().map(|range| range.start))
+            .min();
+
+        match lowest {
+            Some(lowest) => (lowest, self.next),
+            None => (0, 0),
+        }
+    }
+
+    /// Returns the order_layer of a registered Layer.
+    ///
+    pub fn layer(&self, name: &str) -> Option<u32> {
+        self.layers.get(name).copied()
+    }
+
+    /// Returns the name registered at an order_layer.
+    ///
+    pub fn name(&self, layer: u32) -> Option<&str> {
+        self.layers
+            .iter()
+            .find(|(_, l)| **l == layer)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the registered Layers sorted from lowest to highest order_layer.
+    ///
+    pub fn layers(&self) -> Vec<(&str, u32)> {
+        let mut layers: Vec<(&str, u32)> = self
+            .layers
+            .iter()
+            .map(|(name, layer)| (name.as_str(), *layer))
+            .collect();
+
+        layers.sort_by_key(|(_, layer)| *layer);
+        layers
+    }
+}
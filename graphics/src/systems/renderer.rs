@@ -1,11 +1,21 @@
 use crate::{
-    BufferPass, BufferStore, GpuDevice, GpuWindow, GraphicsError, Index,
-    Layout, LayoutStorage, OtherError, PipeLineLayout, PipelineStorage,
-    StaticVertexBuffer,
+    AsBufferPass, AtlasSet, Bounds, BufferPass, BufferStore, Camera, CameraId,
+    ColorSpace, DepthMode, FrameStats, FrameStatsRecorder, FrameUniform,
+    GpuDevice, GpuSurface, GpuWindow, GraphicsError, Index, Layout,
+    LayoutStorage, OtherError, PipeLineLayout, PipelineStorage, Rect,
+    RectRenderer, RenderLayers, RenderRects, StaticVertexBuffer, SurfaceId,
+    System, Vec2, MAX_CAMERAS,
 };
-use cosmic_text::FontSystem;
+use cosmic_text::{Color, FontSystem};
+use slab::Slab;
 use slotmap::SlotMap;
-use std::rc::Rc;
+use std::{
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use winit::{dpi::PhysicalSize, event::WindowEvent, window::Window};
 
@@ -18,9 +28,23 @@ pub struct GpuRenderer {
     pub(crate) buffer_stores: SlotMap<Index, BufferStore>,
     pub(crate) layout_storage: LayoutStorage,
     pub(crate) pipeline_storage: PipelineStorage,
-    pub(crate) depthbuffer: wgpu::TextureView,
+    pub(crate) depthbuffer: Option<wgpu::TextureView>,
     pub(crate) framebuffer: Option<wgpu::TextureView>,
     pub(crate) frame: Option<wgpu::SurfaceTexture>,
+    pub(crate) frame_uniform: FrameUniform,
+    pub(crate) surfaces: Slab<GpuSurface>,
+    pub(crate) damage_tracking: bool,
+    pub(crate) damage: Option<Bounds>,
+    pub(crate) render_layers: RenderLayers,
+    pub(crate) clear_color: Option<Color>,
+    pub(crate) device_lost: Arc<AtomicBool>,
+    pub(crate) frame_stats: FrameStatsRecorder,
+    #[cfg(feature = "gpu-timing")]
+    pub(crate) gpu_timer: Option<crate::GpuTimer>,
+    #[cfg(feature = "debug-tools")]
+    pub(crate) rect_shader_path: Option<std::path::PathBuf>,
+    #[cfg(feature = "debug-tools")]
+    pub(crate) rect_shader_modified: Option<std::time::SystemTime>,
     pub font_sys: FontSystem,
     pub buffer_object: StaticVertexBuffer,
 }
@@ -51,19 +75,42 @@ where
 impl GpuRenderer {
     /// Creates a New GpuRenderer.
     ///
-    pub fn new(window: GpuWindow, device: GpuDevice) -> Self {
+    pub fn new(window: GpuWindow, mut device: GpuDevice) -> Self {
         let buffer_object = StaticVertexBuffer::create_buffer(&device);
-        let depth_buffer = window.create_depth_texture(&device);
+        let depth_buffer = Some(window.create_depth_texture(&device));
+        let mut layout_storage = LayoutStorage::new();
+        let frame_uniform = FrameUniform::new(
+            &mut device,
+            &mut layout_storage,
+            [window.size.width, window.size.height],
+        );
+        #[cfg(feature = "gpu-timing")]
+        let gpu_timer = crate::GpuTimer::new(&device);
+        let device_lost = watch_device_lost(&device);
 
         Self {
             window,
             device,
             buffer_stores: SlotMap::with_capacity_and_key(1024),
-            layout_storage: LayoutStorage::new(),
+            layout_storage,
             pipeline_storage: PipelineStorage::new(),
             depthbuffer: depth_buffer,
             framebuffer: None,
             frame: None,
+            frame_uniform,
+            surfaces: Slab::new(),
+            damage_tracking: false,
+            damage: None,
+            render_layers: RenderLayers::new(),
+            clear_color: Some(Color::rgba(0, 0, 0, 255)),
+            device_lost,
+            frame_stats: FrameStatsRecorder::default(),
+            #[cfg(feature = "gpu-timing")]
+            gpu_timer,
+            #[cfg(feature = "debug-tools")]
+            rect_shader_path: None,
+            #[cfg(feature = "debug-tools")]
+            rect_shader_modified: None,
             font_sys: FontSystem::new(),
             buffer_object,
         }
@@ -75,13 +122,52 @@ impl GpuRenderer {
         self.window.adapter()
     }
 
-    /// Resizes the Window.
+    /// Resizes the Window along with everything that depends on its size.
+    /// Reconfigures the Surface, recreates the Depth Buffer, updates the
+    /// resolution within the shaders struct Frame and resizes registered
+    /// [`Camera`]'s whose Projection covered the whole old Surface.
+    /// With damage tracking enabled the whole Window is damaged.
+    ///
+    /// [`crate::IsolationGroup`] targets follow the new size on their next
+    /// [`crate::RectRenderer::finalize`] and Scissors are computed from the
+    /// size every frame so neither needs anything more.
     ///
     pub fn resize(
         &mut self,
         size: PhysicalSize<u32>,
     ) -> Result<(), GraphicsError> {
-        self.window.resize(&self.device, size)
+        if size.width == 0 || size.height == 0 {
+            return Ok(());
+        }
+
+        let old_size = self.window.size;
+
+        self.window.resize(&self.device, size)?;
+        self.window.inner_size = self.window.window.inner_size();
+        self.update_depth_texture();
+
+        let new_size = self.window.size;
+
+        self.frame_uniform.data.resolution = [new_size.width, new_size.height];
+        self.frame_uniform.upload(&self.device);
+
+        for id in 0..self.frame_uniform.cameras.len() {
+            let camera = &mut self.frame_uniform.cameras[id];
+
+            if camera.left == 0.0
+                && camera.bottom == 0.0
+                && camera.right == old_size.width
+                && camera.top == old_size.height
+            {
+                camera.right = new_size.width;
+                camera.top = new_size.height;
+                self.frame_uniform.upload_camera(&self.device, CameraId(id));
+            }
+        }
+
+        self.add_damage(Bounds::new(0.0, 0.0, new_size.width, new_size.height));
+
+        Ok(())
     }
 
     /// Returns a reference to the Optional [`wgpu::TextureView`]: frame buffer.
@@ -90,10 +176,125 @@ impl GpuRenderer {
         &self.framebuffer
     }
 
-    /// Returns a reference to [`wgpu::TextureView`].
+    /// Sets the Time in seconds used by the shaders struct Frame.
+    /// Also updates the delta_time and resolution. Should be called each frame.
+    ///
+    pub fn set_time(&mut self, time: f32) {
+        let data = &mut self.frame_uniform.data;
+
+        data.delta_time = (time - data.time).max(0.0);
+        data.time = time;
+        data.resolution = [self.window.size.width, self.window.size.height];
+        self.frame_uniform.upload(&self.device);
+    }
+
+    /// Registers a [`Camera`] so Objects can render with it using its [`CameraId`].
+    /// Returns None if [`MAX_CAMERAS`] are already registered.
+    ///
+    pub fn add_camera(&mut self, camera: Camera) -> Option<CameraId> {
+        if self.frame_uniform.cameras.len() >= MAX_CAMERAS {
+            return None;
+        }
+
+        self.frame_uniform.cameras.push(camera);
+
+        let id = CameraId(self.frame_uniform.cameras.len() - 1);
+        self.frame_uniform.upload_camera(&self.device, id);
+        Some(id)
+    }
+
+    /// Updates a registered [`Camera`] using its [`CameraId`].
+    ///
+    pub fn set_camera(&mut self, id: CameraId, camera: Camera) {
+        if let Some(old) = self.frame_uniform.cameras.get_mut(id.0) {
+            *old = camera;
+            self.frame_uniform.upload_camera(&self.device, id);
+        }
+    }
+
+    /// Gets a optional reference to a registered [`Camera`] using its [`CameraId`].
+    ///
+    pub fn camera(&self, id: CameraId) -> Option<&Camera> {
+        self.frame_uniform.cameras.get(id.0)
+    }
+
+    /// Converts a Screen position into a World position using the [`Camera`] at [`CameraId`].
+    /// Screen positions start from the bottom left of the screen.
+    ///
+    pub fn screen_to_world(
+        &self,
+        id: CameraId,
+        screen_pos: Vec2,
+    ) -> Option<Vec2> {
+        let size = Vec2::new(self.window.size.width, self.window.size.height);
+
+        self.camera(id)
+            .map(|camera| camera.screen_to_world(screen_pos, size))
+    }
+
+    /// Returns a reference to the [`FrameUniform`] holding the shaders struct Frame.
+    ///
+    pub fn frame_uniform(&self) -> &FrameUniform {
+        &self.frame_uniform
+    }
+
+    /// Returns a reference to the Depth Buffers [`wgpu::TextureView`].
+    /// None if the [`DepthMode`] is PainterOnly in which case Render Passes
+    /// must not use a Depth Attachment.
     ///
-    pub fn depth_buffer(&self) -> &wgpu::TextureView {
-        &self.depthbuffer
+    pub fn depth_buffer(&self) -> Option<&wgpu::TextureView> {
+        self.depthbuffer.as_ref()
+    }
+
+    /// Returns the [`ColorSpace`] Colors are interpreted in.
+    ///
+    pub fn color_space(&self) -> ColorSpace {
+        self.device.color_space
+    }
+
+    /// Sets the [`ColorSpace`] every Color given to the renderers and the
+    /// clear Color are interpreted in. Defaults to sRGB.
+    ///
+    /// Recreates the built in Pipelines. Custom Pipelines and
+    /// [`crate::Material`]'s must be created again after changing it.
+    ///
+    pub fn set_color_space(&mut self, color_space: ColorSpace) -> &mut Self {
+        if color_space != self.device.color_space {
+            self.device.color_space = color_space;
+            self.pipeline_storage = PipelineStorage::new();
+            self.create_pipelines(self.surface_format());
+        }
+
+        self
+    }
+
+    /// Returns the [`DepthMode`] the Pipelines are created with.
+    ///
+    pub fn depth_mode(&self) -> DepthMode {
+        self.device.depth_mode
+    }
+
+    /// Sets if the Pipelines use a Depth Buffer or rely only on the sorted
+    /// [`crate::DrawOrder`] drawing later Objects over earlier ones.
+    /// PainterOnly drops the Depth Buffer so equal Z's can not z-fight.
+    ///
+    /// Recreates the built in Pipelines. Custom Pipelines and
+    /// [`crate::Material`]'s must be created again after changing it.
+    ///
+    pub fn set_depth_mode(&mut self, mode: DepthMode) -> &mut Self {
+        if mode != self.device.depth_mode {
+            self.device.depth_mode = mode;
+            self.update_depth_texture();
+
+            for (_, surface) in self.surfaces.iter_mut() {
+                surface.resize(&self.device, surface.window.inner_size());
+            }
+
+            self.pipeline_storage = PipelineStorage::new();
+            self.create_pipelines(self.surface_format());
+        }
+
+        self
     }
 
     /// Returns the windows [`PhysicalSize`].
@@ -128,7 +329,13 @@ impl GpuRenderer {
         &mut self,
         event: &WindowEvent,
     ) -> Result<bool, GraphicsError> {
-        let frame = match self.window.update(&self.device, event)? {
+        let frame = self.window.update(&self.device, event)?;
+
+        if std::mem::take(&mut self.window.reconfigured) {
+            self.update_depth_texture();
+        }
+
+        let frame = match frame {
             Some(frame) => frame,
             _ => return Ok(false),
         };
@@ -152,7 +359,12 @@ impl GpuRenderer {
     /// Updates the Internally Stored Depth Buffer.
     ///
     pub fn update_depth_texture(&mut self) {
-        self.depthbuffer = self.window.create_depth_texture(&self.device);
+        self.depthbuffer = match self.device.depth_mode {
+            DepthMode::Depth => {
+                Some(self.window.create_depth_texture(&self.device))
+            }
+            DepthMode::PainterOnly => None,
+        };
     }
 
     /// Presents the Current frame Buffer to the Window if Some().
@@ -160,6 +372,7 @@ impl GpuRenderer {
     ///
     pub fn present(&mut self) -> Result<(), GraphicsError> {
         self.framebuffer = None;
+        self.frame_stats.finish();
 
         match self.frame.take() {
             Some(frame) => {
@@ -172,6 +385,471 @@ impl GpuRenderer {
         }
     }
 
+    /// Updates, renders and presents the [`Rect`]'s in one call for simple
+    /// apps that render a single screen. Unchanged [`Rect`]'s are not rebuilt,
+    /// they are drawn sorted by their [`crate::DrawOrder`] and Clipping
+    /// [`Bounds`] are applied as Scissors. Call after [`GpuRenderer::update`]
+    /// returned true. Errors if there is no frame to render into.
+    ///
+    /// # Arguments
+    /// - rect_renderer: [`RectRenderer`] kept between frames to reuse its buffers.
+    /// - rects: [`Rect`]'s to render within buffer layer 0.
+    /// - atlas: [`AtlasSet`] the [`Rect`]'s render with.
+    /// - system: [`System`] holding the camera the [`Rect`]'s render with.
+    ///
+    pub fn draw_frame<Controls>(
+        &mut self,
+        rect_renderer: &mut RectRenderer,
+        rects: &mut [Rect],
+        atlas: &mut AtlasSet,
+        system: &System<Controls>,
+    ) -> Result<(), GraphicsError>
+    where
+        Controls: camera::controls::Controls,
+    {
+        if self.framebuffer.is_none() {
+            return Err(GraphicsError::Other(OtherError::new(
+                "Frame does not Exist. Did you forget to update the renderer?",
+            )));
+        }
+
+        if rects.iter().any(|rect| rect.bounds.is_some()) {
+            rect_renderer.use_clipping();
+        }
+
+        for rect in rects.iter_mut() {
+            rect_renderer.rect_update(rect, self, atlas, 0);
+        }
+
+        rect_renderer.finalize(self);
+
+        let mut encoder = self.device().create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("draw frame encoder"),
+            },
+        );
+
+        rect_renderer.render_isolation_groups(
+            self,
+            &mut encoder,
+            atlas,
+            system,
+        );
+
+        if let Some(view) = &self.framebuffer {
+            let mut pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("draw frame pass"),
+                    color_attachments: &[Some(
+                        wgpu::RenderPassColorAttachment {
+                            view,
+                            resolve_target: None,
+                            ops: self.color_operations(),
+                        },
+                    )],
+                    depth_stencil_attachment: self.depthbuffer.as_ref().map(
+                        |view| wgpu::RenderPassDepthStencilAttachment {
+                            view,
+                            depth_ops: Some(wgpu::Operations {
+                                load: wgpu::LoadOp::Clear(1.0),
+                                store: wgpu::StoreOp::Store,
+                            }),
+                            stencil_ops: None,
+                        },
+                    ),
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+
+            pass.set_buffers(self.buffer_object.as_buffer_pass());
+            pass.set_bind_group(0, system.bind_group(), &[]);
+            pass.render_rects(self, rect_renderer, atlas, system, 0);
+        }
+
+        self.queue().submit(std::iter::once(encoder.finish()));
+        self.present()
+    }
+
+    /// Returns true if the GPU was reset or its driver lost the [`wgpu::Device`].
+    /// Call [`GpuRenderer::handle_device_lost`] with a new [`GpuDevice`] to recover.
+    ///
+    pub fn is_device_lost(&self) -> bool {
+        self.device_lost.load(Ordering::Acquire)
+    }
+
+    /// Rebuilds the [`GpuRenderer`]'s GPU resources on a new [`GpuDevice`]
+    /// from their CPU side state after the old Device was lost.
+    /// The Surfaces, Depth Buffers, Frame uniform, Cameras and built in
+    /// Pipelines are recreated and every [`BufferStore`] uploads again.
+    ///
+    /// Renderers, [`crate::AtlasSet`]'s and [`crate::Material`]'s hold objects
+    /// of the old Device so they must be recreated and their Textures uploaded
+    /// again. The new Device can be requested from [`GpuRenderer::adapter`].
+    ///
+    pub fn handle_device_lost(
+        &mut self,
+        mut device: GpuDevice,
+    ) -> Result<(), GraphicsError> {
+        device.depth_mode = self.device.depth_mode;
+        device.color_space = self.device.color_space;
+        self.device_lost = watch_device_lost(&device);
+        self.device = device;
+        self.frame = None;
+        self.framebuffer = None;
+
+        self.window.reconfigure(&self.device)?;
+        self.window.reconfigured = false;
+        self.update_depth_texture();
+
+        for (_, surface) in self.surfaces.iter_mut() {
+            surface.frame = None;
+            surface.framebuffer = None;
+            surface.resize(&self.device, surface.window.inner_size());
+        }
+
+        self.buffer_object = StaticVertexBuffer::create_buffer(&self.device);
+        self.layout_storage = LayoutStorage::new();
+        self.pipeline_storage = PipelineStorage::new();
+
+        let mut frame_uniform = FrameUniform::new(
+            &mut self.device,
+            &mut self.layout_storage,
+            self.frame_uniform.data.resolution,
+        );
+
+        frame_uniform.data = self.frame_uniform.data;
+        frame_uniform.cameras = std::mem::take(&mut self.frame_uniform.cameras);
+        frame_uniform.upload(&self.device);
+
+        for id in 0..frame_uniform.cameras.len() {
+            frame_uniform.upload_camera(&self.device, CameraId(id));
+        }
+
+        self.frame_uniform = frame_uniform;
+
+        for store in self.buffer_stores.values_mut() {
+            store.changed = true;
+        }
+
+        #[cfg(feature = "gpu-timing")]
+        {
+            self.gpu_timer = crate::GpuTimer::new(&self.device);
+        }
+
+        self.create_pipelines(self.surface_format());
+        Ok(())
+    }
+
+    /// Adds a extra Window to render to sharing this [`GpuRenderer`]'s Device,
+    /// Buffers and Pipelines. The Window must support the main Windows surface format.
+    ///
+    pub fn add_surface(
+        &mut self,
+        instance: &wgpu::Instance,
+        window: Arc<Window>,
+    ) -> Result<SurfaceId, GraphicsError> {
+        let surface = GpuSurface::new(
+            instance,
+            &self.window.adapter,
+            &self.device,
+            window,
+            self.window.surface_format,
+            self.window.surface_config.present_mode,
+        )?;
+
+        Ok(SurfaceId(self.surfaces.insert(surface)))
+    }
+
+    /// Removes a extra Window's [`GpuSurface`] using its [`SurfaceId`].
+    ///
+    pub fn remove_surface(&mut self, id: SurfaceId) -> Option<GpuSurface> {
+        self.surfaces.try_remove(id.0)
+    }
+
+    /// Gets a optional reference to a extra Window's [`GpuSurface`].
+    ///
+    pub fn surface_by_id(&self, id: SurfaceId) -> Option<&GpuSurface> {
+        self.surfaces.get(id.0)
+    }
+
+    /// Updates a extra Window's [`GpuSurface`] with the Windows event.
+    /// Returns true if its Frame Buffer is ready to be rendered too.
+    ///
+    pub fn update_surface(
+        &mut self,
+        id: SurfaceId,
+        event: &WindowEvent,
+    ) -> Result<bool, GraphicsError> {
+        match self.surfaces.get_mut(id.0) {
+            Some(surface) => surface.update(&self.device, event),
+            None => Ok(false),
+        }
+    }
+
+    /// Presents a extra Window's Current Frame Buffer.
+    ///
+    pub fn present_surface(
+        &mut self,
+        id: SurfaceId,
+    ) -> Result<(), GraphicsError> {
+        match self.surfaces.get_mut(id.0) {
+            Some(surface) => surface.present(),
+            None => Err(GraphicsError::Other(OtherError::new(
+                "Surface does not Exist.",
+            ))),
+        }
+    }
+
+    /// Returns the timestamp writes to set on the rect pass's [`wgpu::RenderPassDescriptor`].
+    /// Returns None if timestamp queries are unsupported or the last timing is still being read.
+    ///
+    #[cfg(feature = "gpu-timing")]
+    pub fn gpu_timestamp_writes(
+        &self,
+    ) -> Option<wgpu::RenderPassTimestampWrites> {
+        self.gpu_timer.as_ref()?.timestamp_writes()
+    }
+
+    /// Resolves the rect pass timestamps. Call after the pass ends and before submitting.
+    ///
+    #[cfg(feature = "gpu-timing")]
+    pub fn resolve_gpu_time(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if let Some(timer) = &mut self.gpu_timer {
+            timer.resolve(encoder);
+        }
+    }
+
+    /// Reads back the rect pass timestamps without blocking. Call after submitting.
+    ///
+    #[cfg(feature = "gpu-timing")]
+    pub fn collect_gpu_time(&mut self) {
+        if let Some(timer) = &mut self.gpu_timer {
+            timer.collect(&self.device);
+        }
+    }
+
+    /// Returns how long the last timed rect pass took on the GPU.
+    /// Returns None if timestamp queries are unsupported or nothing was read back yet.
+    ///
+    #[cfg(feature = "gpu-timing")]
+    pub fn last_frame_gpu_time(&self) -> Option<std::time::Duration> {
+        self.gpu_timer.as_ref()?.last_time()
+    }
+
+    /// Returns the [`FrameStats`] of the last frame presented with
+    /// [`GpuRenderer::present`]. Counts the draws recorded by the crate's
+    /// renderers, so draws made directly on a pass are not included.
+    ///
+    pub fn frame_stats(&self) -> FrameStats {
+        self.frame_stats.last()
+    }
+
+    /// Records a Pipeline being bound within [`FrameStats`].
+    ///
+    pub(crate) fn record_pipeline(&self, pipeline: &wgpu::RenderPipeline) {
+        self.frame_stats.record_pipeline(pipeline);
+    }
+
+    /// Records a draw call within [`FrameStats`].
+    ///
+    pub(crate) fn record_draw(
+        &self,
+        indices: std::ops::Range<u32>,
+        instances: std::ops::Range<u32>,
+    ) {
+        self.frame_stats.record_draw(indices, instances);
+    }
+
+    /// Enables or Disables damage tracking. While enabled only the union of the
+    /// areas changed Objects reported is meant to be redrawn. The pass must render
+    /// into a retained target as the Surface does not keep the last frame.
+    ///
+    pub fn set_damage_tracking(&mut self, enabled: bool) -> &mut Self {
+        self.damage_tracking = enabled;
+        self.damage = None;
+        self
+    }
+
+    /// Returns if damage tracking is enabled.
+    ///
+    pub fn damage_tracking(&self) -> bool {
+        self.damage_tracking
+    }
+
+    /// Adds an area that changed this frame to the damage.
+    /// Does nothing while damage tracking is disabled.
+    ///
+    pub fn add_damage(&mut self, bounds: Bounds) {
+        if self.damage_tracking {
+            self.damage = Some(match self.damage {
+                Some(damage) => damage.union(&bounds),
+                None => bounds,
+            });
+        }
+    }
+
+    /// Returns the area that needs to be redrawn. Returns the whole Window
+    /// while damage tracking is disabled and None when nothing changed.
+    ///
+    pub fn damage(&self) -> Option<Bounds> {
+        if self.damage_tracking {
+            self.damage
+        } else {
+            Some(Bounds::new(0.0, 0.0, self.size().width, self.size().height))
+        }
+    }
+
+    /// Returns the damage as a Scissor X, Y, Width and Height from the top left
+    /// of the Window to set with [`wgpu::RenderPass::set_scissor_rect`].
+    /// Returns None when nothing changed so the pass can be skipped.
+    ///
+    pub fn damage_scissor(&self) -> Option<(u32, u32, u32, u32)> {
+        let damage = self.damage()?;
+        let size = self.size();
+        let left = damage.left.floor().clamp(0.0, size.width);
+        let right = damage.right.ceil().clamp(0.0, size.width);
+        let bottom = damage.bottom.floor().clamp(0.0, size.height);
+        let top = damage.top.ceil().clamp(0.0, size.height);
+
+        if right <= left || top <= bottom {
+            return None;
+        }
+
+        Some((
+            left as u32,
+            (size.height - top) as u32,
+            (right - left) as u32,
+            (top - bottom) as u32,
+        ))
+    }
+
+    /// Clears the damage. Call once the frame has been rendered.
+    ///
+    pub fn clear_damage(&mut self) {
+        self.damage = None;
+    }
+
+    /// Returns true if any [`Rect`] given still needs its buffer rebuilt or
+    /// damage was reported since [`GpuRenderer::clear_damage`]. When false
+    /// nothing changed so the frame can be skipped and the GPU left idle.
+    /// Call before [`Rect::update`] as updating clears the changed flags,
+    /// or enable damage tracking to also catch the changes after it.
+    ///
+    pub fn any_dirty(&self, rects: &[&Rect]) -> bool {
+        (self.damage_tracking && self.damage.is_some())
+            || rects.iter().any(|rect| rect.changed)
+    }
+
+    /// Sets the Color the frame is cleared to when a pass begins using
+    /// [`GpuRenderer::color_load_op`]. None keeps the previous contents so
+    /// rendering can overlay a scene already drawn into the target.
+    ///
+    pub fn set_clear(&mut self, clear_color: Option<Color>) -> &mut Self {
+        self.clear_color = clear_color;
+        self
+    }
+
+    /// Returns the Color the frame is cleared to or None if it is loaded.
+    ///
+    pub fn clear(&self) -> Option<Color> {
+        self.clear_color
+    }
+
+    /// Returns the [`wgpu::LoadOp`] to begin the frames color attachment with.
+    ///
+    pub fn color_load_op(&self) -> wgpu::LoadOp<wgpu::Color> {
+        match self.clear_color {
+            Some(color) => {
+                //The clear value is linear like the shaders output.
+                let srgb = self.device.color_space == ColorSpace::Srgb;
+                let linear = |c: u8| {
+                    let c = c as f64 / 255.0;
+
+                    if !srgb {
+                        c
+                    } else if c <= 0.04045 {
+                        c / 12.92
+                    } else {
+                        ((c + 0.055) / 1.055).powf(2.4)
+                    }
+                };
+
+                wgpu::LoadOp::Clear(wgpu::Color {
+                    r: linear(color.r()),
+                    g: linear(color.g()),
+                    b: linear(color.b()),
+                    a: color.a() as f64 / 255.0,
+                })
+            }
+            None => wgpu::LoadOp::Load,
+        }
+    }
+
+    /// Returns the [`wgpu::Operations`] to use for the frames color attachment.
+    ///
+    pub fn color_operations(&self) -> wgpu::Operations<wgpu::Color> {
+        wgpu::Operations {
+            load: self.color_load_op(),
+            store: wgpu::StoreOp::Store,
+        }
+    }
+
+    /// Loads a Font into the [`FontSystem`] so [`crate::Text`] can fall back to it
+    /// for characters the requested Font does not contain. Fallback Fonts are
+    /// tried after the platforms own fallback list.
+    ///
+    pub fn load_fallback_font(&mut self, data: Vec<u8>) {
+        self.font_sys.db_mut().load_font_data(data);
+    }
+
+    /// Registers a named rendering layer and returns its order_layer.
+    /// See [`RenderLayers::register_layer`].
+    ///
+    pub fn register_layer(&mut self, name: &str) -> u32 {
+        self.render_layers.register_layer(name)
+    }
+
+    /// Registers a named rendering layer at a set order_layer.
+    /// See [`RenderLayers::register_layer_at`].
+    ///
+    pub fn register_layer_at(
+        &mut self,
+        name: &str,
+        layer: u32,
+    ) -> Result<u32, GraphicsError> {
+        self.render_layers.register_layer_at(name, layer)
+    }
+
+    /// Reserves count order_layers above every rendering layer in use.
+    /// See [`RenderLayers::reserve_range`].
+    ///
+    pub fn reserve_layer_range(
+        &mut self,
+        name: &str,
+        count: u32,
+    ) -> Result<std::ops::Range<u32>, GraphicsError> {
+        self.render_layers.reserve_range(name, count)
+    }
+
+    /// Returns the lowest order_layer in use and the first free one above
+    /// every rendering layer. See [`RenderLayers::layer_range`].
+    ///
+    pub fn layer_range(&self) -> (u32, u32) {
+        self.render_layers.layer_range()
+    }
+
+    /// Returns the order_layer of a registered rendering layer.
+    ///
+    pub fn render_layer(&self, name: &str) -> Option<u32> {
+        self.render_layers.layer(name)
+    }
+
+    /// Returns a reference to the [`RenderLayers`] registry.
+    ///
+    pub fn render_layers(&self) -> &RenderLayers {
+        &self.render_layers
+    }
+
     /// Returns a reference to [`wgpu::Device`].
     ///
     pub fn device(&self) -> &wgpu::Device {
@@ -184,6 +862,33 @@ impl GpuRenderer {
         &self.device
     }
 
+    /// Returns the [`wgpu::Limits`] the Device was created with.
+    ///
+    pub fn device_limits(&self) -> wgpu::Limits {
+        self.device.device().limits()
+    }
+
+    /// Returns the largest Width or Height a 2D Texture can be on this Device.
+    ///
+    pub fn max_texture_dimension(&self) -> u32 {
+        self.device.device().limits().max_texture_dimension_2d
+    }
+
+    /// Returns the most Layers a Texture Array can have on this Device.
+    ///
+    pub fn max_texture_array_layers(&self) -> u32 {
+        self.device.device().limits().max_texture_array_layers
+    }
+
+    /// Returns if the Adapter supports anisotropic filtering for Samplers.
+    ///
+    pub fn supports_anisotropy(&self) -> bool {
+        self.adapter()
+            .get_downlevel_capabilities()
+            .flags
+            .contains(wgpu::DownlevelFlags::ANISOTROPIC_FILTERING)
+    }
+
     /// Returns a reference to [`wgpu::Queue`].
     ///
     pub fn queue(&self) -> &wgpu::Queue {
@@ -214,6 +919,24 @@ impl GpuRenderer {
             .insert(BufferStore::new(store_size, index_size))
     }
 
+    /// Returns the Byte Alignment the Backend requires for dynamic buffer offsets.
+    /// Instances packed at this stride can be bound with dynamic offsets.
+    ///
+    pub fn instance_alignment(&self) -> usize {
+        let limits = self.device_limits();
+
+        limits
+            .min_uniform_buffer_offset_alignment
+            .max(limits.min_storage_buffer_offset_alignment) as usize
+    }
+
+    /// Returns the size rounded up to [`GpuRenderer::instance_alignment`].
+    /// Use to size a batch whose Instances get bound with dynamic offsets.
+    ///
+    pub fn aligned_size(&self, size: usize) -> usize {
+        size.div_ceil(self.instance_alignment()) * self.instance_alignment()
+    }
+
     /// Creates a New [`BufferStore`] with default sizes for Rendering Object Data Storage and
     /// Returns its [`Index`] for Referencing it.
     ///
@@ -239,6 +962,15 @@ impl GpuRenderer {
         self.buffer_stores.get_mut(index)
     }
 
+    /// Returns the CPU side bytes of a [`BufferStore`] using its [`Index`].
+    /// Lets the written Vertex data be cast and checked without a GPU readback.
+    ///
+    pub fn peek_buffer(&self, index: Index) -> Option<&[u8]> {
+        self.buffer_stores
+            .get(index)
+            .map(|store| store.store.as_slice())
+    }
+
     /// Creates new BindGroupLayout from Generic K and Returns a Reference Counter to them.
     ///
     pub fn create_layout<K: Layout>(
@@ -292,6 +1024,118 @@ impl GpuRenderer {
             surface_format,
             crate::RectRenderPipeline,
         );
+
+        self.pipeline_storage.create_pipeline(
+            &mut self.device,
+            &mut self.layout_storage,
+            surface_format,
+            crate::RectIsolationPipeline,
+        );
+
+        self.pipeline_storage.create_pipeline(
+            &mut self.device,
+            &mut self.layout_storage,
+            surface_format,
+            crate::RectCompositePipeline,
+        );
+
+        self.pipeline_storage.create_pipeline(
+            &mut self.device,
+            &mut self.layout_storage,
+            surface_format,
+            crate::RectGlowPipeline,
+        );
+
+        for op in crate::CompositeOp::BLENDED {
+            self.pipeline_storage.create_pipeline(
+                &mut self.device,
+                &mut self.layout_storage,
+                surface_format,
+                crate::RectBlendPipeline::new(op),
+            );
+        }
+    }
+
+    /// Sets the WGSL file on disk the Rect shader is reloaded from by
+    /// [`GpuRenderer::reload_shaders`].
+    ///
+    #[cfg(feature = "debug-tools")]
+    pub fn set_rect_shader_path(
+        &mut self,
+        path: impl Into<std::path::PathBuf>,
+    ) -> &mut Self {
+        self.rect_shader_path = Some(path.into());
+        self.rect_shader_modified = None;
+        self
+    }
+
+    /// Recompiles the Rect pipeline from the file set with [`GpuRenderer::set_rect_shader_path`].
+    /// Buffers and Atlases are kept. If the shader fails to compile the Error is
+    /// returned and the old pipeline is kept.
+    ///
+    #[cfg(feature = "debug-tools")]
+    pub fn reload_shaders(&mut self) -> Result<(), GraphicsError> {
+        let path = self.rect_shader_path.clone().ok_or_else(|| {
+            OtherError::new("No rect shader path was set to reload from.")
+        })?;
+
+        self.rect_shader_modified =
+            std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+
+        let source = std::fs::read_to_string(&path)?;
+
+        crate::validate_wgsl(&source, self)?;
+
+        let surface_format = self.surface_format();
+        let pipeline = crate::create_rect_pipeline_from_source(
+            &mut self.device,
+            &mut self.layout_storage,
+            surface_format,
+            &source,
+        );
+
+        self.pipeline_storage
+            .replace_pipeline(crate::RectRenderPipeline, pipeline);
+        Ok(())
+    }
+
+    /// Watches the Rect shader file and reloads it if it was modified since the last reload.
+    /// Returns true if the shader got reloaded.
+    ///
+    #[cfg(feature = "debug-tools")]
+    pub fn reload_shaders_if_changed(&mut self) -> Result<bool, GraphicsError> {
+        let modified = match &self.rect_shader_path {
+            Some(path) => std::fs::metadata(path)?.modified()?,
+            None => return Ok(false),
+        };
+
+        if self.rect_shader_modified == Some(modified) {
+            return Ok(false);
+        }
+
+        self.reload_shaders()?;
+        Ok(true)
+    }
+
+    /// Creates the built in pipelines for the Rect, Text and each
+    /// [`crate::CompositeOp`] if they do not exist yet, then waits for the
+    /// Device to finish its queued work. Call it during a loading screen so
+    /// no compiling lands on the first real frame.
+    /// Custom [`crate::Material`] pipelines compile when they are added.
+    ///
+    pub fn precompile_pipelines(&mut self) {
+        let missing = self.get_pipelines(crate::RectRenderPipeline).is_none()
+            || self.get_pipelines(crate::TextRenderPipeline).is_none()
+            || crate::CompositeOp::BLENDED.iter().any(|op| {
+                self.get_pipelines(crate::RectBlendPipeline::new(*op))
+                    .is_none()
+            });
+
+        if missing {
+            self.create_pipelines(self.surface_format());
+        }
+
+        self.device.device().poll(wgpu::Maintain::Wait);
     }
 
     /// Gets a optional reference of [`wgpu::RenderPipeline`]
@@ -303,3 +1147,25 @@ impl GpuRenderer {
         self.pipeline_storage.get_pipeline(pipeline)
     }
 }
+
+//Flags the returned bool once the Device is lost. Dropping or replacing
+//the callback does not count as being lost.
+fn watch_device_lost(device: &GpuDevice) -> Arc<AtomicBool> {
+    let lost = Arc::new(AtomicBool::new(false));
+    let flag = lost.clone();
+
+    device
+        .device()
+        .set_device_lost_callback(move |reason, message| {
+            if !matches!(
+                reason,
+                wgpu::DeviceLostReason::Dropped
+                    | wgpu::DeviceLostReason::ReplacedCallback
+            ) {
+                log::error!("GPU Device lost: {message}");
+                flag.store(true, Ordering::Release);
+            }
+        });
+
+    lost
+}
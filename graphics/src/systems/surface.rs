@@ -0,0 +1,215 @@
+use crate::{DepthMode, GpuDevice, GraphicsError, OtherError};
+use std::sync::Arc;
+use winit::{dpi::PhysicalSize, event::WindowEvent, window::Window};
+
+/// Id of a extra [`GpuSurface`] added with [`crate::GpuRenderer::add_surface`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SurfaceId(pub usize);
+
+/// Extra Window Surface rendered to with the same [`GpuDevice`] as the main Window.
+/// Buffers, Atlases, Layouts and Pipelines are shared so only the Surface,
+/// Depth Buffer and Frame Buffer are kept per Window.
+///
+pub struct GpuSurface {
+    /// Window Surface we will Render Too.
+    pub(crate) surface: wgpu::Surface<'static>,
+    /// Window we are using to Render Too.
+    pub(crate) window: Arc<Window>,
+    /// Windows Overall Size.
+    pub(crate) size: PhysicalSize<f32>,
+    /// Currently Accepted Surface configurations.
+    pub(crate) surface_config: wgpu::SurfaceConfiguration,
+    /// Depth Buffer sized to this Surface. None if the [`DepthMode`] is PainterOnly.
+    pub(crate) depthbuffer: Option<wgpu::TextureView>,
+    /// Current Frame Buffer if one was acquired.
+    pub(crate) framebuffer: Option<wgpu::TextureView>,
+    /// Current Frame if one was acquired.
+    pub(crate) frame: Option<wgpu::SurfaceTexture>,
+}
+
+impl GpuSurface {
+    /// Creates a [`GpuSurface`] for the [`Window`] using the main Windows
+    /// [`wgpu::TextureFormat`] so the shared Pipelines can render to it.
+    ///
+    pub(crate) fn new(
+        instance: &wgpu::Instance,
+        adapter: &wgpu::Adapter,
+        gpu_device: &GpuDevice,
+        window: Arc<Window>,
+        format: wgpu::TextureFormat,
+        present_mode: wgpu::PresentMode,
+    ) -> Result<Self, GraphicsError> {
+        let surface =
+            instance.create_surface(window.clone()).map_err(|_| {
+                OtherError::new("failed to create a surface for the window")
+            })?;
+
+        if !surface.get_capabilities(adapter).formats.contains(&format) {
+            return Err(GraphicsError::Other(OtherError::new(
+                "window surface does not support the renderers surface format",
+            )));
+        }
+
+        let size = window.inner_size();
+        let surface_config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format,
+            width: size.width.max(1),
+            height: size.height.max(1),
+            present_mode,
+            alpha_mode: wgpu::CompositeAlphaMode::Auto,
+            view_formats: vec![format],
+            desired_maximum_frame_latency: 2,
+        };
+
+        surface.configure(gpu_device.device(), &surface_config);
+
+        let size = PhysicalSize::new(
+            surface_config.width as f32,
+            surface_config.height as f32,
+        );
+
+        Ok(Self {
+            depthbuffer: Self::create_depth_texture(gpu_device, size),
+            surface,
+            window,
+            size,
+            surface_config,
+            framebuffer: None,
+            frame: None,
+        })
+    }
+
+    /// Resizes the [`wgpu::Surface`] and its Depth Buffer.
+    ///
+    pub fn resize(&mut self, gpu_device: &GpuDevice, size: PhysicalSize<u32>) {
+        if size.width == 0 || size.height == 0 {
+            return;
+        }
+
+        self.surface_config.width = size.width;
+        self.surface_config.height = size.height;
+        self.surface
+            .configure(gpu_device.device(), &self.surface_config);
+        self.size = PhysicalSize::new(size.width as f32, size.height as f32);
+        self.depthbuffer = Self::create_depth_texture(gpu_device, self.size);
+    }
+
+    /// Resizes on a Resized event and acquires a Frame Buffer on a RedrawRequested event.
+    /// Returns true if a Frame Buffer is ready to render too.
+    ///
+    pub fn update(
+        &mut self,
+        gpu_device: &GpuDevice,
+        event: &WindowEvent,
+    ) -> Result<bool, GraphicsError> {
+        match event {
+            WindowEvent::Resized(size) => {
+                self.resize(gpu_device, *size);
+                self.window.request_redraw();
+            }
+            WindowEvent::RedrawRequested => {
+                if self.size.width == 0.0 || self.size.height == 0.0 {
+                    return Ok(false);
+                }
+
+                match self.surface.get_current_texture() {
+                    Ok(frame) => {
+                        self.framebuffer = Some(frame.texture.create_view(
+                            &wgpu::TextureViewDescriptor::default(),
+                        ));
+                        self.frame = Some(frame);
+                        return Ok(true);
+                    }
+                    Err(
+                        wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated,
+                    ) => {
+                        self.resize(gpu_device, self.window.inner_size());
+                        self.window.request_redraw();
+                    }
+                    Err(wgpu::SurfaceError::Timeout) => {
+                        self.window.request_redraw();
+                    }
+                    Err(e) => return Err(GraphicsError::from(e)),
+                }
+            }
+            _ => (),
+        }
+
+        Ok(false)
+    }
+
+    /// Presents the Current Frame Buffer to the Window.
+    /// If the Frame Buffer does not Exist will return a Error.
+    ///
+    pub fn present(&mut self) -> Result<(), GraphicsError> {
+        self.framebuffer = None;
+
+        match self.frame.take() {
+            Some(frame) => {
+                frame.present();
+                Ok(())
+            }
+            None => Err(GraphicsError::Other(OtherError::new(
+                "Frame does not Exist. Did you forget to update the surface?",
+            ))),
+        }
+    }
+
+    /// Returns a reference to the Optional Frame Buffer.
+    ///
+    pub fn frame_buffer(&self) -> &Option<wgpu::TextureView> {
+        &self.framebuffer
+    }
+
+    /// Returns a reference to the Depth Buffer.
+    /// None if the [`DepthMode`] is PainterOnly.
+    ///
+    pub fn depth_buffer(&self) -> Option<&wgpu::TextureView> {
+        self.depthbuffer.as_ref()
+    }
+
+    /// Returns the Size of the [`wgpu::Surface`].
+    ///
+    pub fn size(&self) -> PhysicalSize<f32> {
+        self.size
+    }
+
+    /// Returns a Reference to [`Window`].
+    ///
+    pub fn window(&self) -> &Window {
+        &self.window
+    }
+
+    //Creates a Depth Texture the size of the Surface if the DepthMode uses one.
+    fn create_depth_texture(
+        gpu_device: &GpuDevice,
+        size: PhysicalSize<f32>,
+    ) -> Option<wgpu::TextureView> {
+        if gpu_device.depth_mode() == DepthMode::PainterOnly {
+            return None;
+        }
+
+        let texture =
+            gpu_device
+                .device()
+                .create_texture(&wgpu::TextureDescriptor {
+                    label: Some("surface depth texture"),
+                    size: wgpu::Extent3d {
+                        width: size.width as u32,
+                        height: size.height as u32,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: wgpu::TextureFormat::Depth32Float,
+                    usage: wgpu::TextureUsages::TEXTURE_BINDING
+                        | wgpu::TextureUsages::RENDER_ATTACHMENT,
+                    view_formats: &[wgpu::TextureFormat::Depth32Float],
+                });
+
+        Some(texture.create_view(&wgpu::TextureViewDescriptor::default()))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Bounds, CameraType, GpuDevice, GpuRenderer, Layout};
+use crate::{Bounds, Camera, CameraType, GpuDevice, GpuRenderer, Layout};
 use bytemuck::{Pod, Zeroable};
 use camera::Projection;
 use glam::{Mat4, Vec2, Vec3, Vec4};
@@ -179,6 +179,14 @@ where
         self.manual_changed = true;
     }
 
+    /// Sets Manual View and Scale from a [`Camera`].
+    /// Objects using [`CameraType::ManualView`] or [`CameraType::ManualViewWithScale`]
+    /// will then render using the [`Camera`]'s position and zoom.
+    ///
+    pub fn set_manual_camera(&mut self, camera: &Camera) {
+        self.set_manual_view(camera.view(), camera.scale());
+    }
+
     /// Returns Manual views Matrix 4x4.
     ///
     pub fn manual_view(&self) -> Mat4 {
@@ -0,0 +1,230 @@
+use crate::{Bounds, Mat4, Vec2, Vec3, Vec4};
+use camera::Projection;
+
+/// Id of a [`Camera`] registered with [`crate::GpuRenderer::add_camera`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CameraId(pub usize);
+
+impl CameraId {
+    /// Returns the Camera Type numberical the shaders use for this [`CameraId`].
+    /// Registered cameras start after the last [`crate::CameraType`].
+    ///
+    pub fn shader_id(&self) -> u32 {
+        5 + self.0 as u32
+    }
+}
+
+/// Direction the Y Axis of a [`Camera`] increases in.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum YAxis {
+    /// Y increases going up the screen. Used by the World.
+    #[default]
+    Up,
+    /// Y increases going down the screen. Used by most UI layouts.
+    Down,
+}
+
+/// Orthographic Camera builder used to create the View and Projection
+/// a [`crate::CameraType`] renders with.
+///
+/// Use [`crate::System::set_manual_camera`] to apply it to
+/// [`crate::CameraType::ManualView`] and [`crate::CameraType::ManualViewWithScale`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Camera {
+    /// Left side of the Orthographic Projection.
+    pub left: f32,
+    /// Right side of the Orthographic Projection.
+    pub right: f32,
+    /// Bottom side of the Orthographic Projection.
+    pub bottom: f32,
+    /// Top side of the Orthographic Projection.
+    pub top: f32,
+    /// Near plane of the Orthographic Projection.
+    pub near: f32,
+    /// Far plane of the Orthographic Projection.
+    pub far: f32,
+    /// World Position the Camera Looks at from the bottom left.
+    pub position: Vec3,
+    /// Zoom used as the Scale. 1.0 is no zoom.
+    pub zoom: f32,
+    /// Direction the Y Axis increases in.
+    pub y_axis: YAxis,
+}
+
+impl Camera {
+    /// Creates a new Orthographic [`Camera`] at position zero and no zoom.
+    ///
+    pub fn orthographic(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        Self {
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+            position: Vec3::ZERO,
+            zoom: 1.0,
+            y_axis: YAxis::Up,
+        }
+    }
+
+    /// Sets the [`Camera`]'s World Position.
+    ///
+    pub fn with_position(mut self, position: Vec3) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the [`Camera`]'s Zoom.
+    ///
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Sets the [`Camera`]'s [`YAxis`].
+    ///
+    pub fn with_y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// Sets the [`Camera`]'s World Position.
+    ///
+    pub fn set_position(&mut self, position: Vec3) -> &mut Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the [`Camera`]'s Zoom.
+    ///
+    pub fn set_zoom(&mut self, zoom: f32) -> &mut Self {
+        self.zoom = zoom;
+        self
+    }
+
+    /// Sets the [`Camera`]'s [`YAxis`].
+    ///
+    pub fn set_y_axis(&mut self, y_axis: YAxis) -> &mut Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// Moves the [`Camera`] by offset.
+    ///
+    pub fn pan(&mut self, offset: Vec2) -> &mut Self {
+        self.position.x += offset.x;
+        self.position.y += offset.y;
+        self
+    }
+
+    /// Returns the World Position divided by the Zoom the shaders scroll
+    /// parallax [`crate::Rect`]'s by followed by the World Position at the
+    /// center of the view used for distance fading.
+    ///
+    pub(crate) fn shader_position(&self) -> Vec4 {
+        if self.zoom == 0.0 {
+            return Vec4::ZERO;
+        }
+
+        let center =
+            self.view_projection().inverse() * Vec4::new(0.0, 0.0, 0.0, 1.0);
+        let center = if center.w != 0.0 {
+            Vec2::new(center.x, center.y) / center.w
+        } else {
+            Vec2::ZERO
+        };
+
+        Vec4::new(
+            self.position.x / self.zoom,
+            self.position.y / self.zoom,
+            center.x,
+            center.y,
+        )
+    }
+
+    /// Returns the [`Camera`]'s Scale used by the shaders.
+    ///
+    pub fn scale(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Returns the [`Projection`] to use with [`crate::System::set_projection`].
+    /// [`YAxis::Down`] swaps the bottom and top so Y increases down the screen.
+    ///
+    pub fn as_projection(&self) -> Projection {
+        let (bottom, top) = match self.y_axis {
+            YAxis::Up => (self.bottom, self.top),
+            YAxis::Down => (self.top, self.bottom),
+        };
+
+        Projection::Orthographic {
+            left: self.left,
+            right: self.right,
+            bottom,
+            top,
+            near: self.near,
+            far: self.far,
+        }
+    }
+
+    /// Returns the Projection Matrix 4x4.
+    ///
+    pub fn projection(&self) -> Mat4 {
+        self.as_projection().into()
+    }
+
+    /// Returns the View Matrix 4x4.
+    ///
+    pub fn view(&self) -> Mat4 {
+        Mat4::from_translation(-self.position)
+    }
+
+    /// Converts a Screen position into a World position using this [`Camera`].
+    /// Screen positions start from the bottom left of the screen.
+    /// The World position follows the [`Camera`]'s [`YAxis`].
+    ///
+    pub fn screen_to_world(&self, screen_pos: Vec2, screen_size: Vec2) -> Vec2 {
+        let ndc = (screen_pos / screen_size) * 2.0 - Vec2::ONE;
+        let world = self.view_projection().inverse()
+            * Vec4::new(ndc.x, ndc.y, 0.0, 1.0);
+
+        Vec2::new(world.x, world.y) / world.w
+    }
+
+    /// Returns the World area this [`Camera`] shows on a screen of surface_size.
+    /// Use [`Bounds::is_visible_in`] against it to cull what is off screen.
+    ///
+    pub fn visible_bounds(&self, surface_size: Vec2) -> Bounds {
+        let first = self.screen_to_world(Vec2::ZERO, surface_size);
+        let second = self.screen_to_world(surface_size, surface_size);
+        let min = first.min(second);
+        let max = first.max(second);
+
+        Bounds::new(min.x, min.y, max.x, max.y)
+    }
+
+    /// Returns the full Matrix 4x4 the shaders use to render with scale.
+    ///
+    pub fn view_projection(&self) -> Mat4 {
+        self.projection()
+            * self.view()
+            * Mat4::from_scale(Vec3::new(self.zoom, self.zoom, 1.0))
+    }
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self::orthographic(0.0, 1.0, 0.0, 1.0, 1.0, -100.0)
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{Allocation, AtlasSet, GpuRenderer, GraphicsError, TileSheet};
+use crate::{
+    Allocation, AtlasSet, GpuRenderer, GraphicsError, TextureHandle, TileSheet,
+};
 use image::{DynamicImage, GenericImageView, ImageFormat};
 use std::{
     io::{Error, ErrorKind},
@@ -37,6 +39,34 @@ impl Texture {
         Ok(Self::from_image(name, image::open(path)?))
     }
 
+    /// Creates a [`Texture`] from loaded File making every pixel matching
+    /// color_key fully transparent. Used for legacy art that has no alpha.
+    ///
+    pub fn from_file_with_color_key(
+        path: impl AsRef<Path>,
+        color_key: Option<[u8; 3]>,
+    ) -> Result<Self, GraphicsError> {
+        let mut texture = Self::from_file(path)?;
+
+        if let Some(color_key) = color_key {
+            texture.apply_color_key(color_key);
+        }
+
+        Ok(texture)
+    }
+
+    /// Makes every pixel whose RGB matches color_key fully transparent.
+    ///
+    pub fn apply_color_key(&mut self, color_key: [u8; 3]) -> &mut Self {
+        for pixel in self.bytes.chunks_exact_mut(4) {
+            if pixel[..3] == color_key {
+                pixel.copy_from_slice(&[0, 0, 0, 0]);
+            }
+        }
+
+        self
+    }
+
     /// Creates a [`Texture`] from loaded File and uploads it to an [`AtlasSet`].
     /// Returns Associated [`AtlasSet`] Index.
     ///
@@ -50,7 +80,9 @@ impl Texture {
         if let Some(id) = atlas.lookup(&name) {
             Some(id)
         } else {
-            let texture = Texture::from_file(path).ok()?;
+            let texture =
+                Texture::from_file_with_color_key(path, atlas.color_key)
+                    .ok()?;
             let (width, height) = texture.size();
             atlas.upload(name, texture.bytes(), width, height, 0, renderer)
         }
@@ -69,7 +101,9 @@ impl Texture {
         if let Some(id) = atlas.lookup(&name) {
             atlas.peek(id).map(|(allocation, _)| (id, *allocation))
         } else {
-            let texture = Texture::from_file(path).ok()?;
+            let texture =
+                Texture::from_file_with_color_key(path, atlas.color_key)
+                    .ok()?;
             let (width, height) = texture.size();
             atlas.upload_with_alloc(
                 name,
@@ -82,6 +116,20 @@ impl Texture {
         }
     }
 
+    /// Creates a [`Texture`] from loaded File and uploads it to an [`AtlasSet`].
+    /// Returns Associated [`TextureHandle`] and [`Allocation`].
+    ///
+    pub fn upload_from_with_handle(
+        path: impl AsRef<Path>,
+        atlas: &mut AtlasSet<String, i32>,
+        renderer: &GpuRenderer,
+    ) -> Option<(TextureHandle, Allocation)> {
+        let (id, allocation) =
+            Texture::upload_from_with_alloc(path, atlas, renderer)?;
+
+        Some((atlas.handle(id)?, allocation))
+    }
+
     /// Creates a [`Texture`] from [`DynamicImage`].
     ///
     pub fn from_image(name: String, image: DynamicImage) -> Self {
@@ -12,10 +12,56 @@ impl TextureGroup {
         texture_view: &wgpu::TextureView,
         layout: K,
     ) -> Self {
+        Self::from_view_with_filter(
+            renderer,
+            texture_view,
+            layout,
+            wgpu::FilterMode::Nearest,
+        )
+    }
+
+    /// Creates a [`TextureGroup`] whose sampler uses the given [`wgpu::FilterMode`].
+    /// Linear filtering is needed for textures like Signed Distance Fields.
+    ///
+    pub fn from_view_with_filter<K: Layout>(
+        renderer: &mut GpuRenderer,
+        texture_view: &wgpu::TextureView,
+        layout: K,
+        filter: wgpu::FilterMode,
+    ) -> Self {
+        Self::from_view_with_anisotropy(
+            renderer,
+            texture_view,
+            layout,
+            filter,
+            1,
+        )
+    }
+
+    /// Creates a [`TextureGroup`] whose sampler uses the given max anisotropy.
+    /// Anisotropy above 1 requires Linear filtering so the filter is ignored.
+    ///
+    pub fn from_view_with_anisotropy<K: Layout>(
+        renderer: &mut GpuRenderer,
+        texture_view: &wgpu::TextureView,
+        layout: K,
+        filter: wgpu::FilterMode,
+        anisotropy: u16,
+    ) -> Self {
+        let filter = if anisotropy > 1 {
+            wgpu::FilterMode::Linear
+        } else {
+            filter
+        };
+
         let diffuse_sampler =
             renderer.device().create_sampler(&wgpu::SamplerDescriptor {
                 label: Some("Texture_sampler"),
                 lod_max_clamp: 0.0,
+                mag_filter: filter,
+                min_filter: filter,
+                mipmap_filter: filter,
+                anisotropy_clamp: anisotropy.max(1),
                 ..Default::default()
             });
 
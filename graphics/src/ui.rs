@@ -1,9 +1,47 @@
+mod aabb;
+mod checker;
+mod click;
+mod color;
+mod composite;
+mod crossfade;
+mod drag;
+mod glow;
+mod gradient;
+mod interaction;
+mod isolation;
+mod material;
+mod picking;
 mod pipeline;
+mod progress;
+mod rect_desc;
+mod rect_warning;
 mod rectangle;
 mod render;
+mod scroll;
+mod shake;
+mod uv_overflow;
 mod vertex;
 
+pub use aabb::*;
+pub use checker::*;
+pub use click::*;
+pub use color::*;
+pub use composite::*;
+pub use crossfade::*;
+pub use drag::*;
+pub use glow::*;
+pub use gradient::*;
+pub use interaction::*;
+pub use isolation::*;
+pub use material::*;
+pub use picking::*;
 pub use pipeline::*;
+pub use progress::*;
+pub use rect_desc::*;
+pub use rect_warning::*;
 pub use rectangle::*;
 pub use render::*;
+pub use scroll::*;
+pub use shake::*;
+pub use uv_overflow::*;
 pub use vertex::*;
@@ -0,0 +1,16 @@
+use crate::{Bounds, Rect};
+
+/// Returns the [`Bounds`] enclosing every [`Rect`] using [`Rect::aabb`].
+/// Zero sized [`Rect`]'s are hidden so they are skipped.
+/// Returns None if no [`Rect`] is left.
+///
+/// # Arguments
+/// - rects: [`Rect`]'s to enclose such as a selection or a Camera's content.
+///
+pub fn union_bounds(rects: &[&Rect]) -> Option<Bounds> {
+    rects
+        .iter()
+        .filter(|rect| !rect.is_zero_sized())
+        .map(|rect| rect.aabb())
+        .reduce(|union, aabb| union.union(&aabb))
+}
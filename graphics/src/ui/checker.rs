@@ -0,0 +1,30 @@
+use cosmic_text::Color;
+
+/// Bit within [`crate::RectVertex`] flags set when a [`CheckerBackground`] is
+/// drawn. Its Colors and cell size are stored within the Fill Gradient fields.
+pub const RECT_FLAG_CHECKER: u32 = 1 << 17;
+
+/// Procedural checkerboard drawn behind a [`crate::Rect`]'s Image so
+/// transparent areas can be seen, such as within editors and color pickers.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CheckerBackground {
+    /// Width and Height of each cell in pixels.
+    pub size: f32,
+    /// Color of the cell at the bottom left corner.
+    pub color_a: Color,
+    /// Color of the cells next to the [`CheckerBackground::color_a`] cells.
+    pub color_b: Color,
+}
+
+impl CheckerBackground {
+    /// Creates a new [`CheckerBackground`].
+    ///
+    pub fn new(size: f32, color_a: Color, color_b: Color) -> Self {
+        Self {
+            size: size.max(1.0),
+            color_a,
+            color_b,
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::Vec2;
+use std::time::Duration;
+
+/// How many clicks in a row a click made with [`ClickTracker`] counts as.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClickCount {
+    /// First click or one made too late or too far from the last.
+    Single,
+    /// Second click within the interval and tolerance of the first.
+    Double,
+    /// Third click within the interval and tolerance of the second.
+    Triple,
+}
+
+/// Counts repeated clicks on the same [`crate::Rect`] so double and triple
+/// clicks do not need tracking by each caller. Feed it the user_id returned
+/// by [`crate::pick_top`] or checked with [`crate::Rect::check_mouse_bounds`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClickTracker {
+    /// Max time between clicks for them to count as repeated.
+    pub interval: Duration,
+    /// Max distance in pixels the Mouse can move between repeated clicks.
+    pub tolerance: f32,
+    /// user_id, time, position and count of the last click.
+    last: Option<(u64, Duration, Vec2, ClickCount)>,
+}
+
+impl Default for ClickTracker {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(500), 4.0)
+    }
+}
+
+impl ClickTracker {
+    /// Creates a new [`ClickTracker`].
+    ///
+    /// # Arguments
+    /// - interval: Max time between clicks for them to count as repeated.
+    /// - tolerance: Max distance in pixels the Mouse can move between them.
+    ///
+    pub fn new(interval: Duration, tolerance: f32) -> Self {
+        Self {
+            interval,
+            tolerance: tolerance.max(0.0),
+            last: None,
+        }
+    }
+
+    /// Records a click on the [`crate::Rect`] with user_id and returns what it
+    /// counts as. A click after a [`ClickCount::Triple`] starts over as a
+    /// [`ClickCount::Single`].
+    ///
+    /// # Arguments
+    /// - user_id: user_id of the [`crate::Rect`] that was clicked.
+    /// - mouse_pos: Mouse position the click happened at.
+    /// - time: Time the click happened at such as the time since startup.
+    ///
+    pub fn click(
+        &mut self,
+        user_id: u64,
+        mouse_pos: Vec2,
+        time: Duration,
+    ) -> ClickCount {
+        let count = match self.last {
+            Some((last_id, last_time, last_pos, count))
+                if last_id == user_id
+                    && time.saturating_sub(last_time) <= self.interval
+                    && time >= last_time
+                    && last_pos.distance(mouse_pos) <= self.tolerance =>
+            {
+                match count {
+                    ClickCount::Single => ClickCount::Double,
+                    ClickCount::Double => ClickCount::Triple,
+                    ClickCount::Triple => ClickCount::Single,
+                }
+            }
+            _ => ClickCount::Single,
+        };
+
+        self.last = Some((user_id, time, mouse_pos, count));
+        count
+    }
+
+    /// Forgets the last click so the next one counts as a
+    /// [`ClickCount::Single`]. Use when the Mouse leaves the window.
+    ///
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
@@ -0,0 +1,79 @@
+use cosmic_text::Color;
+
+/// Converts Hue, Saturation, Value and Alpha into a [`Color`].
+///
+/// # Arguments
+/// - h: Hue in degrees. Wraps around 360.0.
+/// - s: Saturation 0.0 to 1.0.
+/// - v: Value 0.0 to 1.0.
+/// - a: Alpha 0.0 to 1.0.
+///
+pub fn hsv_to_color(h: f32, s: f32, v: f32, a: f32) -> Color {
+    let (s, v) = (s.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+    let chroma = v * s;
+
+    rgb_from_chroma(h, chroma, v - chroma, a)
+}
+
+/// Converts Hue, Saturation, Lightness and Alpha into a [`Color`].
+///
+/// # Arguments
+/// - h: Hue in degrees. Wraps around 360.0.
+/// - s: Saturation 0.0 to 1.0.
+/// - l: Lightness 0.0 to 1.0.
+/// - a: Alpha 0.0 to 1.0.
+///
+pub fn hsl_to_color(h: f32, s: f32, l: f32, a: f32) -> Color {
+    let (s, l) = (s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+
+    rgb_from_chroma(h, chroma, l - chroma * 0.5, a)
+}
+
+/// Converts a [`Color`] into Hue in degrees, Saturation, Value and Alpha.
+///
+pub fn color_to_hsv(color: Color) -> (f32, f32, f32, f32) {
+    let r = color.r() as f32 / 255.0;
+    let g = color.g() as f32 / 255.0;
+    let b = color.b() as f32 / 255.0;
+    let max = r.max(g).max(b);
+    let chroma = max - r.min(g).min(b);
+
+    let hue = if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / chroma + 2.0)
+    } else {
+        60.0 * ((r - g) / chroma + 4.0)
+    };
+
+    let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+
+    (hue, saturation, max, color.a() as f32 / 255.0)
+}
+
+//Builds the Color from Hue, Chroma and the amount to add to each channel.
+fn rgb_from_chroma(h: f32, chroma: f32, m: f32, a: f32) -> Color {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    let to_byte = |c: f32| ((c + m).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    Color::rgba(
+        to_byte(r),
+        to_byte(g),
+        to_byte(b),
+        (a.clamp(0.0, 1.0) * 255.0).round() as u8,
+    )
+}
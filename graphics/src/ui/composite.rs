@@ -0,0 +1,72 @@
+/// How a [`crate::Rect`] is blended with what was rendered beneath it.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CompositeOp {
+    /// Alpha blends over the backdrop.
+    #[default]
+    Normal,
+    /// Multiplies the backdrop by the color which darkens it.
+    Multiply,
+    /// Screens the backdrop with the color which lightens it.
+    Screen,
+    /// Adds the color onto the backdrop.
+    Add,
+}
+
+impl CompositeOp {
+    /// Every [`CompositeOp`] that renders with its own pipeline.
+    pub const BLENDED: [CompositeOp; 3] =
+        [CompositeOp::Multiply, CompositeOp::Screen, CompositeOp::Add];
+
+    /// Returns the [`wgpu::BlendState`] of the [`CompositeOp`].
+    /// Non Normal states expect the shader to output premultiplied colors
+    /// and leave the backdrops alpha untouched.
+    ///
+    pub fn blend_state(&self) -> wgpu::BlendState {
+        let (src_factor, dst_factor) = match self {
+            CompositeOp::Normal => return wgpu::BlendState::ALPHA_BLENDING,
+            CompositeOp::Multiply => {
+                (wgpu::BlendFactor::Dst, wgpu::BlendFactor::OneMinusSrcAlpha)
+            }
+            CompositeOp::Screen => {
+                (wgpu::BlendFactor::One, wgpu::BlendFactor::OneMinusSrc)
+            }
+            CompositeOp::Add => {
+                (wgpu::BlendFactor::One, wgpu::BlendFactor::One)
+            }
+        };
+
+        wgpu::BlendState {
+            color: wgpu::BlendComponent {
+                src_factor,
+                dst_factor,
+                operation: wgpu::BlendOperation::Add,
+            },
+            alpha: wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::Zero,
+                dst_factor: wgpu::BlendFactor::One,
+                operation: wgpu::BlendOperation::Add,
+            },
+        }
+    }
+
+    //Numeric id used as the RectBlendPipeline key.
+    pub(crate) fn id(&self) -> u32 {
+        match self {
+            CompositeOp::Normal => 0,
+            CompositeOp::Multiply => 1,
+            CompositeOp::Screen => 2,
+            CompositeOp::Add => 3,
+        }
+    }
+
+    //Converts a RectBlendPipeline key back into its CompositeOp.
+    pub(crate) fn from_id(id: u32) -> Self {
+        match id {
+            1 => CompositeOp::Multiply,
+            2 => CompositeOp::Screen,
+            3 => CompositeOp::Add,
+            _ => CompositeOp::Normal,
+        }
+    }
+}
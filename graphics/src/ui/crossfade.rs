@@ -0,0 +1,52 @@
+/// Bit within [`crate::RectVertex`] gradient_map's second value set while
+/// cross fading when [`crate::RECT_FLAG_GRADIENT_MAP`] is not set. The low 12
+/// bits then hold the faded in Texture's Layer and uv_scroll its atlas X and Y.
+pub const RECT_FLAG_CROSSFADE: u32 = 1 << 31;
+/// Shift of the cross fade mix factor stored as 16 bits next to the Layer.
+pub const RECT_CROSSFADE_MIX_SHIFT: u32 = 12;
+/// Max cross fade mix factor once shifted down.
+pub const RECT_CROSSFADE_MIX_MAX: u32 = 0xffff;
+
+/// Animated cross fade of a [`crate::Rect`] from one atlas Texture to another.
+/// See [`crate::Rect::begin_texture_crossfade`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TextureCrossfade {
+    /// [`crate::AtlasSet`] index of the Texture fading out.
+    pub from: usize,
+    /// [`crate::AtlasSet`] index of the Texture fading in.
+    pub to: usize,
+    /// Seconds the cross fade takes.
+    pub duration: f32,
+    /// Seconds passed since the cross fade began.
+    pub elapsed: f32,
+}
+
+impl TextureCrossfade {
+    /// Creates a new [`TextureCrossfade`] that has not started yet.
+    ///
+    pub fn new(from: usize, to: usize, duration: f32) -> Self {
+        Self {
+            from,
+            to,
+            duration: duration.max(0.0),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Returns how far along the cross fade is from 0.0 to 1.0.
+    ///
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+
+        (self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// If the Texture fading in is fully shown.
+    ///
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Bounds, Rect, Vec2, Vec3};
+
+/// Tracks dragging a [`Rect`] with the Mouse while keeping the point it was
+/// grabbed at under the cursor.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DragState {
+    /// Offset from the [`Rect`]'s position to where it was grabbed.
+    /// None while not dragging.
+    offset: Option<Vec2>,
+    /// Optional [`Bounds`] the [`Rect`] is kept within while dragging.
+    pub limits: Option<Bounds>,
+}
+
+impl DragState {
+    /// Creates a new [`DragState`] that is not dragging.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`Bounds`] the [`Rect`] is kept within while dragging.
+    ///
+    pub fn with_limits(mut self, limits: Bounds) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
+    /// Starts dragging if the Mouse is within the [`Rect`] using
+    /// [`Rect::check_mouse_bounds`]. Returns true if the [`Rect`] was grabbed.
+    ///
+    pub fn begin(&mut self, rect: &Rect, mouse_pos: Vec2) -> bool {
+        if !rect.check_mouse_bounds(mouse_pos) {
+            return false;
+        }
+
+        self.offset =
+            Some(mouse_pos - Vec2::new(rect.position.x, rect.position.y));
+        true
+    }
+
+    /// Moves the [`Rect`] so the grab point stays under the Mouse.
+    /// Does nothing while not dragging. Returns true if the [`Rect`] moved.
+    ///
+    pub fn update(&mut self, rect: &mut Rect, mouse_pos: Vec2) -> bool {
+        let offset = match self.offset {
+            Some(offset) => offset,
+            None => return false,
+        };
+
+        let mut position = mouse_pos - offset;
+
+        if let Some(limits) = &self.limits {
+            position.x =
+                position.x.min(limits.right - rect.size.x).max(limits.left);
+            position.y =
+                position.y.min(limits.top - rect.size.y).max(limits.bottom);
+        }
+
+        if position == Vec2::new(rect.position.x, rect.position.y) {
+            return false;
+        }
+
+        rect.set_position(Vec3::new(position.x, position.y, rect.position.z));
+        true
+    }
+
+    /// Stops dragging.
+    ///
+    pub fn end(&mut self) {
+        self.offset = None;
+    }
+
+    /// If a [`Rect`] is currently being dragged.
+    ///
+    pub fn is_dragging(&self) -> bool {
+        self.offset.is_some()
+    }
+}
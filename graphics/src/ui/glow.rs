@@ -0,0 +1,29 @@
+use cosmic_text::Color;
+
+/// Bit within [`crate::RectVertex`] flags set when the Instance is a [`RectGlow`] halo.
+pub const RECT_FLAG_GLOW: u32 = 1 << 7;
+
+/// Soft additive halo rendered around a [`crate::Rect`]'s shape.
+/// The halo follows the [`crate::Rect`]'s radius and corner smoothness.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RectGlow {
+    /// Color of the halo. Its alpha scales the strength.
+    pub color: Color,
+    /// Brightness the halo is added with. Values above 1.0 over brighten.
+    pub intensity: f32,
+    /// How far the halo reaches out from the [`crate::Rect`]'s edge.
+    pub radius: f32,
+}
+
+impl RectGlow {
+    /// Creates a new [`RectGlow`].
+    ///
+    pub fn new(color: Color, intensity: f32, radius: f32) -> Self {
+        Self {
+            color,
+            intensity: intensity.max(0.0),
+            radius: radius.max(0.0),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use cosmic_text::Color;
+
+/// Bit within [`crate::RectVertex`] flags set when a fill [`Gradient`] is used.
+pub const RECT_FLAG_GRADIENT: u32 = 1;
+/// Bit within [`crate::RectVertex`] flags set when the [`Gradient`] gets dithered.
+pub const RECT_FLAG_DITHER: u32 = 1 << 1;
+/// Bit within [`crate::RectVertex`] flags set when a border [`Gradient`] is used.
+pub const RECT_FLAG_BORDER_GRADIENT: u32 = 1 << 2;
+/// Bit within [`crate::RectVertex`] flags set when the Image is recolored by a Gradient Map ramp.
+pub const RECT_FLAG_GRADIENT_MAP: u32 = 1 << 3;
+/// Shift of the [`FillCombine`] stored as 2 bits within [`crate::RectVertex`] flags.
+pub const RECT_FILL_COMBINE_SHIFT: u32 = 18;
+/// Shift of the cells per side of a tessellated [`Gradient`] stored within
+/// [`crate::RectVertex`] gradient_map. The lower 16 bits hold the cell's
+/// column and row as u8's. See [`GradientQuality::High`].
+pub const RECT_GRADIENT_CELLS_SHIFT: u32 = 16;
+
+/// Linear Gradient used to fill a [`crate::Rect`] or its border.
+/// The Gradient is evaluated at the vertices of the [`crate::Rect`] and
+/// interpolated across it. See [`GradientQuality`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Gradient {
+    /// Color the Gradient starts with.
+    pub start: Color,
+    /// Color the Gradient ends with.
+    pub end: Color,
+    /// Direction of the Gradient in radians. 0.0 goes from left to right
+    /// and increases counter clockwise.
+    pub angle: f32,
+}
+
+impl Gradient {
+    /// Creates a new [`Gradient`].
+    ///
+    pub fn new(start: Color, end: Color, angle: f32) -> Self {
+        Self { start, end, angle }
+    }
+}
+
+/// Quality used to render a [`Gradient`].
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GradientQuality {
+    /// Renders the Gradient as is. Large Gradients may band on 8 bit displays.
+    #[default]
+    Standard,
+    /// Dithers the Gradient to hide banding on 8 bit displays. Large
+    /// [`crate::Rect`]'s also get tessellated into a grid of cells whose
+    /// vertices the Gradient is interpolated between.
+    High,
+}
+
+/// How a [`crate::Rect`]'s Image is combined with its Fill Color or [`Gradient`].
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FillCombine {
+    /// The Image is shown as is ignoring the Fill.
+    Replace,
+    /// The Image is multiplied by the Fill so it tints and shades it.
+    #[default]
+    Multiply,
+    /// The Fill is added onto the Image to light it up.
+    Add,
+}
+
+impl FillCombine {
+    /// Returns the [`FillCombine`] packed into [`crate::RectVertex`] flags.
+    ///
+    pub fn flags(&self) -> u32 {
+        let mode = match self {
+            FillCombine::Multiply => 0,
+            FillCombine::Replace => 1,
+            FillCombine::Add => 2,
+        };
+
+        mode << RECT_FILL_COMBINE_SHIFT
+    }
+}
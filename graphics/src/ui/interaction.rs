@@ -0,0 +1,212 @@
+use crate::{Rect, Vec2};
+use cosmic_text::Color;
+
+/// Visual properties of a [`Rect`] that can be swapped between or
+/// transitioned too by an [`Interaction`].
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RectState {
+    /// Color of the Rect.
+    pub color: Color,
+    /// Color of the Rects Border.
+    pub border_color: Color,
+    /// Width of the Rects Border.
+    pub border_width: f32,
+    /// Rectangle Radius.
+    pub radius: f32,
+}
+
+impl RectState {
+    /// Creates a new [`RectState`].
+    ///
+    pub fn new(
+        color: Color,
+        border_color: Color,
+        border_width: f32,
+        radius: f32,
+    ) -> Self {
+        Self {
+            color,
+            border_color,
+            border_width,
+            radius,
+        }
+    }
+
+    /// Creates a [`RectState`] from the [`Rect`]'s current visual properties.
+    ///
+    pub fn from_rect(rect: &Rect) -> Self {
+        Self {
+            color: rect.color,
+            border_color: rect.border_color,
+            border_width: rect.border_width,
+            radius: rect.radius,
+        }
+    }
+
+    /// Sets the [`Rect`]'s visual properties to the [`RectState`].
+    /// Only marks the [`Rect`] changed if something is different.
+    ///
+    pub fn apply(&self, rect: &mut Rect) {
+        if RectState::from_rect(rect) != *self {
+            rect.set_color(self.color)
+                .set_border_color(self.border_color)
+                .set_border_width(self.border_width)
+                .set_radius(self.radius);
+        }
+    }
+
+    /// Returns a [`RectState`] blended between self and other by amount 0.0 to 1.0.
+    ///
+    pub fn lerp(&self, other: &RectState, amount: f32) -> RectState {
+        let amount = amount.clamp(0.0, 1.0);
+
+        RectState {
+            color: lerp_color(self.color, other.color, amount),
+            border_color: lerp_color(
+                self.border_color,
+                other.border_color,
+                amount,
+            ),
+            border_width: self.border_width
+                + (other.border_width - self.border_width) * amount,
+            radius: self.radius + (other.radius - self.radius) * amount,
+        }
+    }
+}
+
+//Blends each channel of the two colors by amount.
+fn lerp_color(from: Color, to: Color, amount: f32) -> Color {
+    let lerp = |a: u8, b: u8| -> u8 {
+        (a as f32 + (b as f32 - a as f32) * amount).round() as u8
+    };
+
+    Color::rgba(
+        lerp(from.r(), to.r()),
+        lerp(from.g(), to.g()),
+        lerp(from.b(), to.b()),
+        lerp(from.a(), to.a()),
+    )
+}
+
+/// Which [`RectState`] of an [`Interaction`] is active.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InteractionState {
+    /// Mouse is outside of the Rect.
+    #[default]
+    Normal,
+    /// Mouse is over the Rect.
+    Hover,
+    /// Mouse is pressed over the Rect.
+    Pressed,
+}
+
+/// Applies a normal, hover or pressed [`RectState`] to a [`Rect`] based on the mouse.
+/// Handles the common button feedback pattern with an optional transition.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Interaction {
+    /// [`RectState`] used while the mouse is outside of the [`Rect`].
+    pub normal: RectState,
+    /// [`RectState`] used while the mouse is over the [`Rect`].
+    pub hover: RectState,
+    /// [`RectState`] used while the mouse is pressed over the [`Rect`].
+    pub pressed: RectState,
+    /// Seconds it takes to transition between states. 0.0 swaps instantly.
+    pub transition: f32,
+    /// Currently active [`InteractionState`].
+    state: InteractionState,
+    /// [`RectState`] currently applied to the [`Rect`].
+    current: RectState,
+    /// [`RectState`] the transition started from.
+    from: RectState,
+    /// Seconds elapsed within the current transition.
+    elapsed: f32,
+}
+
+impl Interaction {
+    /// Creates a new [`Interaction`] starting in the normal state.
+    ///
+    pub fn new(
+        normal: RectState,
+        hover: RectState,
+        pressed: RectState,
+    ) -> Self {
+        Self {
+            normal,
+            hover,
+            pressed,
+            transition: 0.0,
+            state: InteractionState::Normal,
+            current: normal,
+            from: normal,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Sets the seconds it takes to transition between states.
+    ///
+    pub fn with_transition(mut self, seconds: f32) -> Self {
+        self.transition = seconds.max(0.0);
+        self
+    }
+
+    /// Returns the currently active [`InteractionState`].
+    ///
+    pub fn state(&self) -> InteractionState {
+        self.state
+    }
+
+    /// Returns the [`RectState`] for an [`InteractionState`].
+    ///
+    pub fn target(&self, state: InteractionState) -> &RectState {
+        match state {
+            InteractionState::Normal => &self.normal,
+            InteractionState::Hover => &self.hover,
+            InteractionState::Pressed => &self.pressed,
+        }
+    }
+
+    /// Picks the [`InteractionState`] using [`Rect::check_mouse_bounds`] and
+    /// applies its [`RectState`] to the [`Rect`].
+    /// Returns the active [`InteractionState`].
+    ///
+    /// # Arguments
+    /// - rect: [`Rect`] to apply the state too.
+    /// - mouse_pos: Mouse position on the screen.
+    /// - pressed: If the mouse button is held down.
+    /// - delta: Seconds since the last update used to advance the transition.
+    ///
+    pub fn update(
+        &mut self,
+        rect: &mut Rect,
+        mouse_pos: Vec2,
+        pressed: bool,
+        delta: f32,
+    ) -> InteractionState {
+        let state = match (rect.check_mouse_bounds(mouse_pos), pressed) {
+            (true, true) => InteractionState::Pressed,
+            (true, false) => InteractionState::Hover,
+            (false, _) => InteractionState::Normal,
+        };
+
+        if state != self.state {
+            self.state = state;
+            self.from = self.current;
+            self.elapsed = 0.0;
+        }
+
+        let target = *self.target(state);
+
+        self.current = if self.transition > 0.0 {
+            self.elapsed = (self.elapsed + delta).min(self.transition);
+            self.from.lerp(&target, self.elapsed / self.transition)
+        } else {
+            target
+        };
+
+        self.current.apply(rect);
+        state
+    }
+}
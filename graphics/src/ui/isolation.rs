@@ -0,0 +1,195 @@
+use crate::{
+    GpuRenderer, InstanceBuffer, RectVertex, TextureGroup, TextureLayout,
+};
+use cosmic_text::Color;
+use wgpu::util::DeviceExt;
+
+/// Handle to a [`IsolationGroup`] added to a [`crate::RectRenderer`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IsolationGroupId(pub usize);
+
+/// Group of [`crate::Rect`]'s rendered to an offscreen target first and then
+/// composited to screen as one flat image at the groups opacity.
+/// This keeps overlapping translucent members from showing through each other
+/// when the whole group fades.
+///
+pub struct IsolationGroup {
+    /// Instance Buffer holding all [`crate::Rect`]'s within the group.
+    pub buffer: InstanceBuffer<RectVertex>,
+    /// Opacity the group is composited at. 0.0 to 1.0.
+    pub opacity: f32,
+    /// Z depth the composited image is rendered at.
+    pub z: f32,
+    /// Buffer Layer of the [`crate::RectRenderer`] the group composites within.
+    pub buffer_layer: usize,
+    /// Offscreen Color Target the members render into.
+    target: wgpu::TextureView,
+    /// Offscreen Depth Target the members render with.
+    depth: wgpu::TextureView,
+    /// Bind Group to sample the offscreen target while compositing.
+    texture_group: TextureGroup,
+    /// Single full screen instance used to composite the target.
+    composite: wgpu::Buffer,
+    /// Size the targets were created with.
+    size: [u32; 2],
+}
+
+impl IsolationGroup {
+    /// Creates a new [`IsolationGroup`] with targets the size of the window.
+    ///
+    pub fn new(
+        renderer: &mut GpuRenderer,
+        buffer_layer: usize,
+        z: f32,
+    ) -> Self {
+        let size = Self::window_size(renderer);
+        let (target, depth, texture_group) =
+            Self::create_targets(renderer, size);
+        let composite = renderer.device().create_buffer_init(
+            &wgpu::util::BufferInitDescriptor {
+                label: Some("isolation composite buffer"),
+                contents: bytemuck::bytes_of(&RectVertex::default()),
+                usage: wgpu::BufferUsages::VERTEX
+                    | wgpu::BufferUsages::COPY_DST,
+            },
+        );
+
+        Self {
+            buffer: InstanceBuffer::new(renderer.gpu_device(), 512),
+            opacity: 1.0,
+            z,
+            buffer_layer,
+            target,
+            depth,
+            texture_group,
+            composite,
+            size,
+        }
+    }
+
+    /// Recreates the targets if the window got resized and uploads the
+    /// composite instance. Called within [`crate::RectRenderer::finalize`].
+    ///
+    pub fn prepare(&mut self, renderer: &mut GpuRenderer) {
+        let size = Self::window_size(renderer);
+
+        if size != self.size {
+            let (target, depth, texture_group) =
+                Self::create_targets(renderer, size);
+
+            self.target = target;
+            self.depth = depth;
+            self.texture_group = texture_group;
+            self.size = size;
+        }
+
+        let instance = RectVertex {
+            position: [0.0, 0.0, self.z],
+            size: [size[0] as f32, size[1] as f32],
+            uv: [0.0, 0.0, size[0] as f32, size[1] as f32],
+            color: Color::rgba(255, 255, 255, (self.opacity * 255.0) as u8).0,
+            border_width: 0.0,
+            border_color: 0,
+            layer: 0,
+            radius: 0.0,
+            ..Default::default()
+        };
+
+        renderer.queue().write_buffer(
+            &self.composite,
+            0,
+            bytemuck::bytes_of(&instance),
+        );
+    }
+
+    /// Returns the offscreen Color Target the members render into.
+    ///
+    pub fn target(&self) -> &wgpu::TextureView {
+        &self.target
+    }
+
+    /// Returns the offscreen Depth Target the members render with.
+    ///
+    pub fn depth(&self) -> &wgpu::TextureView {
+        &self.depth
+    }
+
+    /// Returns the Bind Group used to sample the offscreen target.
+    ///
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.texture_group.bind_group
+    }
+
+    /// Returns the full screen composite instance buffer.
+    ///
+    pub fn composite(&self) -> &wgpu::Buffer {
+        &self.composite
+    }
+
+    //Gets the window size clamped so we never create a zero sized target.
+    fn window_size(renderer: &GpuRenderer) -> [u32; 2] {
+        let size = renderer.size();
+
+        [(size.width as u32).max(1), (size.height as u32).max(1)]
+    }
+
+    //Creates the Color and Depth targets and the Color targets sampling Bind Group.
+    fn create_targets(
+        renderer: &mut GpuRenderer,
+        size: [u32; 2],
+    ) -> (wgpu::TextureView, wgpu::TextureView, TextureGroup) {
+        let format = renderer.surface_format();
+        let extent = wgpu::Extent3d {
+            width: size[0],
+            height: size[1],
+            depth_or_array_layers: 1,
+        };
+
+        let texture =
+            renderer.device().create_texture(&wgpu::TextureDescriptor {
+                label: Some("isolation texture"),
+                size: extent,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[format],
+            });
+
+        let target = texture.create_view(&wgpu::TextureViewDescriptor {
+            label: Some("isolation target"),
+            dimension: Some(wgpu::TextureViewDimension::D2),
+            ..Default::default()
+        });
+
+        // The Rect shader samples a D2Array so we view the target as one.
+        let array_view = texture.create_view(&wgpu::TextureViewDescriptor {
+            label: Some("isolation array view"),
+            dimension: Some(wgpu::TextureViewDimension::D2Array),
+            array_layer_count: Some(1),
+            ..Default::default()
+        });
+
+        let depth_texture =
+            renderer.device().create_texture(&wgpu::TextureDescriptor {
+                label: Some("isolation depth texture"),
+                size: extent,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Depth32Float,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[wgpu::TextureFormat::Depth32Float],
+            });
+        let depth =
+            depth_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let texture_group =
+            TextureGroup::from_view(renderer, &array_view, TextureLayout);
+
+        (target, depth, texture_group)
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    create_rect_pipeline, FrameLayout, GpuDevice, GpuRenderer, GraphicsError,
+    InstanceBuffer, Layout, OtherError, RectVertex, SystemLayout,
+    TextureLayout,
+};
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+/// Handle to a [`Material`] added to a [`crate::RectRenderer`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MaterialHandle(pub usize);
+
+/// Custom Fragment Shader used to render a [`crate::Rect`].
+///
+/// The source gets appended to the default Rect shader so it has access to
+/// `VertexOutput`, `global`, `frame`, `tex`, `tex_sample` and `distance_alg`.
+/// The fragment entry point must take a `VertexOutput` and return `@location(0) vec4<f32>`.
+/// If a uniform is given it is bound to `@group(3) @binding(0)`.
+///
+#[derive(Clone, Debug)]
+pub struct Material {
+    /// WGSL source containing the fragment entry point.
+    pub source: String,
+    /// Name of the fragment entry point within source.
+    pub entry_point: String,
+    /// Optional uniform bytes bound at group 3.
+    pub uniform: Option<Vec<u8>>,
+}
+
+impl Material {
+    /// Creates a new [`Material`] without a uniform.
+    ///
+    pub fn new(
+        source: impl Into<String>,
+        entry_point: impl Into<String>,
+    ) -> Self {
+        Self {
+            source: source.into(),
+            entry_point: entry_point.into(),
+            uniform: None,
+        }
+    }
+
+    /// Sets the [`Material`]'s uniform bytes.
+    ///
+    pub fn with_uniform(mut self, uniform: &[u8]) -> Self {
+        self.uniform = Some(uniform.to_vec());
+        self
+    }
+}
+
+/// [`Material`] uniform rendering Layout
+///
+#[repr(C)]
+#[derive(Clone, Copy, Hash, Pod, Zeroable)]
+pub struct MaterialLayout;
+
+impl Layout for MaterialLayout {
+    fn create_layout(
+        &self,
+        gpu_device: &mut GpuDevice,
+    ) -> wgpu::BindGroupLayout {
+        gpu_device.device().create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("material_bind_group_layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX
+                        | wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                }],
+            },
+        )
+    }
+}
+
+/// A compiled [`Material`] with its own Instance Buffer so [`crate::Rect`]'s
+/// using it are grouped and rendered with its pipeline.
+///
+pub struct RectMaterial {
+    /// Pipeline built from the default vertex shader and the materials fragment.
+    pub pipeline: wgpu::RenderPipeline,
+    /// Optional uniform buffer and its bind group.
+    pub uniform: Option<(wgpu::Buffer, wgpu::BindGroup)>,
+    /// Instance Buffer holding all [`crate::Rect`]'s using this Material.
+    pub buffer: InstanceBuffer<RectVertex>,
+}
+
+impl RectMaterial {
+    /// Compiles the [`Material`] creating a new [`RectMaterial`].
+    /// Returns a Error if the WGSL fails to parse or validate.
+    ///
+    pub fn new(
+        renderer: &mut GpuRenderer,
+        material: &Material,
+    ) -> Result<Self, GraphicsError> {
+        let source = format!(
+            "{}\n{}",
+            include_str!("../shaders/rectangle_shader.wgsl"),
+            material.source
+        );
+
+        validate_wgsl(&source, renderer)?;
+
+        let shader = renderer.device().create_shader_module(
+            wgpu::ShaderModuleDescriptor {
+                label: Some("Material Shader"),
+                source: wgpu::ShaderSource::Wgsl(source.into()),
+            },
+        );
+
+        let system_layout = renderer.create_layout(SystemLayout);
+        let texture_layout = renderer.create_layout(TextureLayout);
+        let frame_layout = renderer.create_layout(FrameLayout);
+
+        let (pipeline, uniform) = if let Some(bytes) = &material.uniform {
+            let material_layout = renderer.create_layout(MaterialLayout);
+            let buffer = renderer.device().create_buffer_init(
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some("material buffer"),
+                    contents: bytes,
+                    usage: wgpu::BufferUsages::UNIFORM
+                        | wgpu::BufferUsages::COPY_DST,
+                },
+            );
+            let bind_group = renderer.device().create_bind_group(
+                &wgpu::BindGroupDescriptor {
+                    layout: &material_layout,
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: buffer.as_entire_binding(),
+                    }],
+                    label: Some("material_bind_group"),
+                },
+            );
+
+            (
+                create_rect_pipeline(
+                    renderer.gpu_device(),
+                    &shader,
+                    &material.entry_point,
+                    &[
+                        &system_layout,
+                        &texture_layout,
+                        &frame_layout,
+                        &material_layout,
+                    ],
+                    renderer.surface_format(),
+                    wgpu::BlendState::ALPHA_BLENDING,
+                ),
+                Some((buffer, bind_group)),
+            )
+        } else {
+            (
+                create_rect_pipeline(
+                    renderer.gpu_device(),
+                    &shader,
+                    &material.entry_point,
+                    &[&system_layout, &texture_layout, &frame_layout],
+                    renderer.surface_format(),
+                    wgpu::BlendState::ALPHA_BLENDING,
+                ),
+                None,
+            )
+        };
+
+        Ok(Self {
+            pipeline,
+            uniform,
+            buffer: InstanceBuffer::new(renderer.gpu_device(), 512),
+        })
+    }
+
+    /// Writes new bytes into the [`RectMaterial`]'s uniform if it has one.
+    ///
+    pub fn update_uniform(&self, renderer: &GpuRenderer, bytes: &[u8]) {
+        if let Some((buffer, _)) = &self.uniform {
+            renderer.queue().write_buffer(buffer, 0, bytes);
+        }
+    }
+}
+
+/// Parses and Validates WGSL source with naga against what the Device
+/// supports so errors can be returned rather than causing wgpu to panic.
+///
+pub(crate) fn validate_wgsl(
+    source: &str,
+    renderer: &GpuRenderer,
+) -> Result<(), GraphicsError> {
+    let module = naga::front::wgsl::parse_str(source)
+        .map_err(|e| OtherError::new(&e.emit_to_string(source)))?;
+
+    naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        shader_capabilities(renderer),
+    )
+    .validate(&module)
+    .map_err(|e| OtherError::new(&format!("{:?}", e)))?;
+
+    Ok(())
+}
+
+//Returns the naga Capabilities the Device's enabled features allow the same
+//way wgpu validates shader modules. Subgroups are left out as Rect shaders
+//never run with subgroup stages enabled.
+fn shader_capabilities(renderer: &GpuRenderer) -> naga::valid::Capabilities {
+    use naga::valid::Capabilities as Caps;
+    use wgpu::{DownlevelFlags, Features};
+
+    let features = renderer.device().features();
+    let downlevel = renderer.adapter().get_downlevel_capabilities().flags;
+    let mut caps = Caps::empty();
+
+    for (cap, enabled) in [
+        (Caps::PUSH_CONSTANT, features.contains(Features::PUSH_CONSTANTS)),
+        (Caps::FLOAT64, features.contains(Features::SHADER_F64)),
+        (
+            Caps::PRIMITIVE_INDEX,
+            features.contains(Features::SHADER_PRIMITIVE_INDEX),
+        ),
+        (
+            Caps::SAMPLED_TEXTURE_AND_STORAGE_BUFFER_ARRAY_NON_UNIFORM_INDEXING
+                | Caps::SAMPLER_NON_UNIFORM_INDEXING,
+            features.contains(
+                Features::SAMPLED_TEXTURE_AND_STORAGE_BUFFER_ARRAY_NON_UNIFORM_INDEXING,
+            ),
+        ),
+        (
+            Caps::UNIFORM_BUFFER_AND_STORAGE_TEXTURE_ARRAY_NON_UNIFORM_INDEXING,
+            features.contains(
+                Features::UNIFORM_BUFFER_AND_STORAGE_TEXTURE_ARRAY_NON_UNIFORM_INDEXING,
+            ),
+        ),
+        (
+            Caps::STORAGE_TEXTURE_16BIT_NORM_FORMATS,
+            features.contains(Features::TEXTURE_FORMAT_16BIT_NORM),
+        ),
+        (Caps::MULTIVIEW, features.contains(Features::MULTIVIEW)),
+        (
+            Caps::EARLY_DEPTH_TEST,
+            features.contains(Features::SHADER_EARLY_DEPTH_TEST),
+        ),
+        (Caps::SHADER_INT64, features.contains(Features::SHADER_INT64)),
+        (
+            Caps::MULTISAMPLED_SHADING,
+            downlevel.contains(DownlevelFlags::MULTISAMPLED_SHADING),
+        ),
+        (
+            Caps::DUAL_SOURCE_BLENDING,
+            features.contains(Features::DUAL_SOURCE_BLENDING),
+        ),
+        (
+            Caps::CUBE_ARRAY_TEXTURES,
+            downlevel.contains(DownlevelFlags::CUBE_ARRAY_TEXTURES),
+        ),
+    ] {
+        caps.set(cap, enabled);
+    }
+
+    caps
+}
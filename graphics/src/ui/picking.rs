@@ -0,0 +1,28 @@
+use crate::{Rect, Vec2};
+
+/// Returns the topmost [`Rect`] under the Mouse along with its user_id.
+/// Topmost is the [`Rect`] with the highest [`crate::DrawOrder`] so the
+/// [`Rect`]'s must have been updated for their order to be current.
+/// [`Rect`]'s clipped away by their Bounds at the Mouse position are skipped.
+///
+/// # Arguments
+/// - rects: [`Rect`]'s to pick from.
+/// - mouse_pos: Mouse position within the same space as the [`Rect`]'s.
+///
+pub fn pick_top<'a, I>(rects: I, mouse_pos: Vec2) -> Option<(u64, &'a Rect)>
+where
+    I: IntoIterator<Item = &'a Rect>,
+{
+    rects
+        .into_iter()
+        .filter(|rect| {
+            rect.bounds.is_none_or(|bounds| {
+                mouse_pos.x >= bounds.left
+                    && mouse_pos.x <= bounds.right
+                    && mouse_pos.y >= bounds.bottom
+                    && mouse_pos.y <= bounds.top
+            }) && rect.check_mouse_bounds(mouse_pos)
+        })
+        .max_by(|a, b| a.order.cmp(&b.order))
+        .map(|rect| (rect.user_id, rect))
+}
@@ -1,6 +1,7 @@
 use crate::{
-    BufferLayout, GpuDevice, LayoutStorage, PipeLineLayout, RectVertex,
-    StaticVertexBuffer, SystemLayout, TextureLayout,
+    BufferLayout, CompositeOp, FrameLayout, GpuDevice, LayoutStorage,
+    PipeLineLayout, RectVertex, StaticVertexBuffer, SystemLayout,
+    TextureLayout,
 };
 use bytemuck::{Pod, Zeroable};
 
@@ -11,6 +12,159 @@ use bytemuck::{Pod, Zeroable};
 pub struct RectRenderPipeline;
 
 impl PipeLineLayout for RectRenderPipeline {
+    fn create_layout(
+        &self,
+        gpu_device: &mut GpuDevice,
+        layouts: &mut LayoutStorage,
+        surface_format: wgpu::TextureFormat,
+    ) -> wgpu::RenderPipeline {
+        create_rect_pipeline_from_source(
+            gpu_device,
+            layouts,
+            surface_format,
+            include_str!("../shaders/rectangle_shader.wgsl"),
+        )
+    }
+}
+
+/// Creates the [`RectRenderPipeline`] from WGSL source.
+/// Used to reload the Rect shader from disk without restarting.
+///
+pub(crate) fn create_rect_pipeline_from_source(
+    gpu_device: &mut GpuDevice,
+    layouts: &mut LayoutStorage,
+    surface_format: wgpu::TextureFormat,
+    source: &str,
+) -> wgpu::RenderPipeline {
+    let shader = gpu_device.device().create_shader_module(
+        wgpu::ShaderModuleDescriptor {
+            label: Some("Shader"),
+            source: wgpu::ShaderSource::Wgsl(source.into()),
+        },
+    );
+
+    let system_layout = layouts.create_layout(gpu_device, SystemLayout);
+    let texture_layout = layouts.create_layout(gpu_device, TextureLayout);
+    let frame_layout = layouts.create_layout(gpu_device, FrameLayout);
+
+    create_rect_pipeline(
+        gpu_device,
+        &shader,
+        "fragment",
+        &[&system_layout, &texture_layout, &frame_layout],
+        surface_format,
+        wgpu::BlendState::ALPHA_BLENDING,
+    )
+}
+
+/// [`crate::Rect`] RenderPipeline Layout used to render a [`crate::IsolationGroup`]'s
+/// members into its offscreen target. Outputs premultiplied alpha so the
+/// target can be composited as one flat image.
+///
+#[repr(C)]
+#[derive(Clone, Copy, Hash, Pod, Zeroable)]
+pub struct RectIsolationPipeline;
+
+impl PipeLineLayout for RectIsolationPipeline {
+    fn create_layout(
+        &self,
+        gpu_device: &mut GpuDevice,
+        layouts: &mut LayoutStorage,
+        surface_format: wgpu::TextureFormat,
+    ) -> wgpu::RenderPipeline {
+        let shader = gpu_device.device().create_shader_module(
+            wgpu::ShaderModuleDescriptor {
+                label: Some("Shader"),
+                source: wgpu::ShaderSource::Wgsl(
+                    include_str!("../shaders/rectangle_shader.wgsl").into(),
+                ),
+            },
+        );
+
+        let system_layout = layouts.create_layout(gpu_device, SystemLayout);
+        let texture_layout = layouts.create_layout(gpu_device, TextureLayout);
+        let frame_layout = layouts.create_layout(gpu_device, FrameLayout);
+
+        create_rect_pipeline(
+            gpu_device,
+            &shader,
+            "fragment",
+            &[&system_layout, &texture_layout, &frame_layout],
+            surface_format,
+            wgpu::BlendState {
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::SrcAlpha,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+            },
+        )
+    }
+}
+
+/// [`crate::Rect`] RenderPipeline Layout used to composite a [`crate::IsolationGroup`]'s
+/// premultiplied offscreen target to screen. The groups opacity is applied
+/// using the blend constant.
+///
+#[repr(C)]
+#[derive(Clone, Copy, Hash, Pod, Zeroable)]
+pub struct RectCompositePipeline;
+
+impl PipeLineLayout for RectCompositePipeline {
+    fn create_layout(
+        &self,
+        gpu_device: &mut GpuDevice,
+        layouts: &mut LayoutStorage,
+        surface_format: wgpu::TextureFormat,
+    ) -> wgpu::RenderPipeline {
+        let shader = gpu_device.device().create_shader_module(
+            wgpu::ShaderModuleDescriptor {
+                label: Some("Shader"),
+                source: wgpu::ShaderSource::Wgsl(
+                    include_str!("../shaders/rectangle_shader.wgsl").into(),
+                ),
+            },
+        );
+
+        let system_layout = layouts.create_layout(gpu_device, SystemLayout);
+        let texture_layout = layouts.create_layout(gpu_device, TextureLayout);
+        let frame_layout = layouts.create_layout(gpu_device, FrameLayout);
+
+        create_rect_pipeline(
+            gpu_device,
+            &shader,
+            "fragment",
+            &[&system_layout, &texture_layout, &frame_layout],
+            surface_format,
+            wgpu::BlendState {
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::Constant,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+            },
+        )
+    }
+}
+
+/// [`crate::Rect`] RenderPipeline Layout used to add [`crate::RectGlow`] halos
+/// on top of what is already rendered.
+///
+#[repr(C)]
+#[derive(Clone, Copy, Hash, Pod, Zeroable)]
+pub struct RectGlowPipeline;
+
+impl PipeLineLayout for RectGlowPipeline {
     fn create_layout(
         &self,
         gpu_device: &mut GpuDevice,
@@ -28,66 +182,146 @@ impl PipeLineLayout for RectRenderPipeline {
 
         let system_layout = layouts.create_layout(gpu_device, SystemLayout);
         let texture_layout = layouts.create_layout(gpu_device, TextureLayout);
+        let frame_layout = layouts.create_layout(gpu_device, FrameLayout);
 
-        // Create the render pipeline.
-        gpu_device.device().create_render_pipeline(
-            &wgpu::RenderPipelineDescriptor {
-                label: Some("rectangle_render_pipeline"),
-                layout: Some(&gpu_device.device().create_pipeline_layout(
-                    &wgpu::PipelineLayoutDescriptor {
-                        label: Some("rectangle_render_pipeline_layout"),
-                        bind_group_layouts: &[&system_layout, &texture_layout],
-                        push_constant_ranges: &[],
-                    },
-                )),
-                vertex: wgpu::VertexState {
-                    module: &shader,
-                    entry_point: "vertex",
-                    buffers: &[
-                        wgpu::VertexBufferLayout {
-                            array_stride: StaticVertexBuffer::stride(),
-                            step_mode: wgpu::VertexStepMode::Vertex,
-                            attributes: &[
-                                StaticVertexBuffer::vertex_attribute(),
-                            ],
-                        },
-                        wgpu::VertexBufferLayout {
-                            array_stride: RectVertex::stride() as u64,
-                            step_mode: wgpu::VertexStepMode::Instance,
-                            attributes: &RectVertex::attributes(),
-                        },
-                    ],
-                    compilation_options: Default::default(),
+        create_rect_pipeline(
+            gpu_device,
+            &shader,
+            "fragment",
+            &[&system_layout, &texture_layout, &frame_layout],
+            surface_format,
+            wgpu::BlendState {
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
                 },
-                primitive: wgpu::PrimitiveState {
-                    topology: wgpu::PrimitiveTopology::TriangleList,
-                    strip_index_format: None,
-                    front_face: wgpu::FrontFace::Ccw,
-                    cull_mode: None,
-                    unclipped_depth: false,
-                    polygon_mode: wgpu::PolygonMode::Fill,
-                    conservative: false,
+                alpha: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::Zero,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
                 },
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth32Float,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::LessEqual,
-                    stencil: wgpu::StencilState::default(),
-                    bias: wgpu::DepthBiasState::default(),
-                }),
-                multisample: wgpu::MultisampleState::default(),
-                fragment: Some(wgpu::FragmentState {
-                    module: &shader,
-                    entry_point: "fragment",
-                    targets: &[Some(wgpu::ColorTargetState {
-                        format: surface_format,
-                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
-                        write_mask: wgpu::ColorWrites::ALL,
-                    })],
-                    compilation_options: Default::default(),
-                }),
-                multiview: None,
             },
         )
     }
 }
+
+/// [`crate::Rect`] RenderPipeline Layout used to blend [`crate::Rect`]'s
+/// against the backdrop with a [`CompositeOp`]. Each op is its own pipeline.
+///
+#[repr(C)]
+#[derive(Clone, Copy, Hash, Pod, Zeroable)]
+pub struct RectBlendPipeline {
+    op: u32,
+}
+
+impl RectBlendPipeline {
+    /// Creates the [`RectBlendPipeline`] key of a [`CompositeOp`].
+    ///
+    pub fn new(op: CompositeOp) -> Self {
+        Self { op: op.id() }
+    }
+}
+
+impl PipeLineLayout for RectBlendPipeline {
+    fn create_layout(
+        &self,
+        gpu_device: &mut GpuDevice,
+        layouts: &mut LayoutStorage,
+        surface_format: wgpu::TextureFormat,
+    ) -> wgpu::RenderPipeline {
+        let shader = gpu_device.device().create_shader_module(
+            wgpu::ShaderModuleDescriptor {
+                label: Some("Shader"),
+                source: wgpu::ShaderSource::Wgsl(
+                    include_str!("../shaders/rectangle_shader.wgsl").into(),
+                ),
+            },
+        );
+
+        let system_layout = layouts.create_layout(gpu_device, SystemLayout);
+        let texture_layout = layouts.create_layout(gpu_device, TextureLayout);
+        let frame_layout = layouts.create_layout(gpu_device, FrameLayout);
+
+        create_rect_pipeline(
+            gpu_device,
+            &shader,
+            "fragment_premultiplied",
+            &[&system_layout, &texture_layout, &frame_layout],
+            surface_format,
+            CompositeOp::from_id(self.op).blend_state(),
+        )
+    }
+}
+
+/// Creates a [`crate::Rect`] [`wgpu::RenderPipeline`] using the shaders fragment entry point.
+/// Used to share the Rect pipeline setup with Custom Materials.
+///
+pub(crate) fn create_rect_pipeline(
+    gpu_device: &GpuDevice,
+    shader: &wgpu::ShaderModule,
+    fragment_entry: &str,
+    bind_group_layouts: &[&wgpu::BindGroupLayout],
+    surface_format: wgpu::TextureFormat,
+    blend: wgpu::BlendState,
+) -> wgpu::RenderPipeline {
+    let device = gpu_device.device();
+    let constants = gpu_device.pipeline_constants();
+
+    // Create the render pipeline.
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("rectangle_render_pipeline"),
+        layout: Some(&device.create_pipeline_layout(
+            &wgpu::PipelineLayoutDescriptor {
+                label: Some("rectangle_render_pipeline_layout"),
+                bind_group_layouts,
+                push_constant_ranges: &[],
+            },
+        )),
+        vertex: wgpu::VertexState {
+            module: shader,
+            entry_point: "vertex",
+            buffers: &[
+                wgpu::VertexBufferLayout {
+                    array_stride: StaticVertexBuffer::stride(),
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &[StaticVertexBuffer::vertex_attribute()],
+                },
+                wgpu::VertexBufferLayout {
+                    array_stride: RectVertex::stride() as u64,
+                    step_mode: wgpu::VertexStepMode::Instance,
+                    attributes: &RectVertex::attributes(),
+                },
+            ],
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &constants,
+                ..Default::default()
+            },
+        },
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            strip_index_format: None,
+            front_face: wgpu::FrontFace::Ccw,
+            cull_mode: None,
+            unclipped_depth: false,
+            polygon_mode: wgpu::PolygonMode::Fill,
+            conservative: false,
+        },
+        depth_stencil: gpu_device.depth_stencil_state(),
+        multisample: wgpu::MultisampleState::default(),
+        fragment: Some(wgpu::FragmentState {
+            module: shader,
+            entry_point: fragment_entry,
+            targets: &[Some(wgpu::ColorTargetState {
+                format: surface_format,
+                blend: Some(blend),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants: &constants,
+                ..Default::default()
+            },
+        }),
+        multiview: None,
+    })
+}
@@ -0,0 +1,36 @@
+/// Bit within [`crate::RectVertex`] flags set when the Progress fills left to right.
+pub const RECT_FLAG_PROGRESS_X: u32 = 1 << 4;
+/// Bit within [`crate::RectVertex`] flags set when the Progress fills bottom to top.
+pub const RECT_FLAG_PROGRESS_Y: u32 = 1 << 5;
+/// Bit within [`crate::RectVertex`] flags set when the Progress sweeps clockwise from the top.
+pub const RECT_FLAG_PROGRESS_RADIAL: u32 = 1 << 6;
+/// Shift of the Progress stored as a 12 bit fraction within the upper bits of
+/// [`crate::RectVertex`] flags.
+pub const RECT_PROGRESS_SHIFT: u32 = 20;
+/// Value the Progress fraction is stored as when fully shown.
+pub const RECT_PROGRESS_MAX: u32 = 0xfff;
+
+/// How a [`crate::Rect`] reveals its Progress fraction.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ProgressMode {
+    /// Fills from the left to the right.
+    #[default]
+    LinearX,
+    /// Fills from the bottom to the top.
+    LinearY,
+    /// Sweeps clockwise starting from the top like a cooldown.
+    Radial,
+}
+
+impl ProgressMode {
+    /// Returns the [`crate::RectVertex`] flag bit of the [`ProgressMode`].
+    ///
+    pub fn flag(&self) -> u32 {
+        match self {
+            ProgressMode::LinearX => RECT_FLAG_PROGRESS_X,
+            ProgressMode::LinearY => RECT_FLAG_PROGRESS_Y,
+            ProgressMode::Radial => RECT_FLAG_PROGRESS_RADIAL,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    Bounds, CameraType, CompositeOp, Gradient, RectGlow, TextureHandle, Vec2,
+    Vec3, Vec4,
+};
+use cosmic_text::Color;
+
+/// Plain data description of a [`crate::Rect`] that needs no
+/// [`crate::GpuRenderer`] to create, such as when parsing a UI file.
+/// Holds no Instance Buffers Store. One is only allocated once it is
+/// built into a [`crate::Rect`] with [`crate::Rect::from_desc`].
+///
+#[derive(Clone, Debug)]
+pub struct RectDesc {
+    /// Position on the Screen.
+    pub position: Vec3,
+    /// Width and Height of the Rect.
+    pub size: Vec2,
+    /// Color of the Rect.
+    pub color: Color,
+    /// Optional Image [`TextureHandle`].
+    pub image: Option<TextureHandle>,
+    /// Texture X, Y, W and H if any apply.
+    pub uv: Vec4,
+    /// Width of the Rects Border.
+    pub border_width: f32,
+    /// Color of the Rects Border.
+    pub border_color: Color,
+    /// Rectangle Radius.
+    pub radius: f32,
+    /// Optional Fill [`Gradient`].
+    pub gradient: Option<Gradient>,
+    /// Optional Border [`Gradient`].
+    pub border_gradient: Option<Gradient>,
+    /// Optional additive [`RectGlow`] halo rendered around the Rect.
+    pub glow: Option<RectGlow>,
+    /// [`CameraType`] used to render with.
+    pub camera_type: CameraType,
+    /// Rendering Layer of the rect used in DrawOrder.
+    pub render_layer: u32,
+    /// Stacking index within the Rendering Layer used in DrawOrder.
+    pub z_index: i32,
+    /// Optional Bounds for Clipping the Rect too.
+    pub bounds: Option<Bounds>,
+    /// [`CompositeOp`] the Rect is blended with.
+    pub composite_op: CompositeOp,
+    /// Application id attached to the Rect such as an Entity.
+    pub user_id: u64,
+    /// If wheel events under the Rect get routed to it by [`crate::ScrollTarget`].
+    pub scrollable: bool,
+}
+
+impl Default for RectDesc {
+    fn default() -> Self {
+        Self {
+            position: Vec3::default(),
+            size: Vec2::default(),
+            color: Color::rgba(255, 255, 255, 255),
+            image: None,
+            uv: Vec4::default(),
+            border_width: 0.0,
+            border_color: Color::rgba(0, 0, 0, 0),
+            radius: 0.0,
+            gradient: None,
+            border_gradient: None,
+            glow: None,
+            camera_type: CameraType::None,
+            render_layer: 0,
+            z_index: 0,
+            bounds: None,
+            composite_op: CompositeOp::Normal,
+            user_id: 0,
+            scrollable: false,
+        }
+    }
+}
+
+impl RectDesc {
+    /// Creates a new [`RectDesc`] with rendering layer.
+    ///
+    pub fn new(render_layer: u32) -> Self {
+        Self {
+            render_layer,
+            ..Default::default()
+        }
+    }
+}
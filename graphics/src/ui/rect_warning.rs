@@ -0,0 +1,67 @@
+use thiserror::Error;
+
+/// Non fatal misconfiguration of a [`crate::Rect`] found by
+/// [`crate::Rect::validate`] that would otherwise show up as visual glitches.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Error)]
+pub enum RectWarning {
+    /// Position or Size holds a NaN or infinite value.
+    #[error("position or size is not finite")]
+    NonFinite,
+    /// Border Width is below zero.
+    #[error("border width {0} is negative")]
+    NegativeBorderWidth(f32),
+    /// Border Width covers the whole Rect leaving no room for the Fill.
+    #[error("border width {width} exceeds half-size {half_size}")]
+    BorderExceedsHalfSize {
+        /// Border Width of the Rect.
+        width: f32,
+        /// Half of the Rect's smaller side.
+        half_size: f32,
+    },
+    /// Radius is larger than half of the Rect's smaller side.
+    #[error("radius {radius} exceeds half-size {half_size}")]
+    RadiusExceedsHalfSize {
+        /// Radius of the Rect.
+        radius: f32,
+        /// Half of the Rect's smaller side.
+        half_size: f32,
+    },
+    /// Image handle is outdated as its Texture got removed from the atlas.
+    #[error("image was removed from the atlas")]
+    MissingImage,
+    /// Two features that share the same vertex fields are set together so
+    /// only the kept one is rendered.
+    #[error("{dropped:?} is not rendered while {kept:?} is set")]
+    FeatureConflict {
+        /// Feature that is rendered.
+        kept: RectFeature,
+        /// Feature that is ignored.
+        dropped: RectFeature,
+    },
+    /// UV's reach outside of the Image while using [`crate::UvOverflow::Clamp`].
+    #[error("uv {uv:?} lies outside of the image size {size:?}")]
+    UvOutsideImage {
+        /// Texture X, Y, W and H of the Rect.
+        uv: [f32; 4],
+        /// Width and Height of the Image.
+        size: [f32; 2],
+    },
+}
+
+/// Features of a [`crate::Rect`] that share vertex fields and so can not be
+/// rendered together. Reported by [`RectWarning::FeatureConflict`].
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RectFeature {
+    /// Fill [`crate::Gradient`].
+    Gradient,
+    /// [`crate::CheckerBackground`].
+    Checker,
+    /// Gradient Map ramp set by [`crate::Rect::set_gradient_map`].
+    GradientMap,
+    /// UV scroll set by [`crate::Rect::set_uv_scroll`].
+    UvScroll,
+    /// [`crate::TextureCrossfade`] set by [`crate::Rect::begin_texture_crossfade`].
+    Crossfade,
+}
@@ -1,9 +1,25 @@
 use crate::{
-    AtlasSet, Bounds, CameraType, DrawOrder, GpuRenderer, GraphicsError, Index,
-    OrderedIndex, OtherError, RectVertex, Texture, Vec2, Vec3, Vec4,
+    color_to_hsv, hsl_to_color, hsv_to_color, AtlasSet, Bounds, CameraId,
+    CameraType, CheckerBackground, CompositeOp, DrawOrder, FillCombine,
+    GpuRenderer, Gradient, GradientQuality, GraphicsError, Index,
+    IsolationGroupId, MaterialHandle, OrderedIndex, OtherError, ProgressMode,
+    RectDesc, RectFeature, RectGlow, RectVertex, RectWarning, Texture,
+    TextureCrossfade, TextureHandle, TrimOffset, UvOverflow, Vec2, Vec3, Vec4,
+    RECT_CAMERA_MASK, RECT_CAMERA_SHIFT, RECT_CLIP_FEATHER_MAX,
+    RECT_CLIP_FEATHER_SHIFT, RECT_CONTENT_INSET_MAX, RECT_CORNER_MASK,
+    RECT_CORNER_SHIFT, RECT_CROSSFADE_MIX_MAX, RECT_CROSSFADE_MIX_SHIFT,
+    RECT_FLAG_BORDER_GRADIENT, RECT_FLAG_BORDER_OVER_FILL, RECT_FLAG_CHECKER,
+    RECT_FLAG_CROSSFADE, RECT_FLAG_DITHER, RECT_FLAG_GLOW, RECT_FLAG_GRADIENT,
+    RECT_FLAG_GRADIENT_MAP, RECT_FLAG_QUARTER_FRAME, RECT_GRADIENT_CELLS_SHIFT,
+    RECT_LAYER_MASK, RECT_PROGRESS_MAX, RECT_PROGRESS_SHIFT,
 };
 use cosmic_text::Color;
 
+//Pixels each tessellated Gradient cell covers at most.
+const GRADIENT_CELL_SIZE: f32 = 128.0;
+//Max cells per side a Gradient gets tessellated into.
+const GRADIENT_MAX_CELLS: f32 = 16.0;
+
 /// Rectangle to render to screen.
 /// Can contain a Images otherwise just colors.
 ///
@@ -14,26 +30,114 @@ pub struct Rect {
     pub size: Vec2,
     /// Color of the Rect.
     pub color: Color,
-    /// Optional Image Index.
-    pub image: Option<usize>,
+    /// Optional Image [`TextureHandle`].
+    /// If the Handle becomes outdated the [`Rect`] will not render.
+    pub image: Option<TextureHandle>,
     /// Texture X, Y, W and H if any apply.
     pub uv: Vec4,
+    /// Revision of the Image's [`crate::Allocation`] the buffer was built with.
+    /// Used to refresh the UV's after the [`AtlasSet`] moves the Image.
+    pub image_revision: u32,
+    /// Optional [`TextureCrossfade`] the Rect is animating between two Textures.
+    /// Replaces the Image until it finishes.
+    pub crossfade: Option<TextureCrossfade>,
+    /// Velocity in pixels per second the Texture scrolls within its region.
+    pub uv_scroll: Vec2,
+    /// Insets the Image's UV's by half a texel on each side so filtering
+    /// never blends in the neighbouring Allocations of the atlas.
+    pub uv_inset: bool,
+    /// [`UvOverflow`] used where the UV's reach outside of the Image.
+    pub uv_overflow: UvOverflow,
+    /// Left, Bottom, Right and Top pixels the Image is confined within
+    /// so it does not draw under the Border.
+    pub content_inset: Vec4,
+    /// Factor the camera scrolls the Rect by. 1.0 follows the camera
+    /// and 0.0 stays fixed on the screen.
+    pub parallax: Vec2,
+    /// Columns and Rows of copies the Rect is repeated as.
+    pub repeat_count: Vec2,
+    /// Offset between each repeated copy.
+    pub repeat_spacing: Vec2,
     /// Width of the Rects Border.
     pub border_width: f32,
     /// Color of the Rects Border.
     pub border_color: Color,
+    /// Composites the Border over the Fill in one premultiplied step so
+    /// translucent Borders do not double blend with translucent Fills.
+    pub border_over_fill: bool,
     /// Rectangle Radius.
     pub radius: f32,
+    /// Derives the Radius as half the shorter side so the ends stay fully
+    /// rounded as the Rect resizes. Overrides radius while set.
+    pub pill: bool,
+    /// Superellipse exponent of the rounded corners from 2.0 to 10.0.
+    /// 2.0 gives circular corners and higher values give squircles.
+    pub corner_smoothness: f32,
+    /// Optional number of segments each squircle corner is approximated with
+    /// during hit testing. None uses the exact formula.
+    /// Circular corners always use the exact formula.
+    pub hit_test_precision: Option<u32>,
+    /// Optional Fill [`Gradient`]. Replaces the Color when set.
+    pub gradient: Option<Gradient>,
+    /// [`GradientQuality`] the Fill [`Gradient`] renders with.
+    pub gradient_quality: GradientQuality,
+    /// [`FillCombine`] the Image is combined with the Fill Color or [`Gradient`] by.
+    pub fill_combine: FillCombine,
+    /// Optional Border [`Gradient`]. Replaces the Border Color when set.
+    pub border_gradient: Option<Gradient>,
+    /// Optional [`CheckerBackground`] drawn behind the Image.
+    /// Replaces the Fill [`Gradient`] when set.
+    pub checker_background: Option<CheckerBackground>,
+    /// Fraction 0.0 to 1.0 of the Rect that is shown. 1.0 shows all of it.
+    pub progress: f32,
+    /// [`ProgressMode`] used to reveal the Progress fraction.
+    pub progress_mode: ProgressMode,
+    /// Optional [`AtlasSet`] index of a ramp Texture that recolors the Image
+    /// by its luminance. The ramp is read left to right along its first row.
+    pub gradient_map: Option<usize>,
+    /// Optional [`AtlasSet`] index of a quarter frame Texture mirrored into
+    /// all four corners. Replaces the Image while set.
+    pub quarter_frame: Option<usize>,
     /// [`CameraType`] used to render with.
     pub camera_type: CameraType,
+    /// Optional registered Camera to render with. Overrides camera_type.
+    pub camera_id: Option<CameraId>,
     /// Instance Buffers Store ID.
     pub store_id: Index,
     /// the draw order of the rect. created/updated when update is called.
     pub order: DrawOrder,
     /// Rendering Layer of the rect used in DrawOrder.
     pub render_layer: u32,
+    /// Stacking index within the Rendering Layer used in DrawOrder.
+    /// Sorted before the Position so position.z stays free for depth.
+    pub z_index: i32,
     /// Optional Bounds for Clipping the Rect too.
     pub bounds: Option<Bounds>,
+    /// [`CompositeOp`] the Rect is blended with against what is beneath it.
+    pub composite_op: CompositeOp,
+    /// Optional Custom [`crate::Material`] to render with.
+    pub material: Option<MaterialHandle>,
+    /// Optional [`crate::IsolationGroup`] the Rect is rendered within.
+    pub isolation_group: Option<IsolationGroupId>,
+    /// Optional additive [`RectGlow`] halo rendered around the Rect.
+    pub glow: Option<RectGlow>,
+    /// Instance Buffers Store ID of the [`RectGlow`] halo.
+    /// Created the first time a glow gets rendered.
+    pub glow_store_id: Option<Index>,
+    /// Application id attached to the Rect such as an Entity.
+    /// Returned by [`crate::pick_top`] so hits need no lookup table.
+    pub user_id: u64,
+    /// If wheel events under the Rect get routed to it by [`crate::ScrollTarget`].
+    pub scrollable: bool,
+    /// Pixels the edges of the Clipping Bounds fade out over instead of
+    /// being cut off by the scissor. 0 keeps hard clipping.
+    pub clip_feather: f32,
+    /// Optional distance from the center of the camera's view the Rect starts
+    /// and ends fading out at. Only applies to Rects rendered with a camera.
+    pub distance_fade: Option<Vec2>,
+    /// Area the Rect covered when its buffer was last built.
+    /// Reported as damage along with its new area when it changes.
+    pub damage_bounds: Option<Bounds>,
     /// If anything got updated we need to update the buffers too.
     pub changed: bool,
 }
@@ -44,90 +148,526 @@ impl Rect {
     pub fn new(renderer: &mut GpuRenderer, render_layer: u32) -> Self {
         let rect_size = bytemuck::bytes_of(&RectVertex::default()).len();
 
+        Self::with_store(renderer.new_buffer(rect_size, 0), render_layer)
+    }
+
+    /// Builds a [`Rect`] from a [`RectDesc`] authored without a renderer.
+    /// This is where its Instance Buffers Store gets allocated so build a
+    /// batch of parsed [`RectDesc`]'s once the [`GpuRenderer`] exists.
+    /// Values go through the setters so they are clamped as usual.
+    ///
+    pub fn from_desc(renderer: &mut GpuRenderer, desc: &RectDesc) -> Self {
+        let mut rect = Self::new(renderer, desc.render_layer);
+
+        rect.set_position(desc.position)
+            .set_size(desc.size)
+            .set_color(desc.color)
+            .set_container_uv(desc.uv)
+            .set_border_width(desc.border_width)
+            .set_border_color(desc.border_color)
+            .set_radius(desc.radius)
+            .set_use_camera(desc.camera_type)
+            .set_z_index(desc.z_index)
+            .set_composite_op(desc.composite_op)
+            .set_user_id(desc.user_id)
+            .set_scrollable(desc.scrollable)
+            .update_bounds(desc.bounds);
+        rect.image = desc.image;
+
+        if let Some(gradient) = desc.gradient {
+            rect.set_gradient(gradient.start, gradient.end, gradient.angle);
+        }
+
+        if let Some(gradient) = desc.border_gradient {
+            rect.set_border_gradient(
+                gradient.start,
+                gradient.end,
+                gradient.angle,
+            );
+        }
+
+        if let Some(glow) = desc.glow {
+            rect.set_glow(glow.color, glow.intensity, glow.radius);
+        }
+
+        rect
+    }
+
+    /// Creates a new [`Rect`] within a named rendering layer.
+    /// The layer gets registered if it was not already.
+    /// See [`GpuRenderer::register_layer`].
+    ///
+    pub fn new_in_layer(renderer: &mut GpuRenderer, layer_name: &str) -> Self {
+        let render_layer = renderer.register_layer(layer_name);
+
+        Self::new(renderer, render_layer)
+    }
+
+    /// Resets the [`Rect`] to a newly created state with rendering layer while
+    /// keeping its Instance Buffers Store. Used to reuse pooled [`Rect`]'s
+    /// without allocating a new Store for each spawn.
+    ///
+    pub fn reset(&mut self, render_layer: u32) -> &mut Self {
+        let (damage_bounds, glow_store_id) =
+            (self.damage_bounds, self.glow_store_id);
+
+        *self = Self::with_store(self.store_id, render_layer);
+        // Keep the last area so damage tracking still clears it.
+        self.damage_bounds = damage_bounds;
+        self.glow_store_id = glow_store_id;
+        self
+    }
+
+    //Creates a Rect with default values using an existing Store.
+    fn with_store(store_id: Index, render_layer: u32) -> Self {
         Self {
             position: Vec3::default(),
             size: Vec2::default(),
             color: Color::rgba(255, 255, 255, 255),
             image: None,
             uv: Vec4::default(),
+            image_revision: 0,
+            crossfade: None,
+            uv_scroll: Vec2::default(),
+            uv_inset: false,
+            uv_overflow: UvOverflow::Clamp,
+            content_inset: Vec4::ZERO,
+            parallax: Vec2::ONE,
+            repeat_count: Vec2::ONE,
+            repeat_spacing: Vec2::ZERO,
             border_width: 0.0,
             border_color: Color::rgba(0, 0, 0, 0),
+            border_over_fill: false,
             radius: 0.0,
+            pill: false,
+            corner_smoothness: 2.0,
+            hit_test_precision: None,
+            gradient: None,
+            gradient_quality: GradientQuality::Standard,
+            fill_combine: FillCombine::Multiply,
+            border_gradient: None,
+            checker_background: None,
+            progress: 1.0,
+            progress_mode: ProgressMode::LinearX,
+            gradient_map: None,
+            quarter_frame: None,
             camera_type: CameraType::None,
-            store_id: renderer.new_buffer(rect_size, 0),
+            camera_id: None,
+            store_id,
             order: DrawOrder::default(),
             render_layer,
+            z_index: 0,
             bounds: None,
+            composite_op: CompositeOp::Normal,
+            material: None,
+            isolation_group: None,
+            glow: None,
+            glow_store_id: None,
+            user_id: 0,
+            scrollable: false,
+            clip_feather: 0.0,
+            distance_fade: None,
+            damage_bounds: None,
             changed: true,
         }
     }
 
     /// Unloads the [`Rect`] from the Instance Buffers Store.
-    /// 
+    ///
     pub fn unload(&self, renderer: &mut GpuRenderer) {
         renderer.remove_buffer(self.store_id);
+
+        if let Some(glow_store_id) = self.glow_store_id {
+            renderer.remove_buffer(glow_store_id);
+        }
+    }
+
+    /// Sets the Application id attached to the [`Rect`].
+    /// Does not need the [`Rect`] to be rebuilt.
+    ///
+    pub fn set_user_id(&mut self, user_id: u64) -> &mut Self {
+        self.user_id = user_id;
+        self
+    }
+
+    /// Sets how many pixels the [`Rect`] fades out over inside the edges of
+    /// its Clipping Bounds so scrolled content does not show jagged cuts.
+    /// Up to 255 pixels in sixteenths of a pixel. 0 keeps hard clipping.
+    ///
+    pub fn set_clip_feather(&mut self, feather: f32) -> &mut Self {
+        self.clip_feather = feather.max(0.0);
+        self.changed = true;
+        self
+    }
+
+    /// Sets the World distance from the center of the camera's view the
+    /// [`Rect`] starts and ends fading out at so far away labels do not
+    /// clutter the view. Applied within the Shader so moving the camera
+    /// needs no rebuilds. Only applies to [`Rect`]'s rendered with a camera.
+    ///
+    pub fn set_distance_fade(&mut self, start: f32, end: f32) -> &mut Self {
+        let start = start.max(0.0);
+
+        self.distance_fade = Some(Vec2::new(start, end.max(start)));
+        self.changed = true;
+        self
+    }
+
+    /// Removes the distance fade so the [`Rect`] is shown at any distance.
+    ///
+    pub fn clear_distance_fade(&mut self) -> &mut Self {
+        self.distance_fade = None;
+        self.changed = true;
+        self
+    }
+
+    /// Sets if the [`Rect`] is a scroll region wheel events can be routed to
+    /// with [`crate::ScrollTarget`]. Does not need the [`Rect`] to be rebuilt.
+    ///
+    pub fn set_scrollable(&mut self, scrollable: bool) -> &mut Self {
+        self.scrollable = scrollable;
+        self
     }
 
     /// Updates the [`Rect`]'s Clipping Bounds.
-    /// 
+    ///
     pub fn update_bounds(&mut self, bounds: Option<Bounds>) {
+        // Feathered clipping reads the Bounds from the Instance.
+        if self.clip_feather > 0.0 && self.bounds != bounds {
+            self.changed = true;
+        }
+
         self.bounds = bounds;
     }
 
     /// Sets the [`Rect`]'s [`CameraType`] for rendering.
-    /// 
+    ///
     pub fn set_use_camera(&mut self, camera_type: CameraType) -> &mut Self {
         self.camera_type = camera_type;
+        self.camera_id = None;
+        self.changed = true;
+        self
+    }
+
+    /// Sets the [`Rect`] to render with a Camera registered using
+    /// [`GpuRenderer::add_camera`].
+    ///
+    pub fn set_use_camera_id(&mut self, camera_id: CameraId) -> &mut Self {
+        self.camera_id = Some(camera_id);
         self.changed = true;
         self
     }
 
+    /// Sets the [`Rect`]'s Custom [`crate::Material`] to render with.
+    /// The [`Rect`] must be updated using [`crate::RectRenderer::rect_update`]
+    /// for it to be grouped with the Material.
+    ///
+    pub fn set_custom_material(
+        &mut self,
+        material: MaterialHandle,
+    ) -> &mut Self {
+        self.material = Some(material);
+        self
+    }
+
+    /// Removes the [`Rect`]'s Custom [`crate::Material`] so it renders
+    /// with the default shader.
+    ///
+    pub fn clear_custom_material(&mut self) -> &mut Self {
+        self.material = None;
+        self
+    }
+
+    /// Sets the [`crate::IsolationGroup`] the [`Rect`] renders within.
+    /// Members are rendered offscreen and composited together at the groups opacity.
+    /// Custom [`crate::Material`]'s are ignored while within a group.
+    /// The [`Rect`] must be updated using [`crate::RectRenderer::rect_update`]
+    /// for it to be placed within the group.
+    ///
+    pub fn set_group_isolation(
+        &mut self,
+        group: Option<IsolationGroupId>,
+    ) -> &mut Self {
+        self.isolation_group = group;
+        self
+    }
+
     /// Sets the [`Rect`]'s Color.
-    /// 
+    ///
     pub fn set_color(&mut self, color: Color) -> &mut Self {
         self.color = color;
         self.changed = true;
         self
     }
 
+    /// Sets the [`Rect`]'s Color from Hue, Saturation, Value and Alpha.
+    ///
+    /// # Arguments
+    /// - h: Hue in degrees.
+    /// - s: Saturation 0.0 to 1.0.
+    /// - v: Value 0.0 to 1.0.
+    /// - a: Alpha 0.0 to 1.0.
+    ///
+    pub fn set_color_hsv(
+        &mut self,
+        h: f32,
+        s: f32,
+        v: f32,
+        a: f32,
+    ) -> &mut Self {
+        self.set_color(hsv_to_color(h, s, v, a))
+    }
+
+    /// Sets the [`Rect`]'s Color from Hue, Saturation, Lightness and Alpha.
+    ///
+    /// # Arguments
+    /// - h: Hue in degrees.
+    /// - s: Saturation 0.0 to 1.0.
+    /// - l: Lightness 0.0 to 1.0.
+    /// - a: Alpha 0.0 to 1.0.
+    ///
+    pub fn set_color_hsl(
+        &mut self,
+        h: f32,
+        s: f32,
+        l: f32,
+        a: f32,
+    ) -> &mut Self {
+        self.set_color(hsl_to_color(h, s, l, a))
+    }
+
+    /// Rotates the Hue of the [`Rect`]'s Color by degrees keeping its
+    /// Saturation, Value and Alpha.
+    ///
+    pub fn shift_hue(&mut self, degrees: f32) -> &mut Self {
+        let (h, s, v, a) = color_to_hsv(self.color);
+        self.set_color(hsv_to_color(h + degrees, s, v, a))
+    }
+
     /// Sets the [`Rect`]'s Border Color.
-    /// 
+    ///
     pub fn set_border_color(&mut self, color: Color) -> &mut Self {
         self.border_color = color;
         self.changed = true;
         self
     }
 
+    /// Begins cross fading the [`Rect`] from one Texture to another so
+    /// swapping an Image does not pop. Advance it with [`Rect::tick`] each
+    /// frame. Once finished the Rect's Image is set to the Texture faded in.
+    /// Both Textures are mixed within the Shader using the Rect's UV's so they
+    /// should share the same untrimmed size. A smaller Texture fading in has
+    /// its edges stretched instead of reading its neighbours. The Gradient
+    /// Map and UV scroll are paused while cross fading as it reuses their fields.
+    /// Both Textures must stay loaded within the [`AtlasSet`] the Rect renders
+    /// with, otherwise the Image is shown as is.
+    ///
+    /// # Arguments
+    /// - from: [`AtlasSet`] index of the Texture fading out.
+    /// - to: [`AtlasSet`] index of the Texture fading in.
+    /// - duration: Seconds the cross fade takes.
+    ///
+    pub fn begin_texture_crossfade(
+        &mut self,
+        from: usize,
+        to: usize,
+        duration: f32,
+    ) -> &mut Self {
+        self.crossfade = Some(TextureCrossfade::new(from, to, duration));
+        self.changed = true;
+        self
+    }
+
+    /// Advances the [`Rect`]'s animations by delta seconds.
+    /// Returns true while any are still running.
+    ///
+    pub fn tick(&mut self, delta: f32) -> bool {
+        match &mut self.crossfade {
+            // Once finished the next rebuild swaps in the Texture faded in.
+            Some(crossfade) if !crossfade.is_finished() => {
+                crossfade.elapsed += delta.max(0.0);
+                self.changed = true;
+                !crossfade.is_finished()
+            }
+            _ => false,
+        }
+    }
+
+    /// Sets the velocity in pixels per second the [`Rect`]'s Texture scrolls
+    /// at. The scroll wraps within the Texture's region and is driven by the
+    /// time given to [`GpuRenderer::set_time`] so it needs no rebuilds.
+    ///
+    pub fn set_uv_scroll(&mut self, velocity: Vec2) -> &mut Self {
+        self.uv_scroll = velocity;
+        self.changed = true;
+        self
+    }
+
+    /// Sets if the [`Rect`]'s Image UV's are inset by half a texel on each
+    /// side. Stops the edges of packed sprites showing a seam of their
+    /// neighbours under filtering at the cost of scaling the Image by one
+    /// texel. Ignored while the Texture scrolls as it wraps within its region.
+    ///
+    pub fn set_uv_inset(&mut self, inset: bool) -> &mut Self {
+        self.uv_inset = inset;
+        self.changed = true;
+        self
+    }
+
+    /// Sets what the [`Rect`] shows where its UV's reach outside of the
+    /// Image such as from animated UV's. [`UvOverflow::Clamp`] is used by
+    /// default. Ignored while the Texture scrolls or is trimmed.
+    ///
+    pub fn set_uv_overflow(&mut self, overflow: UvOverflow) -> &mut Self {
+        self.uv_overflow = overflow;
+        self.changed = true;
+        self
+    }
+
+    /// Sets the Left, Bottom, Right and Top pixels the Image is confined
+    /// within so a bordered icon shows inside of its frame instead of under
+    /// it. The Fill shows around the Image. Zero draws the Image over the
+    /// whole [`Rect`]. Each side is limited to 4095 pixels.
+    ///
+    pub fn set_content_inset(&mut self, inset: Vec4) -> &mut Self {
+        self.content_inset = inset.max(Vec4::ZERO);
+        self.changed = true;
+        self
+    }
+
+    /// Sets the factor the camera scrolls the [`Rect`] by for layered
+    /// parallax backgrounds. 1.0 moves with the World and 0.0 stays fixed on
+    /// the screen. Applied within the Shader so moving the camera needs no
+    /// rebuilds. Only affects [`Rect`]'s rendered with a camera and hit testing
+    /// still uses the unscrolled position.
+    ///
+    pub fn set_parallax(&mut self, factor: Vec2) -> &mut Self {
+        self.parallax = factor;
+        self.changed = true;
+        self
+    }
+
+    /// Sets the [`Rect`] to be drawn as a grid of count.x by count.y copies
+    /// each offset by spacing such as for grids or scanlines. The copies share
+    /// the [`Rect`]'s Buffer and hit testing tests each of them.
+    /// A count of 1 by 1 draws a single copy. Counts are rounded down.
+    ///
+    pub fn set_repeat(&mut self, count: Vec2, spacing: Vec2) -> &mut Self {
+        self.repeat_count = count.floor().max(Vec2::ONE);
+        self.repeat_spacing = spacing;
+        self.changed = true;
+        self
+    }
+
+    /// Sets if the [`Rect`]'s Border is composited over its Fill in one
+    /// premultiplied step. Use this when both the Border and Fill are
+    /// translucent so their overlap blends like layered paint.
+    ///
+    pub fn set_border_over_fill(
+        &mut self,
+        border_over_fill: bool,
+    ) -> &mut Self {
+        self.border_over_fill = border_over_fill;
+        self.changed = true;
+        self
+    }
+
+    /// Sets how the [`Rect`] blends with what was rendered beneath it.
+    /// The blend happens at the [`Rect`]'s place in [`DrawOrder`] so it only
+    /// affects what was drawn before it. Use [`CompositeOp::Multiply`] to darken the scene.
+    ///
+    pub fn set_composite_op(&mut self, composite_op: CompositeOp) -> &mut Self {
+        self.composite_op = composite_op;
+        self
+    }
+
     /// Sets the [`Rect`]'s Texture.
-    /// 
+    ///
     pub fn set_texture(
         &mut self,
         renderer: &GpuRenderer,
         atlas: &mut AtlasSet,
         path: String,
     ) -> Result<&mut Self, GraphicsError> {
-        let (id, allocation) =
-            Texture::upload_from_with_alloc(path, atlas, renderer)
+        let (handle, _) =
+            Texture::upload_from_with_handle(path, atlas, renderer)
                 .ok_or_else(|| OtherError::new("failed to upload image"))?;
+        let size = atlas.dimensions(handle.index).unwrap_or_default();
 
-        let rect = allocation.rect();
-
-        self.uv = Vec4::new(0.0, 0.0, rect.2 as f32, rect.3 as f32);
-        self.image = Some(id);
+        self.uv = Vec4::new(0.0, 0.0, size.x, size.y);
+        self.image = Some(handle);
         self.changed = true;
         Ok(self)
     }
 
+    /// Sets the [`Rect`]'s size to its Image's native pixel size so it is
+    /// shown at 1:1. Trimmed Images use their untrimmed size.
+    /// Does nothing if the [`Rect`] has no Image or it is no longer loaded.
+    ///
+    pub fn fit_to_texture(&mut self, atlas: &AtlasSet) -> &mut Self {
+        let handle = match self.image {
+            Some(handle) if atlas.contains_handle(handle) => handle,
+            _ => return self,
+        };
+
+        if let Some(size) = atlas.dimensions(handle.index) {
+            self.size = size;
+            self.changed = true;
+        }
+
+        self
+    }
+
+    /// Returns if the [`Rect`]'s Image is still loaded within the [`AtlasSet`].
+    /// Returns false if the Image was removed or evicted so it can be uploaded
+    /// again, or if the [`Rect`] has no Image.
+    ///
+    pub fn texture_resident(&self, atlas: &AtlasSet) -> bool {
+        self.image
+            .is_some_and(|handle| atlas.contains_handle(handle))
+    }
+
     /// Sets the [`Rect`]'s Texture X,Y, W, H details.
-    /// 
+    ///
     pub fn set_container_uv(&mut self, uv: Vec4) -> &mut Self {
         self.uv = uv;
         self.changed = true;
         self
     }
 
+    /// Updates the [`Rect`]'s rendering layer.
+    ///
+    pub fn set_render_layer(&mut self, render_layer: u32) -> &mut Self {
+        self.render_layer = render_layer;
+        self.changed = true;
+        self
+    }
+
+    /// Updates the [`Rect`]'s stacking index within its rendering layer.
+    /// Higher values render above lower ones regardless of position.z.
+    ///
+    pub fn set_z_index(&mut self, z_index: i32) -> &mut Self {
+        self.z_index = z_index;
+        self.changed = true;
+        self
+    }
+
+    /// Updates the [`Rect`]'s rendering layer to a named layer.
+    /// The layer gets registered if it was not already.
+    /// See [`GpuRenderer::register_layer`].
+    ///
+    pub fn set_render_layer_by_name(
+        &mut self,
+        renderer: &mut GpuRenderer,
+        layer_name: &str,
+    ) -> &mut Self {
+        let render_layer = renderer.register_layer(layer_name);
+
+        self.set_render_layer(render_layer)
+    }
+
     /// Sets the [`Rect`]'s Position.
-    /// 
+    ///
     pub fn set_position(&mut self, position: Vec3) -> &mut Self {
         self.position = position;
         self.changed = true;
@@ -135,15 +675,43 @@ impl Rect {
     }
 
     /// Sets the [`Rect`]'s Width and Height.
-    /// 
+    /// Negative sizes are clamped to zero. A zero sized [`Rect`] is not rendered.
+    ///
     pub fn set_size(&mut self, size: Vec2) -> &mut Self {
-        self.size = size;
+        self.size = size.max(Vec2::ZERO);
+        self.changed = true;
+        self
+    }
+
+    /// Moves the [`Rect`] to the nearest corner of a layout grid of cell
+    /// sized cells starting at origin such as when placing it in an editor.
+    /// Axes whose cell size is zero or less are left as is.
+    ///
+    pub fn snap_to_grid(&mut self, cell: Vec2, origin: Vec2) -> &mut Self {
+        let position = self.position.truncate();
+        let snapped = origin + ((position - origin) / cell).round() * cell;
+
+        self.position.x = if cell.x > 0.0 { snapped.x } else { position.x };
+        self.position.y = if cell.y > 0.0 { snapped.y } else { position.y };
+        self.changed = true;
+        self
+    }
+
+    /// Rounds the [`Rect`]'s Width and Height to the nearest multiple of
+    /// cell keeping at least one cell. Use along with [`Rect::snap_to_grid`].
+    /// Axes whose cell size is zero or less are left as is.
+    ///
+    pub fn snap_size_to_grid(&mut self, cell: Vec2) -> &mut Self {
+        let snapped = (self.size / cell).round().max(Vec2::ONE) * cell;
+
+        self.size.x = if cell.x > 0.0 { snapped.x } else { self.size.x };
+        self.size.y = if cell.y > 0.0 { snapped.y } else { self.size.y };
         self.changed = true;
         self
     }
 
     /// Sets the [`Rect`]'s Border Width.
-    /// 
+    ///
     pub fn set_border_width(&mut self, size: f32) -> &mut Self {
         self.border_width = size;
         self.changed = true;
@@ -151,103 +719,1120 @@ impl Rect {
     }
 
     /// Sets the [`Rect`]'s Corner Radius.
-    /// 
+    ///
     pub fn set_radius(&mut self, radius: f32) -> &mut Self {
         self.radius = radius;
         self.changed = true;
         self
     }
 
-    /// Updates the [`Rect`]'s Buffers to prepare them for rendering.
+    /// Sets if the [`Rect`] is drawn as a pill with fully rounded ends.
+    /// The Radius is derived as half the shorter side whenever it is built
+    /// so toggles and tags stay a perfect pill at any size.
     ///
-    pub fn create_quad(
-        &mut self,
-        renderer: &mut GpuRenderer,
-        atlas: &mut AtlasSet,
-    ) {
-        let (uv, layer) = if let Some(id) = self.image {
-            let tex = match atlas.get(id) {
-                Some(tex) => tex,
-                None => return,
-            };
-            let (u, v, width, height) = tex.rect();
-            (
-                [
-                    self.uv.x + u as f32,
-                    self.uv.y + v as f32,
-                    self.uv.z.min(width as f32),
-                    self.uv.w.min(height as f32),
-                ],
-                tex.layer as u32,
-            )
-        } else {
-            ([0.0, 0.0, 0.0, 0.0], 0)
-        };
-
-        let instance = RectVertex {
-            position: self.position.to_array(),
-            size: self.size.to_array(),
-            border_width: self.border_width,
-            radius: self.radius,
-            uv,
-            layer,
-            color: self.color.0,
-            border_color: self.border_color.0,
-            camera_type: self.camera_type as u32,
-        };
+    pub fn set_pill(&mut self, pill: bool) -> &mut Self {
+        self.pill = pill;
+        self.changed = true;
+        self
+    }
 
-        if let Some(store) = renderer.get_buffer_mut(self.store_id) {
-            let bytes = bytemuck::bytes_of(&instance);
-            store.store.resize_with(bytes.len(), || 0);
-            store.store.copy_from_slice(bytes);
-            store.changed = true;
+    /// Returns the Corner Radius the [`Rect`] renders and hit tests with.
+    /// Half the shorter side while [`Rect::set_pill`] is set.
+    ///
+    pub fn corner_radius(&self) -> f32 {
+        if self.pill {
+            self.size.min_element() * 0.5
+        } else {
+            self.radius
         }
+    }
 
-        self.order = DrawOrder::new(
-            self.radius > 0.0,
-            &self.position,
-            self.render_layer,
-        );
+    /// Sets the superellipse exponent of the [`Rect`]'s rounded corners.
+    /// 2.0 gives the default circular corners while around 5.0 gives an iOS
+    /// style squircle. Clamped to 2.0 through 10.0.
+    ///
+    pub fn set_corner_smoothness(&mut self, n: f32) -> &mut Self {
+        self.corner_smoothness = n.clamp(2.0, 10.0);
+        self.changed = true;
+        self
     }
 
-    /// Used to check and update the vertex array.
-    /// Returns a [`OrderedIndex`] used in Rendering.
+    /// Sets how many segments each squircle corner is approximated with in
+    /// [`Rect::check_mouse_bounds`]. Fewer segments are faster but less
+    /// accurate for hot input loops. None uses the exact formula.
+    /// Does not need the [`Rect`] to be rebuilt.
     ///
-    pub fn update(
+    pub fn set_hit_test_precision(
         &mut self,
-        renderer: &mut GpuRenderer,
-        atlas: &mut AtlasSet,
-    ) -> OrderedIndex {
-        // if points added or any data changed recalculate paths.
-        if self.changed {
-            self.create_quad(renderer, atlas);
-            self.changed = false;
-        }
-
-        OrderedIndex::new_with_bounds(
-            self.order,
-            self.store_id,
-            0,
-            self.bounds,
-            self.camera_type,
-        )
+        segments: Option<u32>,
+    ) -> &mut Self {
+        self.hit_test_precision = segments.map(|segments| segments.max(1));
+        self
     }
 
-    /// Checks if the Mouse position is within the Rects location.
-    /// 
-    pub fn check_mouse_bounds(&self, mouse_pos: Vec2) -> bool {
-        if self.radius > 0.0 {
-            let pos = [self.position.x, self.position.y];
+    /// Sets an additive halo that glows around the [`Rect`]'s shape.
+    /// The halo is rendered by [`crate::RectRenderer`] in an additive pass.
+    ///
+    /// # Arguments
+    /// - color: Color of the halo.
+    /// - intensity: Brightness the halo is added with.
+    /// - radius: How far the halo reaches out from the [`Rect`]'s edge.
+    ///
+    pub fn set_glow(
+        &mut self,
+        color: Color,
+        intensity: f32,
+        radius: f32,
+    ) -> &mut Self {
+        self.glow = Some(RectGlow::new(color, intensity, radius));
+        self.changed = true;
+        self
+    }
 
-            let inner_size = [
-                self.size.x - self.radius * 2.0,
-                self.size.y - self.radius * 2.0,
-            ];
-            let top_left = [pos[0] + self.radius, pos[1] + self.radius];
-            let bottom_right =
-                [top_left[0] + inner_size[0], top_left[1] + inner_size[1]];
+    /// Removes the [`Rect`]'s glow halo.
+    ///
+    pub fn clear_glow(&mut self) -> &mut Self {
+        self.glow = None;
+        self.changed = true;
+        self
+    }
 
-            let top_left_distance =
+    /// Sets the [`Rect`]'s Fill [`Gradient`]. Replaces the Color while set.
+    ///
+    /// # Arguments
+    /// - start: Color the Gradient starts with.
+    /// - end: Color the Gradient ends with.
+    /// - angle: Direction in radians. 0.0 goes from left to right.
+    ///
+    pub fn set_gradient(
+        &mut self,
+        start: Color,
+        end: Color,
+        angle: f32,
+    ) -> &mut Self {
+        self.gradient = Some(Gradient::new(start, end, angle));
+        self.changed = true;
+        self
+    }
+
+    /// Removes the [`Rect`]'s Fill [`Gradient`] so it uses its Color again.
+    ///
+    pub fn clear_gradient(&mut self) -> &mut Self {
+        self.gradient = None;
+        self.changed = true;
+        self
+    }
+
+    /// Sets the [`GradientQuality`] of the [`Rect`]'s Fill [`Gradient`].
+    /// [`GradientQuality::High`] dithers and tessellates large Gradients to
+    /// avoid banding.
+    ///
+    pub fn set_gradient_quality(
+        &mut self,
+        quality: GradientQuality,
+    ) -> &mut Self {
+        self.gradient_quality = quality;
+        self.changed = true;
+        self
+    }
+
+    /// Sets how the [`Rect`]'s Image is combined with its Fill Color or
+    /// [`Gradient`] so a textured surface can be shaded by a Gradient.
+    /// [`FillCombine::Multiply`] is used by default.
+    ///
+    pub fn set_fill_combine(&mut self, combine: FillCombine) -> &mut Self {
+        self.fill_combine = combine;
+        self.changed = true;
+        self
+    }
+
+    /// Sets the [`Rect`]'s Border [`Gradient`]. Replaces the Border Color while set.
+    /// Applies independently of the Fill and follows the radius around the corners.
+    ///
+    /// # Arguments
+    /// - start: Color the Gradient starts with.
+    /// - end: Color the Gradient ends with.
+    /// - angle: Direction in radians. 0.0 goes from left to right.
+    ///
+    pub fn set_border_gradient(
+        &mut self,
+        start: Color,
+        end: Color,
+        angle: f32,
+    ) -> &mut Self {
+        self.border_gradient = Some(Gradient::new(start, end, angle));
+        self.changed = true;
+        self
+    }
+
+    /// Removes the [`Rect`]'s Border [`Gradient`] so it uses its Border Color again.
+    ///
+    pub fn clear_border_gradient(&mut self) -> &mut Self {
+        self.border_gradient = None;
+        self.changed = true;
+        self
+    }
+
+    /// Sets a [`CheckerBackground`] drawn within the [`Rect`] behind its Image
+    /// so transparent areas can be seen. The Image tinted by the Color is drawn
+    /// over it, otherwise it replaces the Color. Replaces the Fill [`Gradient`]
+    /// while set and follows the radius around the corners.
+    ///
+    /// # Arguments
+    /// - size: Width and Height of each cell in pixels.
+    /// - color_a: Color of the cell at the bottom left corner.
+    /// - color_b: Color of the cells next to it.
+    ///
+    pub fn set_checker_background(
+        &mut self,
+        size: f32,
+        color_a: Color,
+        color_b: Color,
+    ) -> &mut Self {
+        self.checker_background =
+            Some(CheckerBackground::new(size, color_a, color_b));
+        self.changed = true;
+        self
+    }
+
+    /// Removes the [`Rect`]'s [`CheckerBackground`].
+    ///
+    pub fn clear_checker_background(&mut self) -> &mut Self {
+        self.checker_background = None;
+        self.changed = true;
+        self
+    }
+
+    /// Sets the fraction of the [`Rect`] that is shown for bars and cooldowns.
+    /// The fill, border, texture and radius are all revealed together.
+    ///
+    /// # Arguments
+    /// - progress: Fraction 0.0 to 1.0 to show. 1.0 shows the whole Rect.
+    /// - mode: [`ProgressMode`] used to reveal the fraction.
+    ///
+    pub fn set_progress(
+        &mut self,
+        progress: f32,
+        mode: ProgressMode,
+    ) -> &mut Self {
+        self.progress = progress.clamp(0.0, 1.0);
+        self.progress_mode = mode;
+        self.changed = true;
+        self
+    }
+
+    /// Sets the [`Rect`]'s Gradient Map ramp used to recolor its Image.
+    /// The Image's luminance picks the Color from the ramp so one grayscale
+    /// Image can be themed to any palette. Paused while a Texture cross fade
+    /// runs as it shares its fields, see [`Rect::begin_texture_crossfade`].
+    ///
+    /// # Arguments
+    /// - ramp_id: [`AtlasSet`] index of the ramp Texture within the same [`AtlasSet`] as the Image.
+    ///
+    pub fn set_gradient_map(&mut self, ramp_id: usize) -> &mut Self {
+        self.gradient_map = Some(ramp_id);
+        self.changed = true;
+        self
+    }
+
+    /// Sets a quarter frame Texture drawn mirrored into all four corners of
+    /// the [`Rect`]. Its last column and row are stretched along the edges
+    /// and its bottom right pixel fills the center, so a symmetric frame
+    /// only needs its top left corner. Replaces the Image while set.
+    ///
+    /// # Arguments
+    /// - corner_id: [`AtlasSet`] index of the top left corner Texture.
+    ///
+    pub fn set_quarter_frame(&mut self, corner_id: usize) -> &mut Self {
+        self.quarter_frame = Some(corner_id);
+        self.changed = true;
+        self
+    }
+
+    /// Removes the [`Rect`]'s quarter frame Texture.
+    ///
+    pub fn clear_quarter_frame(&mut self) -> &mut Self {
+        self.quarter_frame = None;
+        self.changed = true;
+        self
+    }
+
+    /// Removes the [`Rect`]'s Gradient Map so the Image uses its own Colors again.
+    ///
+    pub fn clear_gradient_map(&mut self) -> &mut Self {
+        self.gradient_map = None;
+        self.changed = true;
+        self
+    }
+
+    /// Updates the [`Rect`]'s Buffers to prepare them for rendering.
+    ///
+    pub fn create_quad(
+        &mut self,
+        renderer: &mut GpuRenderer,
+        atlas: &mut AtlasSet,
+    ) {
+        // Zero sized Rects are treated as hidden to avoid NaNs within the shader.
+        if self.is_zero_sized() {
+            self.clear_store(renderer);
+            return;
+        }
+
+        if let Some(crossfade) = self.crossfade {
+            if crossfade.is_finished() {
+                self.finish_crossfade(atlas, crossfade.to);
+            }
+        }
+
+        let (mut position, mut size) = (self.position, self.size);
+
+        let quarter_frame = self.quarter_frame.and_then(|id| atlas.get(id));
+        // The Texture fading out is drawn as the Image while cross fading.
+        let image = self
+            .crossfade
+            .and_then(|crossfade| atlas.handle(crossfade.from))
+            .or(self.image);
+
+        let (uv, layer, overflow) = if let Some(corner) = quarter_frame {
+            let (u, v, width, height) = corner.rect();
+
+            (
+                [u as f32, v as f32, width as f32, height as f32],
+                corner.layer as u32,
+                Vec::new(),
+            )
+        } else if let Some(handle) = image {
+            let tex = match atlas.get_by_handle(handle) {
+                Some(tex) => tex,
+                None => {
+                    // The texture got removed so we clear the store to avoid
+                    // rendering whatever got loaded into its old location.
+                    self.clear_store(renderer);
+                    return;
+                }
+            };
+            self.image_revision = atlas.revision(handle.index);
+            let (u, v, width, height) = tex.rect();
+            let to_uv = |src: Vec4| {
+                let uv = [
+                    src.x + u as f32,
+                    src.y + v as f32,
+                    src.z.min(width as f32),
+                    src.w.min(height as f32),
+                ];
+
+                // Scrolling already wraps its samples within the region.
+                if self.uv_inset && self.uv_scroll == Vec2::ZERO {
+                    Self::inset_uv(uv)
+                } else {
+                    uv
+                }
+            };
+            let (src, overflow) = match atlas.trim_offset(handle.index) {
+                Some(trim) => match self.trimmed_area(trim) {
+                    Some((trim_position, trim_size, src)) => {
+                        position = trim_position;
+                        size = trim_size;
+                        (src, Vec::new())
+                    }
+                    None => {
+                        // Only the trimmed away transparent area is shown.
+                        self.clear_store(renderer);
+                        return;
+                    }
+                },
+                None => {
+                    match self.overflow_areas(width as f32, height as f32) {
+                        Some(mut areas) if !areas.is_empty() => {
+                            let (area_position, area_size, src) =
+                                areas.remove(0);
+
+                            position = area_position;
+                            size = area_size;
+                            (src, areas)
+                        }
+                        Some(_) => {
+                            // The UV's lie fully outside of the Image.
+                            self.clear_store(renderer);
+                            return;
+                        }
+                        None => (self.uv, Vec::new()),
+                    }
+                }
+            };
+            let overflow: Vec<(Vec3, Vec2, [f32; 4])> = overflow
+                .into_iter()
+                .map(|(area_position, area_size, src)| {
+                    (area_position, area_size, to_uv(src))
+                })
+                .collect();
+
+            (to_uv(src), tex.layer as u32, overflow)
+        } else {
+            ([0.0, 0.0, 0.0, 0.0], 0, Vec::new())
+        };
+
+        let mut flags = 0;
+
+        if quarter_frame.is_some() {
+            flags |= RECT_FLAG_QUARTER_FRAME;
+        }
+
+        let (gradient, gradient_angle) =
+            match (self.checker_background, self.gradient) {
+                // The checker reuses the Fill Gradient fields as they never apply together.
+                (Some(checker), _) => {
+                    flags |= RECT_FLAG_CHECKER;
+                    ([checker.color_a.0, checker.color_b.0], checker.size)
+                }
+                (None, Some(gradient)) => {
+                    flags |= RECT_FLAG_GRADIENT;
+
+                    if self.gradient_quality == GradientQuality::High {
+                        flags |= RECT_FLAG_DITHER;
+                    }
+
+                    ([gradient.start.0, gradient.end.0], gradient.angle)
+                }
+                (None, None) => ([0; 2], 0.0),
+            };
+
+        let (border_gradient, border_gradient_angle) =
+            match self.border_gradient {
+                Some(gradient) => {
+                    flags |= RECT_FLAG_BORDER_GRADIENT;
+                    ([gradient.start.0, gradient.end.0], gradient.angle)
+                }
+                None => ([0; 2], 0.0),
+            };
+
+        let smoothness = ((self.corner_smoothness.clamp(2.0, 10.0) - 2.0) / 8.0
+            * RECT_CORNER_MASK as f32)
+            .round() as u32;
+        flags |= smoothness << RECT_CORNER_SHIFT;
+
+        if self.border_over_fill {
+            flags |= RECT_FLAG_BORDER_OVER_FILL;
+        }
+
+        flags |= self.fill_combine.flags();
+
+        if self.progress < 1.0 {
+            let progress =
+                (self.progress * RECT_PROGRESS_MAX as f32).round() as u32;
+
+            flags |=
+                self.progress_mode.flag() | (progress << RECT_PROGRESS_SHIFT);
+        }
+
+        let crossfade = match quarter_frame {
+            Some(_) => None,
+            None => self.crossfade_target(atlas, image),
+        };
+
+        // The cross fade packs into the Gradient Map and UV scroll fields.
+        let gradient_map = match self.gradient_map.and_then(|id| atlas.get(id))
+        {
+            Some(ramp) if self.image.is_some() && crossfade.is_none() => {
+                flags |= RECT_FLAG_GRADIENT_MAP;
+
+                let (u, v, width, _) = ramp.rect();
+                [
+                    (u & 0xffff) | ((v & 0xffff) << 16),
+                    (width & 0xffff) | ((ramp.layer as u32 & 0xffff) << 16),
+                ]
+            }
+            _ => [0, crossfade.map_or(0, |(_, target, _)| target)],
+        };
+
+        let camera_type = self
+            .camera_id
+            .map(|id| id.shader_id())
+            .unwrap_or(self.camera_type as u32);
+
+        let (clip, clip_feather) = match self.bounds {
+            Some(bounds) if self.clip_feather > 0.0 => (
+                [bounds.left, bounds.bottom, bounds.right, bounds.top],
+                ((self.clip_feather * 16.0).round() as u32)
+                    .min(RECT_CLIP_FEATHER_MAX),
+            ),
+            _ => ([0.0; 4], 0),
+        };
+
+        let layer = (layer & RECT_LAYER_MASK)
+            | ((camera_type & RECT_CAMERA_MASK) << RECT_CAMERA_SHIFT)
+            | (clip_feather << RECT_CLIP_FEATHER_SHIFT);
+
+        let content_inset = self.content_inset.to_array().map(|inset| {
+            ((inset * 16.0).round() as u32).min(RECT_CONTENT_INSET_MAX)
+        });
+
+        let instance = RectVertex {
+            position: position.to_array(),
+            size: size.to_array(),
+            parallax: self.parallax.to_array(),
+            border_width: self.border_width,
+            radius: self.corner_radius(),
+            distance_fade: self
+                .distance_fade
+                .map(|fade| fade.to_array())
+                .unwrap_or_default(),
+            uv,
+            layer,
+            color: self.color.0,
+            content_inset: [
+                content_inset[0] | (content_inset[1] << 16),
+                content_inset[2] | (content_inset[3] << 16),
+            ],
+            border_color: self.border_color.0,
+            crossfade_bounds: crossfade.map_or([0; 2], |(_, _, bounds)| bounds),
+            gradient,
+            gradient_angle,
+            flags,
+            border_gradient,
+            border_gradient_angle,
+            gradient_map,
+            uv_scroll: self.uv_scroll.to_array(),
+            clip,
+        };
+
+        let mut instances = self.repeat_instances(instance);
+
+        // Each wrapped piece of the Image is drawn as its own Instance.
+        for (area_position, area_size, uv) in &overflow {
+            instances.extend(self.repeat_instances(RectVertex {
+                position: area_position.to_array(),
+                size: area_size.to_array(),
+                uv: *uv,
+                ..instance
+            }));
+        }
+
+        // Each piece samples the same area of the Texture fading in.
+        if let Some((offset, _, _)) = crossfade {
+            for instance in instances.iter_mut() {
+                instance.uv_scroll =
+                    [instance.uv[0] + offset.x, instance.uv[1] + offset.y];
+            }
+        }
+
+        let instances = self.tessellate_instances(instances);
+
+        if let Some(store) = renderer.get_buffer_mut(self.store_id) {
+            store.set_store(bytemuck::cast_slice(&instances));
+        }
+
+        self.create_glow(renderer, &instance);
+
+        self.order = DrawOrder::new(
+            self.corner_radius() > 0.0,
+            &self.position,
+            self.render_layer,
+        )
+        .with_z_index(self.z_index);
+    }
+
+    /// Marks the [`Rect`] as clean so [`Rect::update`] will not rebuild its buffer.
+    /// Use after the buffer was synced externally.
+    ///
+    pub fn mark_clean(&mut self) -> &mut Self {
+        self.changed = false;
+        self
+    }
+
+    /// Marks the [`Rect`] as changed forcing [`Rect::update`] to rebuild its buffer.
+    /// Use after the atlas remaps the texture it uses.
+    ///
+    pub fn mark_dirty(&mut self) -> &mut Self {
+        self.changed = true;
+        self
+    }
+
+    /// Marks every [`Rect`] given as clean.
+    ///
+    pub fn mark_all_clean<'a>(rects: impl IntoIterator<Item = &'a mut Rect>) {
+        rects.into_iter().for_each(|rect| {
+            rect.mark_clean();
+        });
+    }
+
+    /// Marks every [`Rect`] given as changed.
+    ///
+    pub fn mark_all_dirty<'a>(rects: impl IntoIterator<Item = &'a mut Rect>) {
+        rects.into_iter().for_each(|rect| {
+            rect.mark_dirty();
+        });
+    }
+
+    /// Checks if the [`Rect`] overlaps another [`Rect`].
+    /// Rounded corners are taken into account so Rects whose corners only
+    /// come close do not count. Both Rects must be within the same space.
+    ///
+    pub fn intersects(&self, other: &Rect) -> bool {
+        if self.is_zero_sized() || other.is_zero_sized() {
+            return false;
+        }
+
+        let (a_min, a_max) = (
+            self.position.truncate(),
+            self.position.truncate() + self.size,
+        );
+        let (b_min, b_max) = (
+            other.position.truncate(),
+            other.position.truncate() + other.size,
+        );
+
+        if a_min.x >= b_max.x
+            || b_min.x >= a_max.x
+            || a_min.y >= b_max.y
+            || b_min.y >= a_max.y
+        {
+            return false;
+        }
+
+        let a_radius = self
+            .corner_radius()
+            .clamp(0.0, self.size.min_element() * 0.5);
+        let b_radius = other
+            .corner_radius()
+            .clamp(0.0, other.size.min_element() * 0.5);
+
+        if a_radius <= 0.0 && b_radius <= 0.0 {
+            return true;
+        }
+
+        // A rounded Rect is its inner Rect grown by its radius so they overlap
+        // when their inner Rects are within both radii of each other.
+        let gap = (a_min + a_radius).max(b_min + b_radius)
+            - (a_max - a_radius).min(b_max - b_radius);
+
+        gap.max(Vec2::ZERO).length_squared()
+            < (a_radius + b_radius) * (a_radius + b_radius)
+    }
+
+    /// Returns the axis aligned [`Bounds`] the [`Rect`] and its repeated
+    /// copies cover.
+    ///
+    pub fn aabb(&self) -> Bounds {
+        let last = (self.repeat_count - Vec2::ONE) * self.repeat_spacing;
+        let min = last.min(Vec2::ZERO);
+        let max = last.max(Vec2::ZERO) + self.size;
+
+        Bounds::new(
+            self.position.x + min.x,
+            self.position.y + min.y,
+            self.position.x + max.x,
+            self.position.y + max.y,
+        )
+    }
+
+    /// Returns if the [`Rect`] has no Width or Height and would not be Rendered.
+    ///
+    pub fn is_zero_sized(&self) -> bool {
+        self.size.x <= 0.0 || self.size.y <= 0.0
+    }
+
+    /// Checks the [`Rect`] for misconfigurations that would show up as
+    /// visual glitches such as a radius larger than half of its size.
+    /// Returns every [`RectWarning`] found. Meant as a debugging aid such as
+    /// asserting Rects are well formed within debug builds.
+    ///
+    pub fn validate(&self, atlas: &AtlasSet) -> Result<(), Vec<RectWarning>> {
+        let mut warnings = Vec::new();
+
+        if !self.position.is_finite() || !self.size.is_finite() {
+            warnings.push(RectWarning::NonFinite);
+        }
+
+        if self.border_width < 0.0 {
+            warnings.push(RectWarning::NegativeBorderWidth(self.border_width));
+        }
+
+        let half_size = self.size.min_element() * 0.5;
+
+        if !self.is_zero_sized() {
+            if self.border_width > half_size {
+                warnings.push(RectWarning::BorderExceedsHalfSize {
+                    width: self.border_width,
+                    half_size,
+                });
+            }
+
+            if !self.pill && self.radius > half_size {
+                warnings.push(RectWarning::RadiusExceedsHalfSize {
+                    radius: self.radius,
+                    half_size,
+                });
+            }
+        }
+
+        let conflicts = [
+            (
+                self.checker_background.is_some() && self.gradient.is_some(),
+                RectFeature::Checker,
+                RectFeature::Gradient,
+            ),
+            (
+                self.crossfade.is_some() && self.gradient_map.is_some(),
+                RectFeature::Crossfade,
+                RectFeature::GradientMap,
+            ),
+            (
+                self.crossfade.is_some() && self.uv_scroll != Vec2::ZERO,
+                RectFeature::Crossfade,
+                RectFeature::UvScroll,
+            ),
+        ];
+
+        for (_, kept, dropped) in conflicts.into_iter().filter(|c| c.0) {
+            warnings.push(RectWarning::FeatureConflict { kept, dropped });
+        }
+
+        if let Some(handle) = self.image {
+            match atlas
+                .dimensions(handle.index)
+                .filter(|_| atlas.contains_handle(handle))
+            {
+                Some(size) => {
+                    let outside = self.uv.x < 0.0
+                        || self.uv.y < 0.0
+                        || self.uv.x + self.uv.z > size.x
+                        || self.uv.y + self.uv.w > size.y;
+
+                    if outside && self.uv_overflow == UvOverflow::Clamp {
+                        warnings.push(RectWarning::UvOutsideImage {
+                            uv: self.uv.to_array(),
+                            size: size.to_array(),
+                        });
+                    }
+                }
+                None => warnings.push(RectWarning::MissingImage),
+            }
+        }
+
+        if warnings.is_empty() {
+            Ok(())
+        } else {
+            Err(warnings)
+        }
+    }
+
+    //Maps the uv from the untrimmed Image onto the trimmed Image.
+    //Returns the shrunk position, size and uv or None if nothing visible is left.
+    fn trimmed_area(&self, trim: TrimOffset) -> Option<(Vec3, Vec2, Vec4)> {
+        self.uv_area(Vec4::new(
+            trim.x as f32,
+            trim.y as f32,
+            trim.width as f32,
+            trim.height as f32,
+        ))
+    }
+
+    //Returns the areas of the Rect showing the Image by the UvOverflow along
+    //with their UV's within the Image. None when the UV's are used as is.
+    fn overflow_areas(
+        &self,
+        width: f32,
+        height: f32,
+    ) -> Option<Vec<(Vec3, Vec2, Vec4)>> {
+        if self.uv_scroll != Vec2::ZERO || width <= 0.0 || height <= 0.0 {
+            return None;
+        }
+
+        match self.uv_overflow {
+            UvOverflow::Clamp => None,
+            UvOverflow::Discard => Some(
+                self.uv_area(Vec4::new(0.0, 0.0, width, height))
+                    .into_iter()
+                    .collect(),
+            ),
+            UvOverflow::Wrap => {
+                let columns = (self.uv.x / width).floor() as i64
+                    ..((self.uv.x + self.uv.z) / width).ceil() as i64;
+                let rows = (self.uv.y / height).floor() as i64
+                    ..((self.uv.y + self.uv.w) / height).ceil() as i64;
+
+                Some(
+                    rows.flat_map(|row| {
+                        columns.clone().filter_map(move |column| {
+                            self.uv_area(Vec4::new(
+                                column as f32 * width,
+                                row as f32 * height,
+                                width,
+                                height,
+                            ))
+                        })
+                    })
+                    .collect(),
+                )
+            }
+        }
+    }
+
+    //Returns the position, size and UV's relative to the area of the part of
+    //the Rect whose UV's fall within the area.
+    fn uv_area(&self, area: Vec4) -> Option<(Vec3, Vec2, Vec4)> {
+        let scale = Vec2::new(
+            if self.uv.z > 0.0 {
+                self.size.x / self.uv.z
+            } else {
+                0.0
+            },
+            if self.uv.w > 0.0 {
+                self.size.y / self.uv.w
+            } else {
+                0.0
+            },
+        );
+        let left = self.uv.x.max(area.x);
+        let top = self.uv.y.max(area.y);
+        let right = (self.uv.x + self.uv.z).min(area.x + area.z);
+        let bottom = (self.uv.y + self.uv.w).min(area.y + area.w);
+
+        if right <= left || bottom <= top {
+            return None;
+        }
+
+        // Texture rows go down while the Rect goes up so the bottom is offset.
+        let position = Vec3::new(
+            self.position.x + (left - self.uv.x) * scale.x,
+            self.position.y + (self.uv.y + self.uv.w - bottom) * scale.y,
+            self.position.z,
+        );
+        let size =
+            Vec2::new((right - left) * scale.x, (bottom - top) * scale.y);
+        let uv =
+            Vec4::new(left - area.x, top - area.y, right - left, bottom - top);
+
+        Some((position, size, uv))
+    }
+
+    //Empties the Store so nothing is Rendered for this Rect.
+    fn clear_store(&self, renderer: &mut GpuRenderer) {
+        if let Some(store) = renderer.get_buffer_mut(self.store_id) {
+            store.store.clear();
+            store.changed = true;
+        }
+
+        self.clear_glow_store(renderer);
+    }
+
+    //Clears the glow halo's store so it stops rendering.
+    fn clear_glow_store(&self, renderer: &mut GpuRenderer) {
+        if let Some(store) = self
+            .glow_store_id
+            .and_then(|glow_store_id| renderer.get_buffer_mut(glow_store_id))
+        {
+            store.store.clear();
+            store.changed = true;
+        }
+    }
+
+    //Builds the glow halo Instance by expanding the Rect's Instance by the glow radius.
+    fn create_glow(&mut self, renderer: &mut GpuRenderer, rect: &RectVertex) {
+        let glow = match self.glow {
+            Some(glow) if glow.radius > 0.0 && glow.intensity > 0.0 => glow,
+            _ => {
+                self.clear_glow_store(renderer);
+                return;
+            }
+        };
+
+        let glow_store_id = *self.glow_store_id.get_or_insert_with(|| {
+            let rect_size = bytemuck::bytes_of(&RectVertex::default()).len();
+
+            renderer.new_buffer(rect_size, 0)
+        });
+
+        // The shader reads the glow radius from border_width and the
+        // intensity from gradient_angle as the halo uses neither.
+        let instance = RectVertex {
+            position: [
+                rect.position[0] - glow.radius,
+                rect.position[1] - glow.radius,
+                rect.position[2],
+            ],
+            size: [
+                rect.size[0] + glow.radius * 2.0,
+                rect.size[1] + glow.radius * 2.0,
+            ],
+            border_width: glow.radius,
+            radius: self.corner_radius(),
+            color: glow.color.0,
+            layer: rect.layer & !RECT_LAYER_MASK,
+            gradient_angle: glow.intensity,
+            clip: rect.clip,
+            parallax: rect.parallax,
+            distance_fade: rect.distance_fade,
+            flags: RECT_FLAG_GLOW
+                | (rect.flags & (RECT_CORNER_MASK << RECT_CORNER_SHIFT)),
+            ..Default::default()
+        };
+
+        if let Some(store) = renderer.get_buffer_mut(glow_store_id) {
+            let instances = self.repeat_instances(instance);
+            store.set_store(bytemuck::cast_slice(&instances));
+        }
+    }
+
+    //Insets the UV's half a texel on each side of axes wider than a texel.
+    fn inset_uv(uv: [f32; 4]) -> [f32; 4] {
+        let (x, width) = if uv[2] > 1.0 {
+            (uv[0] + 0.5, uv[2] - 1.0)
+        } else {
+            (uv[0], uv[2])
+        };
+        let (y, height) = if uv[3] > 1.0 {
+            (uv[1] + 0.5, uv[3] - 1.0)
+        } else {
+            (uv[1], uv[3])
+        };
+
+        [x, y, width, height]
+    }
+
+    //Returns the offset of each repeated copy from the Rect's position.
+    fn repeat_offsets(&self) -> impl Iterator<Item = Vec2> + '_ {
+        let (columns, rows) =
+            (self.repeat_count.x as u32, self.repeat_count.y as u32);
+
+        (0..rows).flat_map(move |row| {
+            (0..columns).map(move |column| {
+                Vec2::new(column as f32, row as f32) * self.repeat_spacing
+            })
+        })
+    }
+
+    //Sets the Image to the Texture faded in once the cross fade finished.
+    fn finish_crossfade(&mut self, atlas: &mut AtlasSet, to: usize) {
+        self.crossfade = None;
+
+        // Trimmed Textures are addressed by their untrimmed size.
+        if let (Some(handle), Some(size)) =
+            (atlas.handle(to), atlas.dimensions(to))
+        {
+            self.image = Some(handle);
+            self.uv = Vec4::new(0.0, 0.0, size.x, size.y);
+        }
+    }
+
+    //Returns the offset from the Image's atlas position to the same
+    //untrimmed area of the Texture fading in, along with its Layer and mix
+    //factor and its atlas bounds packed for the Shader to mix both Textures with.
+    fn crossfade_target(
+        &self,
+        atlas: &mut AtlasSet,
+        image: Option<TextureHandle>,
+    ) -> Option<(Vec2, u32, [u32; 2])> {
+        let crossfade = self.crossfade?;
+        let handle = image?;
+        let from = atlas.get_by_handle(handle)?;
+        let to = atlas.get(crossfade.to)?;
+        let origin = |id: usize, (u, v): (u32, u32)| {
+            let (x, y) = atlas
+                .trim_offset(id)
+                .map_or((0, 0), |trim| (trim.x, trim.y));
+
+            Vec2::new(u as f32 - x as f32, v as f32 - y as f32)
+        };
+        let offset = origin(crossfade.to, to.position())
+            - origin(handle.index, from.position());
+        let mix = (crossfade.progress() * RECT_CROSSFADE_MIX_MAX as f32).round()
+            as u32;
+        let (u, v, width, height) = to.rect();
+
+        Some((
+            offset,
+            RECT_FLAG_CROSSFADE
+                | (mix << RECT_CROSSFADE_MIX_SHIFT)
+                | (to.layer as u32 & RECT_LAYER_MASK),
+            [
+                (u & 0xffff) | ((v & 0xffff) << 16),
+                ((u + width) & 0xffff) | (((v + height) & 0xffff) << 16),
+            ],
+        ))
+    }
+
+    //Splits each Instance into a grid of cells so the Fill Gradient gets
+    //interpolated between more vertices. Only Rects larger than a cell with a
+    //High quality Gradient and no Gradient Map are split.
+    fn tessellate_instances(
+        &self,
+        instances: Vec<RectVertex>,
+    ) -> Vec<RectVertex> {
+        let flags = match instances.first() {
+            Some(instance) => instance.flags,
+            None => return instances,
+        };
+
+        if self.gradient_quality != GradientQuality::High
+            || flags & RECT_FLAG_GRADIENT == 0
+            || flags & RECT_FLAG_GRADIENT_MAP != 0
+        {
+            return instances;
+        }
+
+        let cells = (self.size.max_element() / GRADIENT_CELL_SIZE)
+            .ceil()
+            .clamp(1.0, GRADIENT_MAX_CELLS) as u32;
+
+        if cells <= 1 {
+            return instances;
+        }
+
+        instances
+            .into_iter()
+            .flat_map(|instance| {
+                (0..cells).flat_map(move |row| {
+                    (0..cells).map(move |column| RectVertex {
+                        gradient_map: [
+                            (cells << RECT_GRADIENT_CELLS_SHIFT)
+                                | (column << 8)
+                                | row,
+                            instance.gradient_map[1],
+                        ],
+                        ..instance
+                    })
+                })
+            })
+            .collect()
+    }
+
+    //Copies the Instance once per repeated copy.
+    fn repeat_instances(&self, instance: RectVertex) -> Vec<RectVertex> {
+        self.repeat_offsets()
+            .map(|offset| RectVertex {
+                position: [
+                    instance.position[0] + offset.x,
+                    instance.position[1] + offset.y,
+                    instance.position[2],
+                ],
+                ..instance
+            })
+            .collect()
+    }
+
+    /// Returns the [`OrderedIndex`] of the [`Rect`]'s glow halo if it has one.
+    /// Must be called after [`Rect::update`]. Used by [`crate::RectRenderer::rect_update`].
+    ///
+    pub fn glow_index(&self) -> Option<OrderedIndex> {
+        self.glow?;
+
+        Some(OrderedIndex::new_with_bounds(
+            self.order,
+            self.glow_store_id?,
+            0,
+            self.bounds,
+            if self.camera_id.is_some() {
+                CameraType::None
+            } else {
+                self.camera_type
+            },
+        ))
+    }
+
+    /// Rebuilds the [`Rect`]'s buffer even when nothing changed such as after
+    /// atlas compaction or recovering from a lost device where the buffers
+    /// must be filled again. Also reports damage when tracking it.
+    ///
+    pub fn rebuild(
+        &mut self,
+        renderer: &mut GpuRenderer,
+        atlas: &mut AtlasSet,
+    ) {
+        self.create_quad(renderer, atlas);
+        self.changed = false;
+
+        if renderer.damage_tracking() {
+            let area = self.aabb();
+
+            if let Some(last) = self.damage_bounds.replace(area) {
+                renderer.add_damage(last);
+            }
+
+            renderer.add_damage(area);
+        }
+    }
+
+    /// Used to check and update the vertex array.
+    /// Returns a [`OrderedIndex`] used in Rendering.
+    ///
+    pub fn update(
+        &mut self,
+        renderer: &mut GpuRenderer,
+        atlas: &mut AtlasSet,
+    ) -> OrderedIndex {
+        // if points added or any data changed recalculate paths.
+        // Also rebuild if the atlas moved our image since the last build.
+        let image_moved = self.image.is_some_and(|handle| {
+            atlas.revision(handle.index) != self.image_revision
+        });
+
+        if self.changed || image_moved {
+            self.rebuild(renderer, atlas);
+        }
+
+        OrderedIndex::new_with_bounds(
+            self.order,
+            self.store_id,
+            0,
+            self.bounds,
+            // Registered Cameras bounds are expected to be in Screen space.
+            if self.camera_id.is_some() {
+                CameraType::None
+            } else {
+                self.camera_type
+            },
+        )
+    }
+
+    /// Checks if a Screen position is within the Rects location.
+    /// Converts the position with the Rect's registered [`crate::Camera`] if it
+    /// has one so its [`crate::YAxis`] is respected.
+    ///
+    pub fn check_screen_bounds(
+        &self,
+        renderer: &GpuRenderer,
+        screen_pos: Vec2,
+    ) -> bool {
+        let pos = match self.camera_id {
+            Some(id) => match renderer.screen_to_world(id, screen_pos) {
+                Some(pos) => pos,
+                None => return false,
+            },
+            None => screen_pos,
+        };
+
+        self.check_mouse_bounds(pos)
+    }
+
+    /// Checks if the Mouse position is within the Rects location.
+    /// The Mouse position must be within the same space as the Rect's position.
+    /// Rounded corners are tested with squared distances so no roots are taken.
+    ///
+    pub fn check_mouse_bounds(&self, mouse_pos: Vec2) -> bool {
+        if self.is_zero_sized() {
+            return false;
+        }
+
+        self.repeat_offsets()
+            .any(|offset| self.tile_contains(mouse_pos - offset))
+    }
+
+    //Checks if the Mouse position is within the Rect's first copy.
+    fn tile_contains(&self, mouse_pos: Vec2) -> bool {
+        let radius = self.corner_radius();
+
+        if radius > 0.0 {
+            let pos = [self.position.x, self.position.y];
+
+            let inner_size =
+                [self.size.x - radius * 2.0, self.size.y - radius * 2.0];
+            let top_left = [pos[0] + radius, pos[1] + radius];
+            let bottom_right =
+                [top_left[0] + inner_size[0], top_left[1] + inner_size[1]];
+
+            let top_left_distance =
                 [top_left[0] - mouse_pos.x, top_left[1] - mouse_pos.y];
             let bottom_right_distance =
                 [mouse_pos.x - bottom_right[0], mouse_pos.y - bottom_right[1]];
@@ -257,9 +1842,7 @@ impl Rect {
                 top_left_distance[1].max(bottom_right_distance[1]).max(0.0),
             ];
 
-            let dist = (dist[0] * dist[0] + dist[1] * dist[1]).sqrt();
-
-            dist < self.radius
+            self.corner_contains(dist, radius)
         } else {
             mouse_pos[0] > self.position.x
                 && mouse_pos[0] < self.position.x + self.size.x
@@ -267,4 +1850,49 @@ impl Rect {
                 && mouse_pos[1] < self.position.y + self.size.y
         }
     }
+
+    //Checks if a distance from the inner corner lies within the rounded corner.
+    fn corner_contains(&self, dist: [f32; 2], radius: f32) -> bool {
+        let n = self.corner_smoothness.clamp(2.0, 10.0);
+
+        if n == 2.0 {
+            return dist[0] * dist[0] + dist[1] * dist[1] < radius * radius;
+        }
+
+        let segments = match self.hit_test_precision {
+            Some(segments) => segments.max(1),
+            None => {
+                // Compared raised to n to skip taking the n'th root.
+                return dist[0].powf(n) + dist[1].powf(n) < radius.powf(n);
+            }
+        };
+
+        //Walks the corners polygon to find the segment whose wedge holds the
+        //point then checks which side of the segment it is on.
+        let point = |i: u32| {
+            let angle =
+                i as f32 / segments as f32 * std::f32::consts::FRAC_PI_2;
+            [
+                radius * angle.cos().powf(2.0 / n),
+                radius * angle.sin().powf(2.0 / n),
+            ]
+        };
+        let cross = |a: [f32; 2], b: [f32; 2]| a[0] * b[1] - a[1] * b[0];
+        let mut start = point(0);
+
+        for i in 1..=segments {
+            let end = point(i);
+
+            if cross(dist, end) >= 0.0 {
+                let edge = [end[0] - start[0], end[1] - start[1]];
+                let offset = [dist[0] - start[0], dist[1] - start[1]];
+
+                return cross(edge, offset) > 0.0;
+            }
+
+            start = end;
+        }
+
+        false
+    }
 }
@@ -1,13 +1,50 @@
 use crate::{
-    AtlasSet, GpuRenderer, GraphicsError, InstanceBuffer, OrderedIndex, Rect,
-    RectRenderPipeline, RectVertex, StaticVertexBuffer, System,
+    AsBufferPass, AtlasSet, Bounds, CameraType, CompositeOp, DepthMode,
+    GpuRenderer, GraphicsError, InstanceBuffer, IsolationGroup,
+    IsolationGroupId, Material, MaterialHandle, OrderedIndex, Rect,
+    RectBlendPipeline, RectCompositePipeline, RectGlowPipeline,
+    RectIsolationPipeline, RectMaterial, RectRenderPipeline, RectVertex,
+    SetBuffers, StaticVertexBuffer, System,
 };
+use std::ops::Range;
+
+//Which Instance Buffer and pipeline a sorted Rect draw renders with.
+//Glows come first so a halo sorts just below the Rect sharing its order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum RectDrawKind {
+    Glow,
+    Normal,
+    Material(usize),
+    Composite(usize),
+}
+
+//A run of Instances drawn at its place in DrawOrder with one pipeline.
+struct RectDraw {
+    kind: RectDrawKind,
+    instances: Range<u32>,
+    bounds: Option<Bounds>,
+    camera_type: CameraType,
+}
 
 /// Instance Buffer Setup for [`Rect`]'s.
 ///
 pub struct RectRenderer {
     /// Instance Buffer holding all Rendering information for [`Rect`]'s.
     pub buffer: InstanceBuffer<RectVertex>,
+    /// Custom [`Material`]'s each holding the [`Rect`]'s that use them.
+    pub materials: Vec<RectMaterial>,
+    /// [`IsolationGroup`]'s each holding the [`Rect`]'s rendered within them.
+    pub isolation_groups: Vec<IsolationGroup>,
+    /// Instance Buffer holding the [`crate::RectGlow`] halos rendered additively.
+    pub glows: InstanceBuffer<RectVertex>,
+    /// Instance Buffers of the [`Rect`]'s blended with a non Normal [`CompositeOp`].
+    pub composites: Vec<(CompositeOp, InstanceBuffer<RectVertex>)>,
+    /// Staged [`OrderedIndex`]'s per Buffer Layer along with the Instance
+    /// Buffer they were added to.
+    staged: Vec<Vec<(RectDrawKind, OrderedIndex)>>,
+    /// Draws per Buffer Layer sorted by [`crate::DrawOrder`] across the
+    /// Instance Buffers so each [`Rect`] renders at its place in the order.
+    draws: Vec<Vec<RectDraw>>,
 }
 
 impl RectRenderer {
@@ -16,9 +53,84 @@ impl RectRenderer {
     pub fn new(renderer: &GpuRenderer) -> Result<Self, GraphicsError> {
         Ok(Self {
             buffer: InstanceBuffer::new(renderer.gpu_device(), 512),
+            materials: Vec::new(),
+            isolation_groups: Vec::new(),
+            glows: InstanceBuffer::new(renderer.gpu_device(), 32),
+            composites: CompositeOp::BLENDED
+                .iter()
+                .map(|op| (*op, InstanceBuffer::new(renderer.gpu_device(), 32)))
+                .collect(),
+            staged: Vec::new(),
+            draws: Vec::new(),
         })
     }
 
+    /// Compiles and Adds a Custom [`Material`] returning its [`MaterialHandle`].
+    /// Use [`Rect::set_custom_material`] to render a [`Rect`] with it.
+    ///
+    pub fn add_material(
+        &mut self,
+        renderer: &mut GpuRenderer,
+        material: &Material,
+    ) -> Result<MaterialHandle, GraphicsError> {
+        let mut material = RectMaterial::new(renderer, material)?;
+
+        if self.buffer.is_clipped() {
+            material.buffer.set_as_clipped();
+        }
+
+        self.materials.push(material);
+        Ok(MaterialHandle(self.materials.len() - 1))
+    }
+
+    /// Writes new bytes into a [`Material`]'s uniform.
+    ///
+    pub fn update_material_uniform(
+        &self,
+        renderer: &GpuRenderer,
+        material: MaterialHandle,
+        bytes: &[u8],
+    ) {
+        if let Some(material) = self.materials.get(material.0) {
+            material.update_uniform(renderer, bytes);
+        }
+    }
+
+    /// Adds a [`IsolationGroup`] returning its [`IsolationGroupId`].
+    /// Use [`Rect::set_group_isolation`] to render a [`Rect`] within it.
+    ///
+    /// # Arguments
+    /// - buffer_layer: The Buffer Layer the group is composited within.
+    /// - z: The Z depth the composited image is rendered at.
+    ///
+    pub fn add_isolation_group(
+        &mut self,
+        renderer: &mut GpuRenderer,
+        buffer_layer: usize,
+        z: f32,
+    ) -> IsolationGroupId {
+        let mut group = IsolationGroup::new(renderer, buffer_layer, z);
+
+        if self.buffer.is_clipped() {
+            group.buffer.set_as_clipped();
+        }
+
+        self.isolation_groups.push(group);
+        IsolationGroupId(self.isolation_groups.len() - 1)
+    }
+
+    /// Sets the opacity a [`IsolationGroup`] is composited at.
+    ///
+    pub fn set_isolation_opacity(
+        &mut self,
+        group: IsolationGroupId,
+        opacity: f32,
+    ) {
+        if let Some(group) = self.isolation_groups.get_mut(group.0) {
+            group.opacity = opacity.clamp(0.0, 1.0);
+        }
+    }
+
     /// Adds a Buffer [`OrderedIndex`] to the Rendering Store to get processed.
     /// This must be done before [`RectRenderer::finalize`] but after [`Rect::update`] in order for it to Render.
     ///
@@ -33,13 +145,161 @@ impl RectRenderer {
         buffer_layer: usize,
     ) {
         self.buffer.add_buffer_store(renderer, index, buffer_layer);
+        self.stage(RectDrawKind::Normal, index, buffer_layer);
+    }
+
+    //Stages the OrderedIndex to be sorted into the Buffer Layer's draws.
+    fn stage(
+        &mut self,
+        kind: RectDrawKind,
+        index: OrderedIndex,
+        buffer_layer: usize,
+    ) {
+        if self.staged.len() <= buffer_layer {
+            self.staged.resize_with(buffer_layer + 1, Vec::new);
+        }
+
+        self.staged[buffer_layer].push((kind, index));
+    }
+
+    /// Adds a Buffer [`OrderedIndex`] to a [`Material`]'s Rendering Store to get processed.
+    /// Falls back to the default Rendering Store if the [`MaterialHandle`] does not exist.
+    ///
+    /// # Arguments
+    /// - index: The [`OrderedIndex`] of the Object we want to render.
+    /// - material: The [`MaterialHandle`] the Object renders with.
+    /// - buffer_layer: The Buffer Layer we want to add this Object too.
+    ///
+    pub fn add_material_buffer_store(
+        &mut self,
+        renderer: &GpuRenderer,
+        index: OrderedIndex,
+        material: MaterialHandle,
+        buffer_layer: usize,
+    ) {
+        if let Some(buffer) = self.materials.get_mut(material.0) {
+            buffer
+                .buffer
+                .add_buffer_store(renderer, index, buffer_layer);
+            self.stage(RectDrawKind::Material(material.0), index, buffer_layer);
+        } else {
+            self.add_buffer_store(renderer, index, buffer_layer);
+        }
+    }
+
+    /// Adds a [`crate::RectGlow`] Buffer [`OrderedIndex`] to the glow Rendering Store to get processed.
+    /// This must be done before [`RectRenderer::finalize`] but after [`Rect::update`] in order for it to Render.
+    ///
+    /// # Arguments
+    /// - index: The [`OrderedIndex`] from [`Rect::glow_index`].
+    /// - buffer_layer: The Buffer Layer we want to add this Object too.
+    ///
+    pub fn add_glow_buffer_store(
+        &mut self,
+        renderer: &GpuRenderer,
+        index: OrderedIndex,
+        buffer_layer: usize,
+    ) {
+        self.glows.add_buffer_store(renderer, index, buffer_layer);
+        self.stage(RectDrawKind::Glow, index, buffer_layer);
+    }
+
+    /// Adds a Buffer [`OrderedIndex`] to a [`CompositeOp`]'s Rendering Store to get processed.
+    /// Uses the default Rendering Store for [`CompositeOp::Normal`].
+    ///
+    /// # Arguments
+    /// - index: The [`OrderedIndex`] of the Object we want to render.
+    /// - op: The [`CompositeOp`] the Object blends with.
+    /// - buffer_layer: The Buffer Layer we want to add this Object too.
+    ///
+    pub fn add_composite_buffer_store(
+        &mut self,
+        renderer: &GpuRenderer,
+        index: OrderedIndex,
+        op: CompositeOp,
+        buffer_layer: usize,
+    ) {
+        if let Some(composite) =
+            self.composites.iter().position(|(other, _)| *other == op)
+        {
+            self.composites[composite].1.add_buffer_store(
+                renderer,
+                index,
+                buffer_layer,
+            );
+            self.stage(RectDrawKind::Composite(composite), index, buffer_layer);
+        } else {
+            self.add_buffer_store(renderer, index, buffer_layer);
+        }
     }
 
     /// Finalizes the Buffer by processing staged [`OrderedIndex`]'s and uploading it to the GPU.
     /// Must be called after all the [`RectRenderer::add_buffer_store`]'s.
     ///
     pub fn finalize(&mut self, renderer: &mut GpuRenderer) {
-        self.buffer.finalize(renderer)
+        self.buffer.finalize(renderer);
+        self.glows.finalize(renderer);
+
+        for (_, buffer) in &mut self.composites {
+            buffer.finalize(renderer);
+        }
+
+        for material in &mut self.materials {
+            material.buffer.finalize(renderer);
+        }
+
+        for group in &mut self.isolation_groups {
+            group.buffer.finalize(renderer);
+            group.prepare(renderer);
+        }
+
+        self.sort_draws(renderer);
+    }
+
+    //Sorts the staged OrderedIndex's of each Buffer Layer by DrawOrder into
+    //draws using where finalize placed them within their Instance Buffer.
+    fn sort_draws(&mut self, renderer: &GpuRenderer) {
+        let stride = self.buffer.stride();
+        let clipped = self.buffer.is_clipped();
+
+        self.draws.resize_with(self.staged.len(), Vec::new);
+
+        for (staged, draws) in self.staged.iter_mut().zip(&mut self.draws) {
+            draws.clear();
+            staged.sort_by(|(kind, index), (other_kind, other)| {
+                index.cmp(other).then(kind.cmp(other_kind))
+            });
+
+            for (kind, index) in staged.drain(..) {
+                let range = match renderer.get_buffer(index.index) {
+                    Some(store) if !store.store_pos.is_empty() => {
+                        store.store_pos.clone()
+                    }
+                    _ => continue,
+                };
+                let instances =
+                    (range.start / stride) as u32..(range.end / stride) as u32;
+
+                // Neighbouring draws of one Instance Buffer share a draw call
+                // unless each needs its own scissor.
+                if let Some(last) = draws.last_mut() {
+                    if !clipped
+                        && last.kind == kind
+                        && last.instances.end == instances.start
+                    {
+                        last.instances.end = instances.end;
+                        continue;
+                    }
+                }
+
+                draws.push(RectDraw {
+                    kind,
+                    instances,
+                    bounds: index.bounds,
+                    camera_type: index.camera_type,
+                });
+            }
+        }
     }
 
     /// Updates a [`Rect`] and adds its [`OrderedIndex`] to staging using [`RectRenderer::add_buffer_store`].
@@ -59,7 +319,30 @@ impl RectRenderer {
     ) {
         let index = rect.update(renderer, atlas);
 
-        self.add_buffer_store(renderer, index, buffer_layer);
+        if let Some(group) = rect
+            .isolation_group
+            .and_then(|group| self.isolation_groups.get_mut(group.0))
+        {
+            group.buffer.add_buffer_store(renderer, index, buffer_layer);
+        } else if let Some(material) = rect.material {
+            self.add_material_buffer_store(
+                renderer,
+                index,
+                material,
+                buffer_layer,
+            );
+        } else {
+            self.add_composite_buffer_store(
+                renderer,
+                index,
+                rect.composite_op,
+                buffer_layer,
+            );
+        }
+
+        if let Some(index) = rect.glow_index() {
+            self.add_glow_buffer_store(renderer, index, buffer_layer);
+        }
     }
 
     /// Sets the Instance Buffer to enable Rendering With Scissor Clipping.
@@ -67,6 +350,94 @@ impl RectRenderer {
     ///
     pub fn use_clipping(&mut self) {
         self.buffer.set_as_clipped();
+        self.glows.set_as_clipped();
+
+        for (_, buffer) in &mut self.composites {
+            buffer.set_as_clipped();
+        }
+
+        for material in &mut self.materials {
+            material.buffer.set_as_clipped();
+        }
+
+        for group in &mut self.isolation_groups {
+            group.buffer.set_as_clipped();
+        }
+    }
+
+    /// Renders each [`IsolationGroup`]'s members into its offscreen target.
+    /// Must be called with a new [`wgpu::CommandEncoder`] pass before the
+    /// pass that calls [`RenderRects::render_rects`] so the targets are ready to composite.
+    ///
+    pub fn render_isolation_groups<Controls>(
+        &self,
+        renderer: &GpuRenderer,
+        encoder: &mut wgpu::CommandEncoder,
+        atlas: &AtlasSet,
+        system: &System<Controls>,
+    ) where
+        Controls: camera::controls::Controls,
+    {
+        let pipeline = match renderer.get_pipelines(RectIsolationPipeline) {
+            Some(pipeline) => pipeline,
+            None => return,
+        };
+
+        for group in &self.isolation_groups {
+            let mut pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("isolation group pass"),
+                    color_attachments: &[Some(
+                        wgpu::RenderPassColorAttachment {
+                            view: group.target(),
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Clear(
+                                    wgpu::Color::TRANSPARENT,
+                                ),
+                                store: wgpu::StoreOp::Store,
+                            },
+                        },
+                    )],
+                    depth_stencil_attachment: match renderer.depth_mode() {
+                        DepthMode::Depth => {
+                            Some(wgpu::RenderPassDepthStencilAttachment {
+                                view: group.depth(),
+                                depth_ops: Some(wgpu::Operations {
+                                    load: wgpu::LoadOp::Clear(1.0),
+                                    store: wgpu::StoreOp::Store,
+                                }),
+                                stencil_ops: None,
+                            })
+                        }
+                        DepthMode::PainterOnly => None,
+                    },
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+
+            pass.set_buffers(renderer.buffer_object.as_buffer_pass());
+            pass.set_bind_group(0, system.bind_group(), &[]);
+            pass.set_bind_group(1, &atlas.texture_group.bind_group, &[]);
+            pass.set_bind_group(2, renderer.frame_uniform().bind_group(), &[]);
+
+            let layers = group
+                .buffer
+                .buffers
+                .len()
+                .max(group.buffer.clipped_buffers.len());
+
+            for buffer_layer in 0..layers {
+                render_rect_instances(
+                    &mut pass,
+                    renderer,
+                    &group.buffer,
+                    pipeline,
+                    system,
+                    buffer_layer,
+                );
+            }
+        }
     }
 }
 
@@ -101,71 +472,215 @@ where
         system: &'b System<Controls>,
         buffer_layer: usize,
     ) {
-        if buffer.buffer.is_clipped() {
-            if let Some(details) =
-                buffer.buffer.clipped_buffers.get(buffer_layer)
+        self.set_bind_group(1, &atlas.texture_group.bind_group, &[]);
+        self.set_bind_group(2, renderer.frame_uniform().bind_group(), &[]);
+
+        render_sorted_rects(self, renderer, buffer, system, buffer_layer);
+
+        if let Some(pipeline) = renderer.get_pipelines(RectCompositePipeline) {
+            for group in buffer
+                .isolation_groups
+                .iter()
+                .filter(|group| group.buffer_layer == buffer_layer)
             {
-                let mut scissor_is_default = true;
+                let opacity = group.opacity as f64;
 
-                if buffer.buffer.count() > 0 {
-                    self.set_bind_group(
-                        1,
-                        &atlas.texture_group.bind_group,
-                        &[],
-                    );
-                    self.set_vertex_buffer(1, buffer.buffer.instances(None));
-                    self.set_pipeline(
-                        renderer.get_pipelines(RectRenderPipeline).unwrap(),
-                    );
+                renderer.record_pipeline(pipeline);
+                self.set_pipeline(pipeline);
+                self.set_blend_constant(wgpu::Color {
+                    r: opacity,
+                    g: opacity,
+                    b: opacity,
+                    a: opacity,
+                });
+                self.set_bind_group(1, group.bind_group(), &[]);
+                self.set_vertex_buffer(1, group.composite().slice(..));
+                renderer
+                    .record_draw(0..StaticVertexBuffer::index_count(), 0..1);
+                self.draw_indexed(
+                    0..StaticVertexBuffer::index_count(),
+                    0,
+                    0..1,
+                );
+            }
 
-                    for (details, bounds, camera_type) in details {
-                        if let Some(bounds) = bounds {
-                            let bounds =
-                                system.world_to_screen(*camera_type, bounds);
-
-                            self.set_scissor_rect(
-                                bounds.x as u32,
-                                bounds.y as u32,
-                                bounds.z as u32,
-                                bounds.w as u32,
-                            );
-                            scissor_is_default = false;
-                        }
+            self.set_bind_group(1, &atlas.texture_group.bind_group, &[]);
+        }
+    }
+}
 
-                        self.draw_indexed(
-                            0..StaticVertexBuffer::index_count(),
-                            0,
-                            details.start..details.end,
-                        );
+//Renders the Buffer Layer's sorted draws switching the Instance Buffer and
+//pipeline only when the next draw uses another.
+fn render_sorted_rects<'a, 'b, Controls>(
+    pass: &mut wgpu::RenderPass<'a>,
+    renderer: &'b GpuRenderer,
+    buffer: &'b RectRenderer,
+    system: &'b System<Controls>,
+    buffer_layer: usize,
+) where
+    'b: 'a,
+    Controls: camera::controls::Controls,
+{
+    let draws = match buffer.draws.get(buffer_layer) {
+        Some(draws) => draws,
+        None => return,
+    };
+    let clipped = buffer.buffer.is_clipped();
+    let mut current = None;
 
-                        if !scissor_is_default {
-                            self.set_scissor_rect(
-                                0,
-                                0,
-                                system.screen_size[0] as u32,
-                                system.screen_size[1] as u32,
-                            );
-                            scissor_is_default = true;
-                        };
+    for draw in draws {
+        let (instances, pipeline) = match draw.kind {
+            RectDrawKind::Glow => {
+                (&buffer.glows, renderer.get_pipelines(RectGlowPipeline))
+            }
+            RectDrawKind::Normal => {
+                (&buffer.buffer, renderer.get_pipelines(RectRenderPipeline))
+            }
+            RectDrawKind::Material(material) => {
+                match buffer.materials.get(material) {
+                    Some(material) => {
+                        (&material.buffer, Some(&material.pipeline))
                     }
+                    None => continue,
                 }
             }
-        } else if let Some(Some(details)) =
-            buffer.buffer.buffers.get(buffer_layer)
-        {
-            if buffer.buffer.count() > 0 {
-                self.set_bind_group(1, &atlas.texture_group.bind_group, &[]);
-                self.set_vertex_buffer(1, buffer.buffer.instances(None));
-                self.set_pipeline(
-                    renderer.get_pipelines(RectRenderPipeline).unwrap(),
-                );
+            RectDrawKind::Composite(composite) => {
+                match buffer.composites.get(composite) {
+                    Some((op, instances)) => (
+                        instances,
+                        renderer.get_pipelines(RectBlendPipeline::new(*op)),
+                    ),
+                    None => continue,
+                }
+            }
+        };
 
-                self.draw_indexed(
-                    0..StaticVertexBuffer::index_count(),
-                    0,
-                    details.start..details.end,
-                );
+        let pipeline = match pipeline {
+            Some(pipeline) => pipeline,
+            None => continue,
+        };
+
+        if current != Some(draw.kind) {
+            if let RectDrawKind::Material(material) = draw.kind {
+                if let Some((_, bind_group)) =
+                    &buffer.materials[material].uniform
+                {
+                    pass.set_bind_group(3, bind_group, &[]);
+                }
+            }
+
+            pass.set_vertex_buffer(1, instances.instances(None));
+            renderer.record_pipeline(pipeline);
+            pass.set_pipeline(pipeline);
+            current = Some(draw.kind);
+        }
+
+        let bounds = draw.bounds.filter(|_| clipped);
+
+        if let Some(bounds) = &bounds {
+            let bounds = system.world_to_screen(draw.camera_type, bounds);
+
+            pass.set_scissor_rect(
+                bounds.x as u32,
+                bounds.y as u32,
+                bounds.z as u32,
+                bounds.w as u32,
+            );
+        }
+
+        renderer.record_draw(
+            0..StaticVertexBuffer::index_count(),
+            draw.instances.clone(),
+        );
+        pass.draw_indexed(
+            0..StaticVertexBuffer::index_count(),
+            0,
+            draw.instances.clone(),
+        );
+
+        if bounds.is_some() {
+            pass.set_scissor_rect(
+                0,
+                0,
+                system.screen_size[0] as u32,
+                system.screen_size[1] as u32,
+            );
+        }
+    }
+}
+
+//Renders a single Rect Instance Buffer's layer using the given pipeline.
+fn render_rect_instances<'a, 'b, Controls>(
+    pass: &mut wgpu::RenderPass<'a>,
+    renderer: &GpuRenderer,
+    buffer: &'b InstanceBuffer<RectVertex>,
+    pipeline: &'b wgpu::RenderPipeline,
+    system: &'b System<Controls>,
+    buffer_layer: usize,
+) where
+    'b: 'a,
+    Controls: camera::controls::Controls,
+{
+    if buffer.is_clipped() {
+        if let Some(details) = buffer.clipped_buffers.get(buffer_layer) {
+            let mut scissor_is_default = true;
+
+            if buffer.count() > 0 {
+                pass.set_vertex_buffer(1, buffer.instances(None));
+                renderer.record_pipeline(pipeline);
+                pass.set_pipeline(pipeline);
+
+                for (details, bounds, camera_type) in details {
+                    if let Some(bounds) = bounds {
+                        let bounds =
+                            system.world_to_screen(*camera_type, bounds);
+
+                        pass.set_scissor_rect(
+                            bounds.x as u32,
+                            bounds.y as u32,
+                            bounds.z as u32,
+                            bounds.w as u32,
+                        );
+                        scissor_is_default = false;
+                    }
+
+                    renderer.record_draw(
+                        0..StaticVertexBuffer::index_count(),
+                        details.start..details.end,
+                    );
+                    pass.draw_indexed(
+                        0..StaticVertexBuffer::index_count(),
+                        0,
+                        details.start..details.end,
+                    );
+
+                    if !scissor_is_default {
+                        pass.set_scissor_rect(
+                            0,
+                            0,
+                            system.screen_size[0] as u32,
+                            system.screen_size[1] as u32,
+                        );
+                        scissor_is_default = true;
+                    };
+                }
             }
         }
+    } else if let Some(Some(details)) = buffer.buffers.get(buffer_layer) {
+        if buffer.count() > 0 {
+            pass.set_vertex_buffer(1, buffer.instances(None));
+            renderer.record_pipeline(pipeline);
+            pass.set_pipeline(pipeline);
+
+            renderer.record_draw(
+                0..StaticVertexBuffer::index_count(),
+                details.start..details.end,
+            );
+            pass.draw_indexed(
+                0..StaticVertexBuffer::index_count(),
+                0,
+                details.start..details.end,
+            );
+        }
     }
 }
@@ -0,0 +1,42 @@
+use crate::{pick_top, Rect, Vec2};
+
+/// Mouse wheel event routed to the topmost scrollable [`Rect`] under the Mouse.
+/// Nested scroll regions are drawn over their parents so the innermost
+/// region under the Mouse receives the wheel.
+///
+#[derive(Copy, Clone)]
+pub struct ScrollTarget<'a> {
+    /// user_id of the [`Rect`] that should scroll.
+    pub user_id: u64,
+    /// The scrollable [`Rect`] under the Mouse.
+    pub rect: &'a Rect,
+    /// Wheel delta to scroll the [`Rect`]'s content by.
+    pub delta: Vec2,
+}
+
+impl<'a> ScrollTarget<'a> {
+    /// Finds the topmost [`Rect`] set with [`Rect::set_scrollable`] under the
+    /// Mouse using [`pick_top`]. Returns None if the delta is zero or no
+    /// scrollable [`Rect`] is under the Mouse.
+    ///
+    /// # Arguments
+    /// - rects: [`Rect`]'s to route the wheel event between.
+    /// - mouse_pos: Mouse position within the same space as the [`Rect`]'s.
+    /// - delta: Wheel delta of the event.
+    ///
+    pub fn find<I>(rects: I, mouse_pos: Vec2, delta: Vec2) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Rect>,
+    {
+        if delta == Vec2::ZERO {
+            return None;
+        }
+
+        pick_top(rects.into_iter().filter(|rect| rect.scrollable), mouse_pos)
+            .map(|(user_id, rect)| Self {
+                user_id,
+                rect,
+                delta,
+            })
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{Rect, Vec2, Vec3};
+use std::f32::consts::TAU;
+
+//Strength in pixels below which the shake counts as settled.
+const SHAKE_SETTLED: f32 = 0.05;
+
+/// Decaying shake that jitters a [`Rect`] around its base position such as
+/// when a health bar is hit. The base position is kept so the [`Rect`]
+/// settles back exactly where it was.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ShakeEffect {
+    /// Max distance in pixels the [`Rect`] is moved when the shake begins.
+    pub amplitude: f32,
+    /// Shakes per second.
+    pub frequency: f32,
+    /// How fast the shake dies down per second. Higher settles sooner.
+    pub decay: f32,
+    /// Seconds passed since the shake was triggered. None while settled.
+    elapsed: Option<f32>,
+    /// Position of the [`Rect`] before it started shaking.
+    base: Option<Vec3>,
+}
+
+impl ShakeEffect {
+    /// Creates a new [`ShakeEffect`] that is not shaking.
+    ///
+    /// # Arguments
+    /// - amplitude: Max distance in pixels the [`Rect`] is moved.
+    /// - frequency: Shakes per second.
+    /// - decay: How fast the shake dies down per second.
+    ///
+    pub fn new(amplitude: f32, frequency: f32, decay: f32) -> Self {
+        Self {
+            amplitude: amplitude.max(0.0),
+            frequency: frequency.max(0.0),
+            decay: decay.max(0.0),
+            elapsed: None,
+            base: None,
+        }
+    }
+
+    /// Starts the shake over at full strength. Triggering while already
+    /// shaking keeps the original base position.
+    ///
+    pub fn trigger(&mut self) {
+        self.elapsed = Some(0.0);
+    }
+
+    /// Advances the shake by dt seconds and offsets the [`Rect`] from its
+    /// base position. Once settled the [`Rect`] is put back at its base
+    /// position. Returns true while still shaking.
+    ///
+    pub fn apply(&mut self, rect: &mut Rect, dt: f32) -> bool {
+        let elapsed = match self.elapsed {
+            Some(elapsed) => elapsed + dt.max(0.0),
+            None => return false,
+        };
+        let base = *self.base.get_or_insert(rect.position);
+        let strength = self.amplitude * (-self.decay * elapsed).exp();
+
+        if strength < SHAKE_SETTLED {
+            self.stop(rect);
+            return false;
+        }
+
+        // Offset phases keep the axes from moving in lock step.
+        let phase = elapsed * self.frequency * TAU;
+        let offset = Vec2::new(
+            phase.sin() * 0.7 + (phase * 2.3 + 1.1).sin() * 0.3,
+            (phase * 1.3 + 2.1).sin() * 0.7 + (phase * 2.9 + 0.4).sin() * 0.3,
+        ) * strength;
+
+        self.elapsed = Some(elapsed);
+        rect.set_position(Vec3::new(
+            base.x + offset.x,
+            base.y + offset.y,
+            base.z,
+        ));
+        true
+    }
+
+    /// Stops shaking and puts the [`Rect`] back at its base position.
+    ///
+    pub fn stop(&mut self, rect: &mut Rect) {
+        if let Some(base) = self.base.take() {
+            rect.set_position(base);
+        }
+
+        self.elapsed = None;
+    }
+
+    /// If the shake is still running.
+    ///
+    pub fn is_shaking(&self) -> bool {
+        self.elapsed.is_some()
+    }
+}
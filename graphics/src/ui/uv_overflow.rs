@@ -0,0 +1,13 @@
+/// What a [`crate::Rect`] shows where its UV's reach outside of its Image's
+/// [`crate::Allocation`], such as when animating the UV's.
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum UvOverflow {
+    /// Samples as is so the edge of the Allocation or its neighbours show.
+    #[default]
+    Clamp,
+    /// Leaves the area outside of the Allocation transparent.
+    Discard,
+    /// Repeats the Allocation so the area outside of it wraps around.
+    Wrap,
+}
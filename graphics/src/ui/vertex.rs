@@ -1,6 +1,32 @@
 use crate::{BufferData, BufferLayout};
 use std::iter;
 
+/// Shift of the corner superellipse exponent stored as 7 bits within
+/// [`RectVertex`] flags. 0 is an exponent of 2.0 and 127 is 10.0.
+pub const RECT_CORNER_SHIFT: u32 = 8;
+/// Mask of the corner superellipse exponent once shifted down.
+pub const RECT_CORNER_MASK: u32 = 0x7f;
+/// Bit within [`RectVertex`] flags set when the Border is composited over
+/// the Fill in one premultiplied step instead of being mixed with it.
+pub const RECT_FLAG_BORDER_OVER_FILL: u32 = 1 << 15;
+/// Bit within [`RectVertex`] flags set when the Texture is a quarter frame
+/// mirrored into all four corners with its last row and column stretched.
+pub const RECT_FLAG_QUARTER_FRAME: u32 = 1 << 16;
+/// Mask of the Texture Array Layer stored as the low 12 bits of [`RectVertex`] layer.
+pub const RECT_LAYER_MASK: u32 = 0xfff;
+/// Shift of the Camera Type stored as 8 bits within [`RectVertex`] layer.
+pub const RECT_CAMERA_SHIFT: u32 = 12;
+/// Mask of the Camera Type once shifted down.
+pub const RECT_CAMERA_MASK: u32 = 0xff;
+/// Shift of the clip feather stored as the upper 12 bits of [`RectVertex`]
+/// layer in sixteenths of a pixel.
+pub const RECT_CLIP_FEATHER_SHIFT: u32 = 20;
+/// Max clip feather in sixteenths of a pixel.
+pub const RECT_CLIP_FEATHER_MAX: u32 = 0xfff;
+/// Max content inset of each side in sixteenths of a pixel stored as
+/// u16's within [`RectVertex`] content_inset.
+pub const RECT_CONTENT_INSET_MAX: u32 = 0xffff;
+
 /// Vertex Details for [`crate::Rect`] that matches the Shaders Vertex Layout.
 ///
 #[repr(C)]
@@ -10,20 +36,63 @@ pub struct RectVertex {
     pub position: [f32; 3],
     /// Width and Height of the Rect.
     pub size: [f32; 2],
+    /// Parallax factor the camera scrolls the Rect by.
+    /// Read by the Shader along with size as one attribute.
+    pub parallax: [f32; 2],
     /// Texture X, Y, W and H if any apply.
     pub uv: [f32; 4],
     /// Color of the Rect.
     pub color: u32,
+    /// Left and Bottom then Right and Top inset the Texture is confined within
+    /// packed as u16's in sixteenths of a pixel. See [`RECT_CONTENT_INSET_MAX`].
+    /// Read by the Shader along with color as one attribute.
+    pub content_inset: [u32; 2],
     /// Width of the Rects Border.
     pub border_width: f32,
     /// Color of the Rects Border.
     pub border_color: u32,
-    /// Texture Array Layer if one applies.
+    /// Atlas X and Y of the faded in Texture's top left then bottom right
+    /// corners packed as u16's so its samples stay within it while cross fading.
+    /// Read by the Shader along with border_color as one attribute.
+    pub crossfade_bounds: [u32; 2],
+    /// Texture Array Layer if one applies along with the Camera Type
+    /// and clip feather. See [`RECT_LAYER_MASK`], [`RECT_CAMERA_SHIFT`]
+    /// and [`RECT_CLIP_FEATHER_SHIFT`].
     pub layer: u32,
     /// Rectangle Radius.
     pub radius: f32,
-    /// Camera Type numberical.
-    pub camera_type: u32,
+    /// Distance from the camera the Rect starts and ends fading out at.
+    /// Read by the Shader along with radius as one attribute.
+    pub distance_fade: [f32; 2],
+    /// Fill Gradient start and end Colors.
+    /// Holds the checker Colors instead when [`crate::RECT_FLAG_CHECKER`] is set.
+    pub gradient: [u32; 2],
+    /// Fill Gradient direction in radians.
+    /// Holds the checker cell size instead when [`crate::RECT_FLAG_CHECKER`] is set.
+    pub gradient_angle: f32,
+    /// Bit flags of optional features. See [`crate::RECT_FLAG_GRADIENT`].
+    /// The upper 12 bits hold the Progress fraction. See [`crate::RECT_PROGRESS_SHIFT`].
+    /// Bits 8 to 14 hold the corner exponent. See [`RECT_CORNER_SHIFT`].
+    /// Bit 15 is [`RECT_FLAG_BORDER_OVER_FILL`], bit 16 is [`RECT_FLAG_QUARTER_FRAME`]
+    /// and bit 17 is [`crate::RECT_FLAG_CHECKER`]. Bits 18 and 19 hold the
+    /// [`crate::FillCombine`]. See [`crate::RECT_FILL_COMBINE_SHIFT`].
+    pub flags: u32,
+    /// Border Gradient start and end Colors.
+    pub border_gradient: [u32; 2],
+    /// Border Gradient direction in radians.
+    pub border_gradient_angle: f32,
+    /// Gradient Map ramp X and Y packed as u16's then its Width and Layer packed as u16's.
+    /// Holds the tessellated Gradient cell instead when [`crate::RECT_FLAG_GRADIENT_MAP`]
+    /// is not set. See [`crate::RECT_GRADIENT_CELLS_SHIFT`]. The second value then
+    /// holds the cross fade. See [`crate::RECT_FLAG_CROSSFADE`].
+    pub gradient_map: [u32; 2],
+    /// Texture scroll velocity in pixels per second.
+    /// Holds the faded in Texture's atlas X and Y instead while cross fading.
+    /// Read by the Shader along with gradient_map as their bits.
+    pub uv_scroll: [f32; 2],
+    /// Clip Bounds left, bottom, right and top the edges fade out within
+    /// by the clip feather.
+    pub clip: [f32; 4],
 }
 
 impl Default for RectVertex {
@@ -31,20 +100,31 @@ impl Default for RectVertex {
         Self {
             position: [0.0; 3],
             size: [0.0; 2],
+            parallax: [1.0; 2],
             uv: [0.0; 4],
             color: 0,
+            content_inset: [0; 2],
             border_width: 0.0,
             border_color: 0,
+            crossfade_bounds: [0; 2],
             layer: 0,
             radius: 1.0,
-            camera_type: 0,
+            distance_fade: [0.0; 2],
+            gradient: [0; 2],
+            gradient_angle: 0.0,
+            flags: 0,
+            border_gradient: [0; 2],
+            border_gradient_angle: 0.0,
+            gradient_map: [0; 2],
+            uv_scroll: [0.0; 2],
+            clip: [0.0; 4],
         }
     }
 }
 
 impl BufferLayout for RectVertex {
     fn attributes() -> Vec<wgpu::VertexAttribute> {
-        wgpu::vertex_attr_array![1 => Float32x3, 2 => Float32x2, 3 => Float32x4, 4 => Uint32, 5 => Float32, 6 => Uint32, 7 => Uint32, 8 => Float32, 9 => Uint32]
+        wgpu::vertex_attr_array![1 => Float32x3, 2 => Float32x4, 3 => Float32x4, 4 => Uint32x3, 5 => Float32, 6 => Uint32x3, 7 => Uint32, 8 => Float32x3, 10 => Uint32x2, 11 => Float32, 12 => Uint32, 13 => Uint32x2, 14 => Float32, 15 => Uint32x4, 9 => Float32x4]
             .to_vec()
     }
 
@@ -68,6 +148,6 @@ impl BufferLayout for RectVertex {
     }
 
     fn stride() -> usize {
-        std::mem::size_of::<[f32; 15]>()
+        std::mem::size_of::<[f32; 37]>()
     }
 }